@@ -0,0 +1,86 @@
+//! Binary vs JSON colormap storage
+//!
+//! Saves a batch of colormaps in both formats, then times loading them
+//! back and compares file sizes. Run in release mode for meaningful numbers:
+//!
+//! ```text
+//! cargo run --release --example binary_vs_json --features bincode
+//! ```
+
+use scala_chromatica::{io, Color, ColorMap};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+const MAP_COUNT: usize = 1000;
+
+fn main() -> scala_chromatica::Result<()> {
+    let dir = std::env::temp_dir().join(format!(
+        "scala_chromatica_binary_vs_json_{}",
+        std::process::id()
+    ));
+    fs::remove_dir_all(&dir).ok();
+    fs::create_dir_all(&dir)?;
+
+    // Dense 64-stop gradients, similar to LUTs sampled from a larger map
+    let maps: Vec<ColorMap> = (0..MAP_COUNT)
+        .map(|i| {
+            let colors = (0..64)
+                .map(|j| Color::from_hsv((i * 7 + j * 5) as f64 % 360.0, 0.8, 0.9))
+                .collect();
+            ColorMap::equidistant(format!("Map {}", i), colors)
+        })
+        .collect();
+
+    for (i, map) in maps.iter().enumerate() {
+        fs::write(
+            dir.join(format!("{}.json", i)),
+            io::save_colormap_to_string(map)?,
+        )?;
+        io::save_colormap_binary(map, &dir.join(format!("{}.scbin", i)))?;
+    }
+
+    println!("=== Loading {} colormaps (64 stops each) ===\n", MAP_COUNT);
+    let json = time_loads(&dir, "json")?;
+    let binary = time_loads(&dir, "scbin")?;
+    report("JSON", json, directory_size(&dir, "json")?);
+    report("Binary", binary, directory_size(&dir, "scbin")?);
+    println!(
+        "\nBinary loads {:.1}x faster",
+        json.as_secs_f64() / binary.as_secs_f64()
+    );
+
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+/// Load every file with `extension` through `load_colormap_auto`
+fn time_loads(dir: &Path, extension: &str) -> scala_chromatica::Result<Duration> {
+    let start = Instant::now();
+    for i in 0..MAP_COUNT {
+        let map = io::load_colormap_auto(&dir.join(format!("{}.{}", i, extension)))?;
+        assert_eq!(map.stops.len(), 64);
+    }
+    Ok(start.elapsed())
+}
+
+fn directory_size(dir: &Path, extension: &str) -> std::io::Result<u64> {
+    let mut total = 0;
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) == Some(extension) {
+            total += fs::metadata(&path)?.len();
+        }
+    }
+    Ok(total)
+}
+
+fn report(label: &str, elapsed: Duration, bytes: u64) {
+    println!(
+        "{:<7} {:>8.2} ms total, {:>6.1} µs per map, {:>7} KiB on disk",
+        label,
+        elapsed.as_secs_f64() * 1000.0,
+        elapsed.as_secs_f64() * 1e6 / MAP_COUNT as f64,
+        bytes / 1024
+    );
+}
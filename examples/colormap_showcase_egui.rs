@@ -88,6 +88,15 @@ impl eframe::App for ColormapShowcase {
 
                         // Load and display the colormap
                         if let Ok(colormap) = io::load_builtin_colormap(&name) {
+                            if let Some(description) = &colormap.metadata.description {
+                                ui.label(
+                                    egui::RichText::new(description)
+                                        .size(12.0)
+                                        .color(egui::Color32::GRAY),
+                                );
+                                ui.add_space(4.0);
+                            }
+
                             let gradient_height = if is_selected { 50.0 } else { 35.0 };
                             draw_colormap_gradient(ui, &colormap, gradient_height);
 
@@ -125,16 +134,10 @@ impl eframe::App for ColormapShowcase {
                                                 .rounding(3.0);
 
                                             frame.show(ui, |ui| {
-                                                // Choose text color based on brightness
-                                                let brightness = (color.r as f32 * 0.299
-                                                    + color.g as f32 * 0.587
-                                                    + color.b as f32 * 0.114)
-                                                    / 255.0;
-                                                let text_color = if brightness > 0.5 {
-                                                    egui::Color32::BLACK
-                                                } else {
-                                                    egui::Color32::WHITE
-                                                };
+                                                // Choose text color based on luminance
+                                                let text = color.contrasting_text_color();
+                                                let text_color =
+                                                    egui::Color32::from_rgb(text.r, text.g, text.b);
                                                 ui.label(
                                                     egui::RichText::new(hex_text)
                                                         .size(11.0)
@@ -241,6 +244,10 @@ fn get_all_builtin_colormaps() -> Vec<String> {
         "Spring Meadow",
         "Egyptian Echo",
         "Copper Sheen",
+        "Viridis",
+        "Plasma",
+        "Magma",
+        "Inferno",
     ]
     .into_iter()
     .map(|s| s.to_string())
@@ -2,6 +2,7 @@
 //! Displays each colormap with ANSI colors, gradient bar, and detailed stop information
 //! Run with: cargo run --example colormap_showcase_shell
 
+use scala_chromatica::color::ANSI_RESET;
 use scala_chromatica::io::load_builtin_colormap;
 
 fn main() {
@@ -10,12 +11,12 @@ fn main() {
     println!("╚═══════════════════════════════════════════════════════════╝\n");
 
     let colormap_names = get_all_builtin_colormaps();
-    
+
     for (idx, name) in colormap_names.iter().enumerate() {
         println!("─────────────────────────────────────────────────────────────");
         println!("Colormap #{}: {}", idx + 1, name);
         println!("─────────────────────────────────────────────────────────────");
-        
+
         match load_builtin_colormap(name) {
             Ok(colormap) => {
                 // Print gradient bar using ANSI colors
@@ -23,30 +24,33 @@ fn main() {
                 for i in 0..80 {
                     let t = i as f64 / 79.0;
                     let color = colormap.get_color(t);
-                    print!("\x1b[48;2;{};{};{}m \x1b[0m", color.r, color.g, color.b);
+                    print!("{} {}", color.ansi_bg(), ANSI_RESET);
                 }
                 println!();
-                
+
                 // Print stops count
                 println!("Stops:    {} color stops", colormap.stops.len());
+                if let Some(description) = &colormap.metadata.description {
+                    println!("About:    {}", description);
+                }
                 println!();
-                
+
                 // Print detailed stop information
                 for (i, stop) in colormap.stops.iter().enumerate() {
                     let color = &stop.color;
                     let desc = describe_color(color.r, color.g, color.b);
-                    
+
                     // Color swatch
                     print!("  ");
-                    print!("\x1b[48;2;{};{};{}m    \x1b[0m", color.r, color.g, color.b);
-                    
+                    print!("{}    {}", color.ansi_bg(), ANSI_RESET);
+
                     // Stop details
                     print!(" [{:2}] pos={:.3}", i, stop.position);
                     print!("  RGB({:3},{:3},{:3})", color.r, color.g, color.b);
                     print!("  {}", color.to_hex());
                     println!("  {}", desc);
                 }
-                
+
                 println!();
             }
             Err(e) => {
@@ -55,7 +59,7 @@ fn main() {
             }
         }
     }
-    
+
     println!("─────────────────────────────────────────────────────────────");
     println!("Total: {} built-in colormaps", colormap_names.len());
     println!();
@@ -81,6 +85,10 @@ fn get_all_builtin_colormaps() -> Vec<String> {
         "Spring Meadow",
         "Egyptian Echo",
         "Copper Sheen",
+        "Viridis",
+        "Plasma",
+        "Magma",
+        "Inferno",
     ]
     .into_iter()
     .map(|s| s.to_string())
@@ -92,7 +100,7 @@ fn describe_color(r: u8, g: u8, b: u8) -> String {
     let max_component = r.max(g).max(b);
     let min_component = r.min(g).min(b);
     let chroma = max_component - min_component;
-    
+
     // Near grayscale
     if chroma < 20 {
         if brightness < 20 {
@@ -103,7 +111,7 @@ fn describe_color(r: u8, g: u8, b: u8) -> String {
             return format!("gray (brightness: {})", brightness);
         }
     }
-    
+
     // Determine dominant hue
     let dominant = if r >= g && r >= b {
         "red"
@@ -112,31 +120,31 @@ fn describe_color(r: u8, g: u8, b: u8) -> String {
     } else {
         "blue"
     };
-    
+
     // Determine saturation level
     let saturation = if max_component > 0 {
         (chroma as f32 / max_component as f32 * 100.0) as u32
     } else {
         0
     };
-    
+
     // Build description
     let mut desc = String::new();
-    
+
     // Brightness modifier
     if brightness < 80 {
         desc.push_str("dark ");
     } else if brightness > 180 {
         desc.push_str("bright ");
     }
-    
+
     // Saturation modifier
     if saturation < 30 {
         desc.push_str("pale ");
     } else if saturation > 80 {
         desc.push_str("vivid ");
     }
-    
+
     // Specific color names based on RGB patterns
     if r > 200 && g < 100 && b < 100 {
         desc.push_str("red");
@@ -160,6 +168,6 @@ fn describe_color(r: u8, g: u8, b: u8) -> String {
         desc.push_str(dominant);
         desc.push_str(" tint");
     }
-    
+
     desc
 }
@@ -6,7 +6,7 @@
 //! - Saving and loading custom gradients
 //! - Gradient inversion and manipulation
 
-use scala_chromatica::{Color, ColorMap, ColorStop};
+use scala_chromatica::{Color, ColorMap, ColorStop, Easing};
 
 fn main() {
     println!("=== Advanced Gradient Techniques ===\n");
@@ -25,14 +25,11 @@ fn main() {
     // Example 2: Sharp transitions vs smooth transitions
     println!("\n2. Sharp vs smooth transitions:");
 
-    println!("   Sharp (adjacent stops):");
+    println!("   Sharp (stepped segments):");
     let mut sharp = ColorMap::new("Sharp");
-    sharp.add_stop(ColorStop::new(0.0, Color::new(255, 0, 0)));
-    sharp.add_stop(ColorStop::new(0.333, Color::new(255, 0, 0)));
-    sharp.add_stop(ColorStop::new(0.334, Color::new(0, 255, 0)));
-    sharp.add_stop(ColorStop::new(0.666, Color::new(0, 255, 0)));
+    sharp.add_stop(ColorStop::new(0.0, Color::new(255, 0, 0)).with_easing(Easing::Step));
+    sharp.add_stop(ColorStop::new(0.334, Color::new(0, 255, 0)).with_easing(Easing::Step));
     sharp.add_stop(ColorStop::new(0.667, Color::new(0, 0, 255)));
-    sharp.add_stop(ColorStop::new(1.0, Color::new(0, 0, 255)));
     sample_gradient(&sharp);
 
     println!("\n   Smooth (spaced stops):");
@@ -45,31 +42,31 @@ fn main() {
     // Example 3: Metallic gradient with named stops
     println!("\n3. Metallic gradient with named colors:");
     let mut metallic = ColorMap::new("Bronze Metal");
-    metallic.add_stop(ColorStop {
-        position: 0.0,
-        color: Color::new(52, 28, 11),
-        name: Some("Deep Bronze".to_string()),
-    });
-    metallic.add_stop(ColorStop {
-        position: 0.3,
-        color: Color::new(140, 82, 33),
-        name: Some("Bronze Base".to_string()),
-    });
-    metallic.add_stop(ColorStop {
-        position: 0.5,
-        color: Color::new(205, 127, 50),
-        name: Some("Bronze Highlight".to_string()),
-    });
-    metallic.add_stop(ColorStop {
-        position: 0.7,
-        color: Color::new(140, 82, 33),
-        name: Some("Bronze Shadow".to_string()),
-    });
-    metallic.add_stop(ColorStop {
-        position: 1.0,
-        color: Color::new(52, 28, 11),
-        name: Some("Deep Bronze".to_string()),
-    });
+    metallic.add_stop(ColorStop::with_name(
+        0.0,
+        Color::new(52, 28, 11),
+        "Deep Bronze",
+    ));
+    metallic.add_stop(ColorStop::with_name(
+        0.3,
+        Color::new(140, 82, 33),
+        "Bronze Base",
+    ));
+    metallic.add_stop(ColorStop::with_name(
+        0.5,
+        Color::new(205, 127, 50),
+        "Bronze Highlight",
+    ));
+    metallic.add_stop(ColorStop::with_name(
+        0.7,
+        Color::new(140, 82, 33),
+        "Bronze Shadow",
+    ));
+    metallic.add_stop(ColorStop::with_name(
+        1.0,
+        Color::new(52, 28, 11),
+        "Deep Bronze",
+    ));
 
     sample_gradient(&metallic);
 
@@ -127,7 +124,7 @@ fn main() {
     custom.add_stop(ColorStop::new(1.0, Color::from_hsv(260.0, 1.0, 1.0)));
 
     // Save it
-    match scala_chromatica::io::save_colormap(&custom) {
+    match scala_chromatica::io::save_colormap_atomic(&custom) {
         Ok(_) => {
             println!("   ✓ Saved 'MyCustomGradient'");
 
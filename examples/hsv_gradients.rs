@@ -101,7 +101,7 @@ fn main() {
     sunset.add_stop(ColorStop::new(0.6, Color::from_hsv(20.0, 1.0, 1.0))); // Orange
     sunset.add_stop(ColorStop::new(1.0, Color::from_hsv(60.0, 1.0, 1.0))); // Yellow
 
-    match scala_chromatica::io::save_colormap(&sunset) {
+    match scala_chromatica::io::save_colormap_atomic(&sunset) {
         Ok(_) => println!("   ✓ Saved 'HSV Sunset' gradient to disk"),
         Err(e) => println!("   ✗ Error saving: {}", e),
     }
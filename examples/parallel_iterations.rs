@@ -0,0 +1,80 @@
+//! Parallel vs sequential fractal coloring
+//!
+//! Colors a 4096×4096 buffer of pseudo-random iteration counts with
+//! `color_from_iterations` on one thread and with
+//! `color_from_iterations_batch` on all cores, checks that the results are
+//! identical and prints the speedup. Run in release mode:
+//!
+//! ```text
+//! cargo run --release --example parallel_iterations --features rayon
+//! ```
+
+use scala_chromatica::{color_from_iterations, color_from_iterations_batch, Color, ColorMap};
+use std::time::Instant;
+
+const SIZE: usize = 4096;
+const MAX_ITERATIONS: u32 = 1000;
+
+fn main() {
+    let colormap = ColorMap::viridis_scheme();
+
+    // xorshift keeps the "image" reproducible without extra dependencies
+    let mut state = 0x9e37_79b9_u32;
+    let iterations: Vec<u32> = (0..SIZE * SIZE)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state % (MAX_ITERATIONS + 1)
+        })
+        .collect();
+
+    println!("=== Coloring {}x{} iteration counts ===\n", SIZE, SIZE);
+
+    let start = Instant::now();
+    let sequential: Vec<Color> = iterations
+        .iter()
+        .map(|&n| {
+            color_from_iterations(
+                n,
+                MAX_ITERATIONS,
+                &colormap,
+                true,
+                256,
+                true,
+                [0, 0, 0],
+                false,
+            )
+        })
+        .collect();
+    let sequential_time = start.elapsed();
+
+    let start = Instant::now();
+    let parallel = color_from_iterations_batch(
+        &iterations,
+        MAX_ITERATIONS,
+        &colormap,
+        true,
+        256,
+        true,
+        [0, 0, 0],
+        false,
+    );
+    let parallel_time = start.elapsed();
+
+    assert_eq!(parallel, sequential, "parallel output must match");
+
+    println!(
+        "Sequential: {:>8.1} ms",
+        sequential_time.as_secs_f64() * 1000.0
+    );
+    println!(
+        "Parallel:   {:>8.1} ms ({} threads)",
+        parallel_time.as_secs_f64() * 1000.0,
+        std::thread::available_parallelism().map_or(1, |n| n.get())
+    );
+    println!(
+        "\nSpeedup: {:.1}x, results identical",
+        sequential_time.as_secs_f64() / parallel_time.as_secs_f64()
+    );
+}
@@ -3,13 +3,137 @@
 //! Provides a simple RGB color representation with support for:
 //! - RGB color creation
 //! - HSV to RGB conversion
+//! - HSL conversion and lighten/darken/saturate/desaturate adjustments
 //! - Linear interpolation (lerp) between colors
 //! - Common color constants (black, white)
+//! - Translucent [`Rgba`] colors with source-over compositing
+//! - 16-bit [`Color16`] colors for high-bit-depth exports
+//! - [`serde_hex`] helpers for `"#RRGGBB"` serialization
+//! - ANSI truecolor and xterm-256 terminal escapes
 
+mod ansi;
+mod color16;
+pub(crate) mod conversion;
+mod css;
+mod cvd;
+mod named;
+mod rgba;
+pub mod serde_hex;
+
+pub use ansi::ANSI_RESET;
+pub use color16::Color16;
+pub use cvd::{ColorBlindType, ColorVisionDeficiency};
+pub use rgba::Rgba;
+
+use conversion::{
+    decode_srgb, encode_srgb, hsl_to_rgb, hsv_to_rgb, lab_to_xyz, linear_rgb_to_oklab,
+    linear_to_srgb, oklab_to_linear_rgb, oklab_to_oklch, oklab_to_rgb, oklch_to_oklab,
+    planckian_xy, rgb_to_hsl, rgb_to_hsv, rgb_to_lab, rgb_to_oklab, rgb_to_xyz, srgb_to_linear,
+    xyz_to_linear_rgb, xyz_to_rgb,
+};
 use serde::{Deserialize, Serialize};
 
+/// Per-channel blend modes for compositing one color onto another
+///
+/// In the formulas below `a` is the base color and `b` is the blend color,
+/// both normalized to 0.0-1.0. Results are clamped back into range.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlendMode {
+    /// `a * b` - darkens; blending with white is a no-op
+    Multiply,
+    /// `1 - (1 - a) * (1 - b)` - lightens; blending with black is a no-op
+    Screen,
+    /// Multiply for dark base channels, screen for light ones
+    Overlay,
+    /// `min(a, b)`
+    Darken,
+    /// `max(a, b)`
+    Lighten,
+    /// `a + b`, saturating at white
+    Add,
+    /// `a - b`, saturating at black
+    Subtract,
+    /// `|a - b|`
+    Difference,
+    /// Gentler overlay driven by the blend color (W3C compositing formula)
+    SoftLight,
+    /// Color dodge, `a / (1 - b)` - brightens the base toward the blend color
+    Dodge,
+    /// Color burn, `1 - (1 - a) / b` - darkens the base toward the blend color
+    Burn,
+    /// Plain alpha compositing: the blend color drawn over the base with
+    /// the base at opacity `alpha`, same as `other.lerp(&self, alpha)`
+    Normal(f64),
+}
+
+/// Direction taken around the hue wheel by [`Color::lerp_hsv`] and [`Color::lerp_oklch`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HuePath {
+    /// The arc of at most 180 degrees
+    #[default]
+    Shorter,
+    /// The arc of at least 180 degrees
+    Longer,
+    /// Always with increasing hue, wrapping from 360 to 0
+    Increasing,
+    /// Always with decreasing hue, wrapping from 0 to 360
+    Decreasing,
+}
+
+impl HuePath {
+    /// Signed hue change in degrees when going from `h1` to `h2` along this path
+    fn delta(self, h1: f64, h2: f64) -> f64 {
+        let mut delta = h2 - h1;
+        match self {
+            HuePath::Shorter => {
+                if delta > 180.0 {
+                    delta -= 360.0;
+                } else if delta < -180.0 {
+                    delta += 360.0;
+                }
+            }
+            HuePath::Longer => {
+                if delta > 0.0 && delta < 180.0 {
+                    delta -= 360.0;
+                } else if delta < 0.0 && delta > -180.0 {
+                    delta += 360.0;
+                }
+            }
+            HuePath::Increasing => {
+                if delta < 0.0 {
+                    delta += 360.0;
+                }
+            }
+            HuePath::Decreasing => {
+                if delta > 0.0 {
+                    delta -= 360.0;
+                }
+            }
+        }
+        delta
+    }
+}
+
+/// Channel weighting used when converting a color to grayscale
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GrayscaleMethod {
+    /// Plain mean of the three channels
+    Average,
+    /// ITU-R BT.601 luma weights (0.299, 0.587, 0.114)
+    Luminosity,
+    /// ITU-R BT.709 luma weights (0.2126, 0.7152, 0.0722)
+    Rec709,
+}
+
+/// Oklch chroma below which a color is treated as gray
+const OKLCH_ACHROMATIC: f64 = 1e-4;
+
 /// RGB Color representation
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+///
+/// Colors are totally ordered lexicographically by `r`, then `g`, then `b`,
+/// which makes them usable as `BTreeMap` keys or for sorting palettes. The
+/// order carries no perceptual meaning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Color {
     pub r: u8,
     pub g: u8,
@@ -17,50 +141,364 @@ pub struct Color {
 }
 
 impl Color {
+    /// Black (0, 0, 0)
+    pub const BLACK: Color = Color::new(0, 0, 0);
+    /// White (255, 255, 255)
+    pub const WHITE: Color = Color::new(255, 255, 255);
+    /// Pure red (255, 0, 0)
+    pub const RED: Color = Color::new(255, 0, 0);
+    /// Pure green (0, 255, 0); note that CSS `green` is (0, 128, 0)
+    pub const GREEN: Color = Color::new(0, 255, 0);
+    /// Pure blue (0, 0, 255)
+    pub const BLUE: Color = Color::new(0, 0, 255);
+    /// Cyan (0, 255, 255)
+    pub const CYAN: Color = Color::new(0, 255, 255);
+    /// Magenta (255, 0, 255)
+    pub const MAGENTA: Color = Color::new(255, 0, 255);
+    /// Yellow (255, 255, 0)
+    pub const YELLOW: Color = Color::new(255, 255, 0);
+
+    /// Half-width of the neutral band used by [`Color::is_warm`] and
+    /// [`Color::is_cool`]; colors with `|warmth()|` at or below it are neither
+    pub const WARMTH_NEUTRAL_BAND: f64 = 0.1;
+
     /// Create a new RGB color
-    pub fn new(r: u8, g: u8, b: u8) -> Self {
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
         Self { r, g, b }
     }
 
     /// Create a color from HSV values
     ///
     /// # Arguments
-    /// * `h` - Hue (0.0 - 360.0)
-    /// * `s` - Saturation (0.0 - 1.0)
-    /// * `v` - Value/Brightness (0.0 - 1.0)
+    /// * `h` - Hue in degrees (wrapped into 0.0 - 360.0, so 360 is red again)
+    /// * `s` - Saturation (clamped to 0.0 - 1.0)
+    /// * `v` - Value/Brightness (clamped to 0.0 - 1.0)
     pub fn from_hsv(h: f64, s: f64, v: f64) -> Self {
-        let c = v * s;
-        let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
-        let m = v - c;
-
-        let (r, g, b) = if h < 60.0 {
-            (c, x, 0.0)
-        } else if h < 120.0 {
-            (x, c, 0.0)
-        } else if h < 180.0 {
-            (0.0, c, x)
-        } else if h < 240.0 {
-            (0.0, x, c)
-        } else if h < 300.0 {
-            (x, 0.0, c)
+        let (r, g, b) = hsv_to_rgb(h, s, v);
+
+        Self {
+            r: (r * 255.0) as u8,
+            g: (g * 255.0) as u8,
+            b: (b * 255.0) as u8,
+        }
+    }
+
+    /// Convert to HSV as `(hue, saturation, value)`
+    ///
+    /// Hue is in degrees (0.0 - 360.0), saturation and value are 0.0 - 1.0.
+    /// Grays report a hue of 0.0.
+    pub fn to_hsv(&self) -> (f64, f64, f64) {
+        ColorF::from(*self).to_hsv()
+    }
+
+    /// Create a color from HSL values
+    ///
+    /// # Arguments
+    /// * `h` - Hue in degrees (wrapped into 0.0 - 360.0)
+    /// * `s` - Saturation (0.0 - 1.0)
+    /// * `l` - Lightness (0.0 - 1.0)
+    pub fn from_hsl(h: f64, s: f64, l: f64) -> Self {
+        ColorF::from_hsl(h, s, l).to_color()
+    }
+
+    /// Convert to HSL as `(hue, saturation, lightness)`
+    ///
+    /// Hue is in degrees (0.0 - 360.0), saturation and lightness are 0.0 - 1.0.
+    /// Grays report a hue of 0.0.
+    pub fn to_hsl(&self) -> (f64, f64, f64) {
+        ColorF::from(*self).to_hsl()
+    }
+
+    /// Create a color from HWB (hue, whiteness, blackness) values, as in CSS `hwb()`
+    ///
+    /// # Arguments
+    /// * `h` - Hue in degrees (wrapped into 0.0 - 360.0)
+    /// * `w` - Whiteness (0.0 - 1.0)
+    /// * `b` - Blackness (0.0 - 1.0)
+    ///
+    /// If whiteness and blackness add up to more than 1 they are scaled down
+    /// proportionally, which yields a gray.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// assert_eq!(Color::from_hwb(0.0, 0.0, 0.0), Color::new(255, 0, 0));
+    /// assert_eq!(Color::from_hwb(0.0, 0.6, 0.6), Color::new(128, 128, 128));
+    /// ```
+    pub fn from_hwb(h: f64, w: f64, b: f64) -> Self {
+        let w = w.clamp(0.0, 1.0);
+        let b = b.clamp(0.0, 1.0);
+
+        if w + b >= 1.0 {
+            let gray = (w / (w + b) * 255.0).round() as u8;
+            return Self::new(gray, gray, gray);
+        }
+
+        let v = 1.0 - b;
+        let s = 1.0 - w / v;
+        let (r, g, b) = hsv_to_rgb(h, s, v);
+
+        Self {
+            r: (r * 255.0).round() as u8,
+            g: (g * 255.0).round() as u8,
+            b: (b * 255.0).round() as u8,
+        }
+    }
+
+    /// Convert to HWB as `(hue, whiteness, blackness)`
+    ///
+    /// Hue is in degrees (0.0 - 360.0), whiteness and blackness are 0.0 - 1.0.
+    /// Grays report a hue of 0.0.
+    pub fn to_hwb(&self) -> (f64, f64, f64) {
+        let (h, _, _) = self.to_hsv();
+        let min = self.r.min(self.g).min(self.b) as f64 / 255.0;
+        let max = self.r.max(self.g).max(self.b) as f64 / 255.0;
+        (h, min, 1.0 - max)
+    }
+
+    /// Convert to CIE XYZ (D65 illuminant, Y of white = 1.0)
+    pub fn to_xyz(&self) -> (f64, f64, f64) {
+        rgb_to_xyz(self)
+    }
+
+    /// Create a color from CIE XYZ (D65 illuminant, Y of white = 1.0)
+    ///
+    /// Out-of-gamut values are clamped to the sRGB cube.
+    pub fn from_xyz(x: f64, y: f64, z: f64) -> Self {
+        xyz_to_rgb(x, y, z)
+    }
+
+    /// Convert to CIE L*a*b* (D65 illuminant) as `(L*, a*, b*)`
+    ///
+    /// L* runs from 0.0 (black) to 100.0 (white); gray colors have
+    /// a* and b* near 0.0.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// let (l, a, b) = Color::new(70, 130, 180).to_lab();
+    /// assert_eq!(Color::from_lab(l, a, b), Color::new(70, 130, 180));
+    /// ```
+    pub fn to_lab(&self) -> (f64, f64, f64) {
+        rgb_to_lab(self)
+    }
+
+    /// Create a color from CIE L*a*b* (D65 illuminant)
+    ///
+    /// Out-of-gamut values are clamped to the sRGB cube.
+    pub fn from_lab(l: f64, a: f64, b: f64) -> Self {
+        let (x, y, z) = lab_to_xyz(l, a, b);
+        xyz_to_rgb(x, y, z)
+    }
+
+    /// Convert to Oklab as `(L, a, b)`
+    ///
+    /// Oklab (Björn Ottosson, 2020) is a perceptual color space like CIE
+    /// L*a*b*, but keeps hue steady when blending saturated blues and
+    /// purples. L runs from 0.0 (black) to 1.0 (white); grays have a and b
+    /// near 0.0.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// let (l, a, b) = Color::new(70, 130, 180).to_oklab();
+    /// assert_eq!(Color::from_oklab(l, a, b), Color::new(70, 130, 180));
+    /// ```
+    pub fn to_oklab(&self) -> (f64, f64, f64) {
+        rgb_to_oklab(self)
+    }
+
+    /// Create a color from Oklab
+    ///
+    /// Out-of-gamut values are clamped to the sRGB cube.
+    pub fn from_oklab(l: f64, a: f64, b: f64) -> Self {
+        oklab_to_rgb(l, a, b)
+    }
+
+    /// Convert to Oklch, the cylindrical form of Oklab, as `(L, C, h)`
+    ///
+    /// C is the chroma (0.0 for grays, about 0.32 at most in sRGB) and h the
+    /// hue in degrees (0.0 - 360.0).
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// let (l, c, h) = Color::new(70, 130, 180).to_oklch();
+    /// assert!(h > 200.0 && h < 260.0);
+    /// assert_eq!(Color::from_oklch(l, c, h), Color::new(70, 130, 180));
+    /// ```
+    pub fn to_oklch(&self) -> (f64, f64, f64) {
+        let (l, a, b) = self.to_oklab();
+        oklab_to_oklch(l, a, b)
+    }
+
+    /// Create a color from Oklch (hue in degrees)
+    ///
+    /// Out-of-gamut values are clamped to the sRGB cube.
+    pub fn from_oklch(l: f64, c: f64, h: f64) -> Self {
+        let (l, a, b) = oklch_to_oklab(l, c, h);
+        Self::from_oklab(l, a, b)
+    }
+
+    /// Convert linear HDR radiance to a color by clamping, without tonemapping
+    ///
+    /// Anything brighter than 1.0 clips to full intensity. Use
+    /// [`ColorF::tonemap_reinhard`] or [`ColorF::tonemap_aces`] to keep
+    /// detail in highlights.
+    pub fn from_hdr(radiance: ColorF) -> Self {
+        ColorF::new(
+            radiance.r.clamp(0.0, 1.0),
+            radiance.g.clamp(0.0, 1.0),
+            radiance.b.clamp(0.0, 1.0),
+        )
+        .to_srgb()
+        .to_color()
+    }
+
+    /// Increase HSL lightness by `amount` (0.0 - 1.0), clamping at white
+    pub fn lighten(&self, amount: f64) -> Color {
+        let (h, s, l) = self.to_hsl();
+        Color::from_hsl(h, s, l + amount.clamp(0.0, 1.0))
+    }
+
+    /// Decrease HSL lightness by `amount` (0.0 - 1.0), clamping at black
+    pub fn darken(&self, amount: f64) -> Color {
+        let (h, s, l) = self.to_hsl();
+        Color::from_hsl(h, s, l - amount.clamp(0.0, 1.0))
+    }
+
+    /// Increase HSL saturation by `amount` (0.0 - 1.0)
+    pub fn saturate(&self, amount: f64) -> Color {
+        let (h, s, l) = self.to_hsl();
+        Color::from_hsl(h, s + amount.clamp(0.0, 1.0), l)
+    }
+
+    /// Decrease HSL saturation by `amount` (0.0 - 1.0); 1.0 yields a gray
+    pub fn desaturate(&self, amount: f64) -> Color {
+        let (h, s, l) = self.to_hsl();
+        Color::from_hsl(h, s - amount.clamp(0.0, 1.0), l)
+    }
+
+    /// Approximate color of a blackbody radiator at the given temperature in Kelvin
+    ///
+    /// Uses Tanner Helland's curve fit of Mitchell Charity's blackbody table,
+    /// which is accurate to a few units per channel between 1000 K and 40000 K.
+    /// Temperatures outside that range are clamped to it.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// let candle = Color::from_kelvin(1900.0);
+    /// assert!(candle.r > candle.g && candle.g > candle.b);
+    /// ```
+    pub fn from_kelvin(temp: f64) -> Self {
+        let t = temp.clamp(1000.0, 40000.0) / 100.0;
+
+        let r = if t <= 66.0 {
+            255.0
+        } else {
+            329.698727446 * (t - 60.0).powf(-0.1332047592)
+        };
+
+        let g = if t <= 66.0 {
+            99.4708025861 * t.ln() - 161.1195681661
+        } else {
+            288.1221695283 * (t - 60.0).powf(-0.0755148492)
+        };
+
+        let b = if t >= 66.0 {
+            255.0
+        } else if t <= 19.0 {
+            0.0
         } else {
-            (c, 0.0, x)
+            138.5177312231 * (t - 10.0).ln() - 305.0447927307
         };
 
         Self {
-            r: ((r + m) * 255.0) as u8,
-            g: ((g + m) * 255.0) as u8,
-            b: ((b + m) * 255.0) as u8,
+            r: r.clamp(0.0, 255.0).round() as u8,
+            g: g.clamp(0.0, 255.0).round() as u8,
+            b: b.clamp(0.0, 255.0).round() as u8,
         }
     }
 
+    /// Blackbody color for a temperature in Kelvin (1000 K - 12000 K)
+    ///
+    /// Finds the chromaticity on the Planckian locus with Krystek's
+    /// approximation, converts it to sRGB and scales the brightest channel
+    /// to 255. Temperatures outside the range are clamped to it. This is
+    /// more accurate than [`Color::from_kelvin`]'s curve fit, particularly
+    /// around daylight white (6500 K is close to D65).
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// assert_eq!(Color::from_temperature(6500.0), Color::new(255, 248, 254));
+    /// ```
+    pub fn from_temperature(kelvin: f64) -> Self {
+        let (x, y) = planckian_xy(kelvin.clamp(1000.0, 12000.0));
+        let (r, g, b) = xyz_to_linear_rgb(x / y, 1.0, (1.0 - x - y) / y);
+        let max = r.max(g).max(b);
+
+        Self::new(
+            linear_to_srgb(r / max),
+            linear_to_srgb(g / max),
+            linear_to_srgb(b / max),
+        )
+    }
+
+    /// Approximate color of monochromatic light at `nm` nanometers
+    ///
+    /// Uses Dan Bruton's piecewise-linear fit of the visible spectrum with
+    /// a 0.8 gamma. Unlike the original, intensity fades all the way to
+    /// black across 380 - 420 nm and 700 - 780 nm, so the curve is
+    /// continuous and anything outside 380 - 780 nm is black. Sweeping the
+    /// wavelength therefore yields a seamless gradient, as in
+    /// [`ColorMap::spectrum`](crate::ColorMap::spectrum).
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// let green = Color::from_wavelength(550.0);
+    /// assert!(green.g > green.r && green.g > green.b);
+    /// assert_eq!(Color::from_wavelength(800.0), Color::BLACK);
+    /// ```
+    pub fn from_wavelength(nm: f64) -> Self {
+        let (r, g, b) = match nm {
+            w if (380.0..440.0).contains(&w) => ((440.0 - w) / 60.0, 0.0, 1.0),
+            w if (440.0..490.0).contains(&w) => (0.0, (w - 440.0) / 50.0, 1.0),
+            w if (490.0..510.0).contains(&w) => (0.0, 1.0, (510.0 - w) / 20.0),
+            w if (510.0..580.0).contains(&w) => ((w - 510.0) / 70.0, 1.0, 0.0),
+            w if (580.0..645.0).contains(&w) => (1.0, (645.0 - w) / 65.0, 0.0),
+            w if (645.0..=780.0).contains(&w) => (1.0, 0.0, 0.0),
+            _ => return Self::BLACK,
+        };
+
+        let intensity = if nm < 420.0 {
+            (nm - 380.0) / 40.0
+        } else if nm > 700.0 {
+            (780.0 - nm) / 80.0
+        } else {
+            1.0
+        };
+        let channel = |c: f64| ((c * intensity).powf(0.8) * 255.0).round() as u8;
+
+        Self::new(channel(r), channel(g), channel(b))
+    }
+
     /// Pure black color (0, 0, 0)
-    pub fn black() -> Self {
+    pub const fn black() -> Self {
         Self::new(0, 0, 0)
     }
 
     /// Pure white color (255, 255, 255)
-    pub fn white() -> Self {
+    pub const fn white() -> Self {
         Self::new(255, 255, 255)
     }
 
@@ -88,6 +526,12 @@ impl Color {
     /// ```
     pub fn from_hex(hex: &str) -> crate::error::Result<Self> {
         let hex = hex.trim().trim_start_matches('#');
+        // Checked up front so the slicing below stays on char boundaries
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(crate::error::ColorMapError::InvalidHexColor(
+                hex.to_string(),
+            ));
+        }
         
         match hex.len() {
             3 => {
@@ -114,6 +558,14 @@ impl Color {
         }
     }
 
+    /// Look up one of the 148 CSS named colors (e.g., "red", "CornflowerBlue")
+    ///
+    /// Matching is case-insensitive and ignores surrounding whitespace.
+    pub fn from_name(name: &str) -> crate::error::Result<Self> {
+        named::lookup(name)
+            .ok_or_else(|| crate::error::ColorMapError::InvalidColor(name.to_string()))
+    }
+
     /// Convert a Color to a hex string (e.g., "#FF5733")
     ///
     /// # Examples
@@ -124,85 +576,1393 @@ impl Color {
     /// assert_eq!(color.to_hex(), "#FF5733");
     /// ```
     pub fn to_hex(&self) -> String {
-        format!("#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+        format!("#{:X}", self)
     }
 
-    /// Linear interpolation between two colors
+    /// Parse a CSS color string
     ///
-    /// # Arguments
-    /// * `other` - The target color to interpolate towards
-    /// * `t` - Interpolation factor (0.0 = self, 1.0 = other)
-    pub fn lerp(&self, other: &Color, t: f64) -> Color {
-        let t = t.clamp(0.0, 1.0);
-        Color {
-            r: (self.r as f64 + (other.r as f64 - self.r as f64) * t) as u8,
-            g: (self.g as f64 + (other.g as f64 - self.g as f64) * t) as u8,
-            b: (self.b as f64 + (other.b as f64 - self.b as f64) * t) as u8,
+    /// Supports the following formats:
+    /// - `rgb()` / `rgba()` with numbers (0-255) or percentages, in either
+    ///   the comma syntax or the space syntax with `/ alpha`
+    /// - `hsl()` / `hsla()` with a hue (degrees, or `deg`/`grad`/`rad`/`turn`)
+    ///   and percentage saturation and lightness
+    /// - `#RGB` and `#RRGGBB` (via [`Color::from_hex`])
+    /// - CSS named colors (via [`Color::from_name`])
+    ///
+    /// Out-of-range components are clamped as CSS specifies. Alpha is
+    /// accepted but ignored; use [`Rgba::from_css_string`] to keep it.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// assert_eq!(Color::from_css_string("rgb(255, 87, 51)").unwrap(), Color::new(255, 87, 51));
+    /// assert_eq!(Color::from_css_string("hsl(14 100% 60%)").unwrap(), Color::new(255, 99, 51));
+    /// assert_eq!(Color::from_css_string("#FF5733").unwrap(), Color::new(255, 87, 51));
+    /// ```
+    pub fn from_css_string(s: &str) -> crate::error::Result<Self> {
+        let trimmed = s.trim();
+
+        if trimmed.starts_with('#') {
+            Self::from_hex(trimmed)
+        } else if trimmed.contains('(') {
+            css::parse_functional(trimmed).map(|(color, _)| color)
+        } else {
+            Self::from_name(trimmed)
         }
     }
-}
 
-impl std::fmt::Display for Color {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "RGB({},{},{})", self.r, self.g, self.b)
+    /// Convert a Color to a CSS `rgb()` string (e.g., "rgb(255, 87, 51)")
+    ///
+    /// Same as [`Color::to_css_rgb`].
+    pub fn to_css_string(&self) -> String {
+        self.to_css_rgb()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Format as a CSS `rgb()` string (e.g., "rgb(255, 87, 51)")
+    pub fn to_css_rgb(&self) -> String {
+        format!("rgb({}, {}, {})", self.r, self.g, self.b)
+    }
 
-    #[test]
-    fn test_color_creation() {
-        let color = Color::new(255, 128, 64);
-        assert_eq!(color.r, 255);
-        assert_eq!(color.g, 128);
-        assert_eq!(color.b, 64);
+    /// Format as a CSS `hsl()` string (e.g., "hsl(14.1, 100%, 60%)")
+    ///
+    /// Hue, saturation and lightness are rounded to one decimal place, with
+    /// a trailing `.0` dropped, so regenerated CSS stays stable.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// assert_eq!(Color::new(255, 99, 51).to_css_hsl(), "hsl(14.1, 100%, 60%)");
+    /// assert_eq!(Color::new(70, 130, 180).to_css_hsl(), "hsl(207.3, 44%, 49%)");
+    /// ```
+    pub fn to_css_hsl(&self) -> String {
+        let (h, s, l) = self.to_hsl();
+        format!(
+            "hsl({}, {}%, {}%)",
+            css_decimal(h),
+            css_decimal(s * 100.0),
+            css_decimal(l * 100.0)
+        )
     }
 
-    #[test]
-    fn test_color_constants() {
-        let black = Color::black();
-        assert_eq!(black.r, 0);
-        assert_eq!(black.g, 0);
-        assert_eq!(black.b, 0);
+    /// Pack into a `0xAARRGGBB` integer with full alpha (`0xFFRRGGBB`)
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// assert_eq!(Color::new(0x12, 0x34, 0x56).to_argb_u32(), 0xFF123456);
+    /// ```
+    pub const fn to_argb_u32(&self) -> u32 {
+        0xFF00_0000 | (self.r as u32) << 16 | (self.g as u32) << 8 | self.b as u32
+    }
 
-        let white = Color::white();
-        assert_eq!(white.r, 255);
-        assert_eq!(white.g, 255);
-        assert_eq!(white.b, 255);
+    /// Unpack a `0xAARRGGBB` integer, ignoring the alpha byte
+    pub const fn from_argb_u32(v: u32) -> Self {
+        Self::new((v >> 16) as u8, (v >> 8) as u8, v as u8)
     }
 
-    #[test]
-    fn test_lerp() {
-        let red = Color::new(255, 0, 0);
-        let blue = Color::new(0, 0, 255);
+    /// Pack into a `0xRRGGBBAA` integer with full alpha (`0xRRGGBBFF`)
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// assert_eq!(Color::new(0x12, 0x34, 0x56).to_rgba_u32(), 0x123456FF);
+    /// ```
+    pub const fn to_rgba_u32(&self) -> u32 {
+        (self.r as u32) << 24 | (self.g as u32) << 16 | (self.b as u32) << 8 | 0xFF
+    }
 
-        let mid = red.lerp(&blue, 0.5);
-        assert_eq!(mid.r, 127);
-        assert_eq!(mid.g, 0);
-        assert_eq!(mid.b, 127);
+    /// Unpack a `0xRRGGBBAA` integer, ignoring the alpha byte
+    pub const fn from_rgba_u32(v: u32) -> Self {
+        Self::new((v >> 24) as u8, (v >> 16) as u8, (v >> 8) as u8)
+    }
 
-        let at_red = red.lerp(&blue, 0.0);
-        assert_eq!(at_red.r, 255);
+    /// Reduce each channel to the given bit depth and expand back to 8 bits
+    ///
+    /// Values are rounded to the nearest representable level, so quantizing
+    /// an already quantized color is a no-op. Bit depths are clamped to
+    /// 0 - 8; a depth of 0 zeroes the channel.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// // One bit per channel leaves only the eight corners of the RGB cube
+    /// assert_eq!(Color::new(200, 100, 30).quantize(1, 1, 1), Color::new(255, 0, 0));
+    /// ```
+    pub fn quantize(&self, bits_r: u8, bits_g: u8, bits_b: u8) -> Color {
+        let channel = |c: u8, bits: u8| expand_channel(reduce_channel(c, bits), bits);
+        Color {
+            r: channel(self.r, bits_r),
+            g: channel(self.g, bits_g),
+            b: channel(self.b, bits_b),
+        }
+    }
 
-        let at_blue = red.lerp(&blue, 1.0);
-        assert_eq!(at_blue.b, 255);
+    /// Pack into 16-bit RGB565 (`RRRRRGGGGGGBBBBB`), rounding each channel
+    pub fn to_rgb565(&self) -> u16 {
+        let r = reduce_channel(self.r, 5) as u16;
+        let g = reduce_channel(self.g, 6) as u16;
+        let b = reduce_channel(self.b, 5) as u16;
+        r << 11 | g << 5 | b
     }
 
-    #[test]
-    fn test_hsv_conversion() {
-        // Pure red (H=0)
-        let red = Color::from_hsv(0.0, 1.0, 1.0);
-        assert_eq!(red.r, 255);
-        assert_eq!(red.g, 0);
-        assert_eq!(red.b, 0);
+    /// Unpack a 16-bit RGB565 value, expanding each channel to 8 bits
+    ///
+    /// `Color::from_rgb565(c.to_rgb565())` equals `c.quantize(5, 6, 5)`.
+    pub fn from_rgb565(v: u16) -> Self {
+        Self::new(
+            expand_channel((v >> 11) as u8 & 0x1F, 5),
+            expand_channel((v >> 5) as u8 & 0x3F, 6),
+            expand_channel(v as u8 & 0x1F, 5),
+        )
+    }
 
-        // Pure green (H=120)
-        let green = Color::from_hsv(120.0, 1.0, 1.0);
-        assert_eq!(green.r, 0);
-        assert_eq!(green.g, 255);
-        assert_eq!(green.b, 0);
+    /// Snap each channel to the nearest multiple of 51 (the 216-color web-safe palette)
+    pub fn to_web_safe(&self) -> Color {
+        let channel = |c: u8| ((c as f64 / 51.0).round() * 51.0) as u8;
+        Color {
+            r: channel(self.r),
+            g: channel(self.g),
+            b: channel(self.b),
+        }
+    }
+
+    /// Channels as an `[r, g, b]` array
+    pub const fn to_array(&self) -> [u8; 3] {
+        [self.r, self.g, self.b]
+    }
+
+    /// Create a color from an `[r, g, b]` array
+    pub const fn from_array(rgb: [u8; 3]) -> Self {
+        Self::new(rgb[0], rgb[1], rgb[2])
+    }
+
+    /// Channels as an `[r, g, b, a]` array with full alpha
+    pub const fn to_rgba_array(&self) -> [u8; 4] {
+        [self.r, self.g, self.b, 255]
+    }
+
+    /// Create a color from an `[r, g, b, a]` array, ignoring alpha
+    pub const fn from_rgba_array(rgba: [u8; 4]) -> Self {
+        Self::new(rgba[0], rgba[1], rgba[2])
+    }
+
+    /// Linear interpolation between two colors
+    ///
+    /// # Arguments
+    /// * `other` - The target color to interpolate towards
+    /// * `t` - Interpolation factor (0.0 = self, 1.0 = other)
+    pub fn lerp(&self, other: &Color, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        Color {
+            r: (self.r as f64 + (other.r as f64 - self.r as f64) * t) as u8,
+            g: (self.g as f64 + (other.g as f64 - self.g as f64) * t) as u8,
+            b: (self.b as f64 + (other.b as f64 - self.b as f64) * t) as u8,
+        }
+    }
+
+    /// `n` colors evenly interpolated from `self` to `other`, both included
+    ///
+    /// Uses [`Color::lerp`]. `n = 1` yields just `self` and `n = 0` nothing.
+    /// The iterator knows its length and can be reversed.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// let ramp: Vec<Color> = Color::BLACK.steps(&Color::WHITE, 3).collect();
+    /// assert_eq!(ramp, vec![Color::BLACK, Color::new(127, 127, 127), Color::WHITE]);
+    /// ```
+    pub fn steps(
+        &self,
+        other: &Color,
+        n: usize,
+    ) -> impl DoubleEndedIterator<Item = Color> + ExactSizeIterator {
+        let (from, to) = (*self, *other);
+        let last = n.saturating_sub(1).max(1) as f64;
+        (0..n).map(move |i| from.lerp(&to, i as f64 / last))
+    }
+
+    /// Linear interpolation in linear light (gamma-correct)
+    ///
+    /// [`Color::lerp`] interpolates the gamma-encoded channel values, which
+    /// makes midpoints look darker than they should: halfway between green
+    /// and magenta it gives a murky `(127, 127, 127)`. Decoding to linear
+    /// light first gives a midpoint with the perceived brightness of the
+    /// endpoints instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// let green = Color::new(0, 255, 0);
+    /// let magenta = Color::new(255, 0, 255);
+    /// assert_eq!(green.lerp(&magenta, 0.5), Color::new(127, 127, 127));
+    /// assert_eq!(green.lerp_srgb_linear(&magenta, 0.5), Color::new(188, 188, 188));
+    /// ```
+    pub fn lerp_srgb_linear(&self, other: &Color, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| {
+            let a = srgb_to_linear(a);
+            let b = srgb_to_linear(b);
+            linear_to_srgb(a + (b - a) * t)
+        };
+
+        Color {
+            r: channel(self.r, other.r),
+            g: channel(self.g, other.g),
+            b: channel(self.b, other.b),
+        }
+    }
+
+    /// Interpolate in HSV space, taking the hue around the wheel along `hue_path`
+    ///
+    /// Hue, saturation and value are interpolated separately, which keeps
+    /// saturated endpoints from passing through gray. A gray endpoint takes
+    /// its hue from the other endpoint so the interpolation doesn't spin
+    /// through unrelated hues.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, HuePath};
+    ///
+    /// let red = Color::new(255, 0, 0);
+    /// let blue = Color::new(0, 0, 255);
+    /// assert_eq!(red.lerp_hsv(&blue, 0.5, HuePath::Shorter), Color::new(255, 0, 255));
+    /// assert_eq!(red.lerp_hsv(&blue, 0.5, HuePath::Longer), Color::new(0, 255, 0));
+    /// ```
+    pub fn lerp_hsv(&self, other: &Color, t: f64, hue_path: HuePath) -> Color {
+        ColorF::from(*self)
+            .lerp_hsv(&ColorF::from(*other), t, hue_path)
+            .to_color()
+    }
+
+    /// Interpolate between two colors in Oklab (`t` clamped to 0.0 - 1.0)
+    ///
+    /// Blending in a perceptual space keeps lightness changing evenly and
+    /// avoids the dark, desaturated middle that RGB blends of complementary
+    /// colors pass through.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// let (yellow, blue) = (Color::new(255, 255, 0), Color::new(0, 0, 255));
+    /// let rgb = yellow.lerp(&blue, 0.5);
+    /// let oklab = yellow.lerp_oklab(&blue, 0.5);
+    /// assert_eq!(rgb, Color::new(127, 127, 127));
+    /// assert!(oklab.to_oklab().0 > rgb.to_oklab().0);
+    /// ```
+    pub fn lerp_oklab(&self, other: &Color, t: f64) -> Color {
+        ColorF::from(*self)
+            .lerp_oklab(&ColorF::from(*other), t)
+            .to_color()
+    }
+
+    /// Interpolate in Oklch along `hue_path` (`t` clamped to 0.0 - 1.0)
+    ///
+    /// Lightness and chroma are interpolated linearly and hue along the
+    /// chosen arc. Like [`Color::lerp_hsv`], a gray endpoint takes its hue
+    /// from the other endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, HuePath};
+    ///
+    /// // Red to blue the short way passes through purple, not green
+    /// let mid = Color::RED.lerp_oklch(&Color::BLUE, 0.5, HuePath::Shorter);
+    /// assert!(mid.r > mid.g && mid.b > mid.g);
+    /// ```
+    pub fn lerp_oklch(&self, other: &Color, t: f64, hue_path: HuePath) -> Color {
+        ColorF::from(*self)
+            .lerp_oklch(&ColorF::from(*other), t, hue_path)
+            .to_color()
+    }
+
+    /// Simulate how the color appears with a color vision deficiency
+    ///
+    /// Uses the Machado et al. (2009) matrices applied in linear RGB.
+    /// `severity` ranges from 0.0 (normal vision, returns the color
+    /// unchanged) to 1.0 (dichromacy); values in between blend linearly
+    /// toward the full-strength matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, ColorVisionDeficiency};
+    ///
+    /// let red = Color::new(255, 0, 0);
+    /// let seen = red.simulate_cvd(ColorVisionDeficiency::Deuteranopia, 1.0);
+    /// assert!(seen.g > 100); // red reads as a dull yellow
+    /// ```
+    pub fn simulate_cvd(&self, kind: ColorVisionDeficiency, severity: f64) -> Color {
+        cvd::simulate(self, kind, severity)
+    }
+
+    /// Simulate full dichromacy, same as `simulate_cvd(cbtype, 1.0)`
+    pub fn simulate_colorblindness(&self, cbtype: ColorBlindType) -> Color {
+        self.simulate_cvd(cbtype, 1.0)
+    }
+
+    /// Convert to a gray with equal channels using the given weighting
+    ///
+    /// Weights are applied directly to the gamma-encoded channel values.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, GrayscaleMethod};
+    ///
+    /// let gray = Color::new(255, 0, 0).to_grayscale(GrayscaleMethod::Luminosity);
+    /// assert_eq!(gray, Color::new(76, 76, 76));
+    /// ```
+    pub fn to_grayscale(&self, method: GrayscaleMethod) -> Color {
+        let (wr, wg, wb) = match method {
+            GrayscaleMethod::Average => (1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0),
+            GrayscaleMethod::Luminosity => (0.299, 0.587, 0.114),
+            GrayscaleMethod::Rec709 => (0.2126, 0.7152, 0.0722),
+        };
+        let y = (wr * self.r as f64 + wg * self.g as f64 + wb * self.b as f64).round() as u8;
+        Color::new(y, y, y)
+    }
+
+    /// WCAG relative luminance (0.0 = black, 1.0 = white)
+    ///
+    /// Channels are linearized from sRGB and weighted with
+    /// `0.2126 R + 0.7152 G + 0.0722 B`.
+    pub fn perceived_brightness(&self) -> f64 {
+        0.2126 * srgb_to_linear(self.r)
+            + 0.7152 * srgb_to_linear(self.g)
+            + 0.0722 * srgb_to_linear(self.b)
+    }
+
+    /// Check whether the color is light, i.e. black text reads better on it than white
+    ///
+    /// Uses the WCAG midpoint luminance of 0.179.
+    pub fn is_light(&self) -> bool {
+        self.perceived_brightness() > 0.179
+    }
+
+    /// Check whether the color is dark (the complement of [`Color::is_light`])
+    pub fn is_dark(&self) -> bool {
+        !self.is_light()
+    }
+
+    /// Warm/cool score from -1.0 (cool) to 1.0 (warm)
+    ///
+    /// Hue sets the direction: oranges around 30° score warmest, blues
+    /// around 210° coolest, and greens and purples sit near 0.0. The score
+    /// is scaled by chroma (HSV saturation times value), so grays, black
+    /// and white score exactly 0.0.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// assert_eq!(Color::new(255, 128, 0).warmth().round(), 1.0);
+    /// assert_eq!(Color::new(90, 90, 90).warmth(), 0.0);
+    /// assert!(Color::BLUE.warmth() < -0.8);
+    /// ```
+    pub fn warmth(&self) -> f64 {
+        let (h, s, v) = self.to_hsv();
+        let chroma = s * v;
+        if chroma == 0.0 {
+            return 0.0;
+        }
+        chroma * (h - 30.0).to_radians().cos()
+    }
+
+    /// Check whether [`Color::warmth`] is above the neutral band
+    /// ([`Color::WARMTH_NEUTRAL_BAND`])
+    pub fn is_warm(&self) -> bool {
+        self.warmth() > Self::WARMTH_NEUTRAL_BAND
+    }
+
+    /// Check whether [`Color::warmth`] is below the neutral band
+    /// ([`Color::WARMTH_NEUTRAL_BAND`])
+    pub fn is_cool(&self) -> bool {
+        self.warmth() < -Self::WARMTH_NEUTRAL_BAND
+    }
+
+    /// WCAG 2.x contrast ratio between two colors (1.0 to 21.0)
+    ///
+    /// The result is symmetric; the lighter color is always the numerator.
+    pub fn contrast_ratio(&self, other: &Color) -> f64 {
+        let l1 = self.perceived_brightness();
+        let l2 = other.perceived_brightness();
+        (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05)
+    }
+
+    /// Black or white, whichever is more readable as text on this color
+    ///
+    /// Follows [`Color::is_light`], so the choice always has the higher
+    /// WCAG contrast ratio of the two.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// assert_eq!(Color::new(255, 255, 0).contrasting_text_color(), Color::black());
+    /// assert_eq!(Color::new(0, 0, 128).contrasting_text_color(), Color::white());
+    /// ```
+    pub fn contrasting_text_color(&self) -> Color {
+        if self.is_light() {
+            Color::black()
+        } else {
+            Color::white()
+        }
+    }
+
+    /// The candidate with the highest WCAG contrast ratio against this color
+    ///
+    /// Ties go to the earlier candidate. With no candidates this falls back
+    /// to [`Color::contrasting_text_color`].
+    pub fn best_text_color(&self, candidates: &[Color]) -> Color {
+        let mut best: Option<(Color, f64)> = None;
+        for candidate in candidates {
+            let ratio = self.contrast_ratio(candidate);
+            if best.is_none_or(|(_, best_ratio)| ratio > best_ratio) {
+                best = Some((*candidate, ratio));
+            }
+        }
+
+        best.map_or_else(|| self.contrasting_text_color(), |(color, _)| color)
+    }
+
+    /// Invert each channel (`255 - c`), producing a photographic negative
+    ///
+    /// Inverting twice returns the original color.
+    pub fn inverted(&self) -> Color {
+        Color::new(255 - self.r, 255 - self.g, 255 - self.b)
+    }
+
+    /// Complementary color: hue rotated by 180° with saturation and value preserved
+    ///
+    /// Computed exactly as `max + min - c` per channel, which is the HSV
+    /// half-turn without any floating-point round trip. Grays (including
+    /// black and white) are their own complement.
+    pub fn complement(&self) -> Color {
+        let max = self.r.max(self.g).max(self.b) as u16;
+        let min = self.r.min(self.g).min(self.b) as u16;
+        let channel = |c: u8| (max + min - c as u16) as u8;
+        Color::new(channel(self.r), channel(self.g), channel(self.b))
+    }
+
+    /// Complementary color: the HSV hue rotated by 180°
+    ///
+    /// Goes through [`Color::rotate_hue`] like the other harmony helpers,
+    /// so it can differ from the exact [`Color::complement`] by one step
+    /// per channel.
+    pub fn complementary(&self) -> Color {
+        self.rotate_hue(180.0)
+    }
+
+    /// Analogous colors: the hue rotated by `-angle` and `+angle` degrees
+    ///
+    /// Like all harmony helpers this rotates the HSV hue with
+    /// [`Color::rotate_hue`], keeping saturation and value; grays have no
+    /// hue and come back unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// let [left, right] = Color::GREEN.analogous(30.0);
+    /// assert_eq!(left, Color::new(128, 255, 0));
+    /// assert_eq!(right, Color::new(0, 255, 128));
+    /// ```
+    pub fn analogous(&self, angle: f64) -> [Color; 2] {
+        [self.rotate_hue(-angle), self.rotate_hue(angle)]
+    }
+
+    /// Triadic harmony: the hue rotated by 120° and 240°
+    pub fn triadic(&self) -> [Color; 2] {
+        [self.rotate_hue(120.0), self.rotate_hue(240.0)]
+    }
+
+    /// Tetradic (square) harmony: the hue rotated by 90°, 180° and 270°
+    pub fn tetradic(&self) -> [Color; 3] {
+        [
+            self.rotate_hue(90.0),
+            self.rotate_hue(180.0),
+            self.rotate_hue(270.0),
+        ]
+    }
+
+    /// Rotate the hue by `degrees` in HSV space, preserving saturation and value
+    ///
+    /// Any angle is accepted; the resulting hue wraps around the color wheel.
+    /// Grays have no hue and are returned unchanged.
+    pub fn rotate_hue(&self, degrees: f64) -> Color {
+        let (h, s, v) = self.to_hsv();
+        if s == 0.0 {
+            return *self;
+        }
+
+        let (r, g, b) = hsv_to_rgb(h + degrees, s, v);
+        Color {
+            r: (r * 255.0).round() as u8,
+            g: (g * 255.0).round() as u8,
+            b: (b * 255.0).round() as u8,
+        }
+    }
+
+    /// Apply display gamma: each normalized channel is raised to `1 / gamma`
+    ///
+    /// Equivalent to [`Color::apply_gamma_encoding`].
+    pub fn with_gamma(&self, gamma: f64) -> Color {
+        self.apply_gamma_encoding(gamma)
+    }
+
+    /// Gamma-encode a linear color (`channel ^ (1 / gamma)`), brightening midtones
+    ///
+    /// `gamma = 1.0` returns the color unchanged. Gamma values that are not
+    /// finite and positive are treated as 1.0.
+    pub fn apply_gamma_encoding(&self, gamma: f64) -> Color {
+        self.apply_gamma_exponent(gamma.recip())
+    }
+
+    /// Gamma-decode an encoded color (`channel ^ gamma`), darkening midtones
+    ///
+    /// The inverse of [`Color::apply_gamma_encoding`] (up to rounding), with
+    /// the same policy for gamma values that are not finite and positive.
+    pub fn apply_gamma_decoding(&self, gamma: f64) -> Color {
+        self.apply_gamma_exponent(gamma)
+    }
+
+    /// Raise each normalized channel to `exponent`
+    fn apply_gamma_exponent(&self, exponent: f64) -> Color {
+        if exponent == 1.0 || !exponent.is_finite() || exponent <= 0.0 {
+            return *self;
+        }
+
+        let channel = |c: u8| ((c as f64 / 255.0).powf(exponent) * 255.0).round() as u8;
+        Color {
+            r: channel(self.r),
+            g: channel(self.g),
+            b: channel(self.b),
+        }
+    }
+
+    /// Blend another color onto this one using the given blend mode
+    ///
+    /// `self` is the base layer and `other` the blend layer.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{BlendMode, Color};
+    ///
+    /// let gray = Color::new(128, 128, 128);
+    /// assert_eq!(gray.blend(&Color::white(), BlendMode::Multiply), gray);
+    /// ```
+    pub fn blend(&self, other: &Color, mode: BlendMode) -> Color {
+        if let BlendMode::Normal(alpha) = mode {
+            return other.lerp(self, alpha);
+        }
+
+        let channel = |a: u8, b: u8| -> u8 {
+            let a = a as f64 / 255.0;
+            let b = b as f64 / 255.0;
+            let result = match mode {
+                BlendMode::Multiply => a * b,
+                BlendMode::Screen => 1.0 - (1.0 - a) * (1.0 - b),
+                BlendMode::Overlay => {
+                    if a < 0.5 {
+                        2.0 * a * b
+                    } else {
+                        1.0 - 2.0 * (1.0 - a) * (1.0 - b)
+                    }
+                }
+                BlendMode::Darken => a.min(b),
+                BlendMode::Lighten => a.max(b),
+                BlendMode::Add => a + b,
+                BlendMode::Subtract => a - b,
+                BlendMode::Difference => (a - b).abs(),
+                BlendMode::SoftLight => {
+                    if b <= 0.5 {
+                        a - (1.0 - 2.0 * b) * a * (1.0 - a)
+                    } else {
+                        let d = if a <= 0.25 {
+                            ((16.0 * a - 12.0) * a + 4.0) * a
+                        } else {
+                            a.sqrt()
+                        };
+                        a + (2.0 * b - 1.0) * (d - a)
+                    }
+                }
+                BlendMode::Dodge => {
+                    if a == 0.0 {
+                        0.0
+                    } else if b >= 1.0 {
+                        1.0
+                    } else {
+                        a / (1.0 - b)
+                    }
+                }
+                BlendMode::Burn => {
+                    if a >= 1.0 {
+                        1.0
+                    } else if b <= 0.0 {
+                        0.0
+                    } else {
+                        1.0 - (1.0 - a) / b
+                    }
+                }
+                BlendMode::Normal(_) => unreachable!("handled above"),
+            };
+            (result.clamp(0.0, 1.0) * 255.0).round() as u8
+        };
+
+        Color {
+            r: channel(self.r, other.r),
+            g: channel(self.g, other.g),
+            b: channel(self.b, other.b),
+        }
+    }
+
+    /// Add channel by channel, saturating at 255 (same as `self + other`)
+    pub fn saturating_add(&self, other: &Color) -> Color {
+        Color {
+            r: self.r.saturating_add(other.r),
+            g: self.g.saturating_add(other.g),
+            b: self.b.saturating_add(other.b),
+        }
+    }
+
+    /// Subtract channel by channel, saturating at 0 (same as `self - other`)
+    pub fn saturating_sub(&self, other: &Color) -> Color {
+        Color {
+            r: self.r.saturating_sub(other.r),
+            g: self.g.saturating_sub(other.g),
+            b: self.b.saturating_sub(other.b),
+        }
+    }
+
+    /// Multiply every channel by `factor`, rounding and clamping to 0 - 255
+    fn scaled(&self, factor: f64) -> Color {
+        let channel = |c: u8| (c as f64 * factor).round().clamp(0.0, 255.0) as u8;
+        Color {
+            r: channel(self.r),
+            g: channel(self.g),
+            b: channel(self.b),
+        }
+    }
+
+    /// Weighted average of any number of colors
+    ///
+    /// Weights are normalized so they don't need to sum to 1.0. Negative
+    /// weights are treated as zero, and if the total weight is zero the
+    /// result is black. Channels are averaged as gamma-encoded values;
+    /// see [`Color::mix_linear`] for averaging in linear light.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// let red = Color::new(255, 0, 0);
+    /// let blue = Color::new(0, 0, 255);
+    /// assert_eq!(Color::mix(&[(red, 3.0), (blue, 1.0)]), Color::new(191, 0, 64));
+    /// ```
+    pub fn mix(colors: &[(Color, f64)]) -> Color {
+        Self::weighted_average(colors, |c| c as f64, |c| c.clamp(0.0, 255.0).round() as u8)
+    }
+
+    /// Equal-weight average of any number of colors
+    ///
+    /// The same as [`Color::mix`] with every weight set to 1.0, so an empty
+    /// slice gives black. Mixing pigments or light is not modeled: red,
+    /// green and blue average to a mid gray rather than white.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// let gray = Color::average(&[Color::RED, Color::GREEN, Color::BLUE]);
+    /// assert_eq!(gray, Color::new(85, 85, 85));
+    /// ```
+    pub fn average(colors: &[Color]) -> Color {
+        let weighted: Vec<(Color, f64)> = colors.iter().map(|&color| (color, 1.0)).collect();
+        Self::mix(&weighted)
+    }
+
+    /// Weighted average of any number of colors, computed in linear light
+    ///
+    /// Averaging linear values avoids the dark bands produced by averaging
+    /// gamma-encoded channels. Weights follow the same rules as [`Color::mix`].
+    pub fn mix_linear(colors: &[(Color, f64)]) -> Color {
+        Self::weighted_average(colors, srgb_to_linear, linear_to_srgb)
+    }
+
+    /// Shared implementation of [`Color::mix`] and [`Color::mix_linear`]
+    fn weighted_average(
+        colors: &[(Color, f64)],
+        decode: impl Fn(u8) -> f64,
+        encode: impl Fn(f64) -> u8,
+    ) -> Color {
+        let mut total = 0.0;
+        let (mut r, mut g, mut b) = (0.0, 0.0, 0.0);
+
+        for (color, weight) in colors {
+            let weight = weight.max(0.0);
+            total += weight;
+            r += decode(color.r) * weight;
+            g += decode(color.g) * weight;
+            b += decode(color.b) * weight;
+        }
+
+        if total <= 0.0 {
+            return Color::black();
+        }
+
+        Color {
+            r: encode(r / total),
+            g: encode(g / total),
+            b: encode(b / total),
+        }
+    }
+
+    /// Euclidean distance between two colors in RGB space
+    ///
+    /// Cheap, but not perceptually uniform. Prefer [`Color::delta_e2000`]
+    /// when the result needs to match what a viewer would notice.
+    pub fn distance_rgb(&self, other: &Color) -> f64 {
+        let dr = self.r as f64 - other.r as f64;
+        let dg = self.g as f64 - other.g as f64;
+        let db = self.b as f64 - other.b as f64;
+        (dr * dr + dg * dg + db * db).sqrt()
+    }
+
+    /// "Redmean" weighted RGB distance
+    ///
+    /// A low-cost approximation that weights the red, green and blue
+    /// differences by the mean red level of the two colors, so green shifts
+    /// count for more than blue ones. Nearly as cheap as
+    /// [`Color::distance_rgb`] and on the same 0 - ~765 scale, while tracking
+    /// perception much better. Use [`Color::delta_e2000`] when accuracy
+    /// matters more than speed. See
+    /// <https://www.compuphase.com/cmetric.htm>.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// let gray = Color::new(128, 128, 128);
+    /// let bluer = Color::new(128, 128, 148);
+    /// let greener = Color::new(128, 148, 128);
+    /// assert!(gray.distance_redmean(&bluer) < gray.distance_redmean(&greener));
+    /// ```
+    pub fn distance_redmean(&self, other: &Color) -> f64 {
+        let mean_r = (self.r as f64 + other.r as f64) / 2.0;
+        let dr = self.r as f64 - other.r as f64;
+        let dg = self.g as f64 - other.g as f64;
+        let db = self.b as f64 - other.b as f64;
+        ((2.0 + mean_r / 256.0) * dr * dr
+            + 4.0 * dg * dg
+            + (2.0 + (255.0 - mean_r) / 256.0) * db * db)
+            .sqrt()
+    }
+
+    /// Largest absolute difference between corresponding channels
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// let a = Color::new(100, 50, 0);
+    /// assert_eq!(a.max_channel_diff(&Color::new(97, 52, 0)), 3);
+    /// ```
+    pub fn max_channel_diff(&self, other: &Color) -> u8 {
+        self.r
+            .abs_diff(other.r)
+            .max(self.g.abs_diff(other.g))
+            .max(self.b.abs_diff(other.b))
+    }
+
+    /// Check whether every channel is within `tolerance` of `other`
+    ///
+    /// Useful when comparing interpolated colors, where rounding makes exact
+    /// equality fragile. See also [`assert_color_approx_eq!`](crate::assert_color_approx_eq).
+    pub fn approx_eq(&self, other: &Color, tolerance: u8) -> bool {
+        self.max_channel_diff(other) <= tolerance
+    }
+
+    /// CIE76 color difference ΔE*ab (Euclidean distance in CIE L*a*b*)
+    ///
+    /// A difference of about 2.3 is the commonly quoted "just noticeable"
+    /// threshold. CIE76 is only an approximation: it overstates differences
+    /// between saturated colors and is superseded by [`Color::delta_e2000`].
+    /// See <https://en.wikipedia.org/wiki/Color_difference#CIE76>.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// let black = Color::new(0, 0, 0);
+    /// assert_eq!(black.distance_delta_e_76(&black), 0.0);
+    /// ```
+    pub fn distance_delta_e_76(&self, other: &Color) -> f64 {
+        let (l1, a1, b1) = rgb_to_lab(self);
+        let (l2, a2, b2) = rgb_to_lab(other);
+        ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+    }
+
+    /// CIE76 color difference; shorthand for [`Color::distance_delta_e_76`]
+    pub fn delta_e76(&self, other: &Color) -> f64 {
+        self.distance_delta_e_76(other)
+    }
+
+    /// CIEDE2000 color difference
+    ///
+    /// The current CIE recommendation for perceptual color difference,
+    /// correcting CIE76 for hue, chroma and lightness non-uniformities.
+    pub fn delta_e2000(&self, other: &Color) -> f64 {
+        ciede2000(rgb_to_lab(self), rgb_to_lab(other))
+    }
+
+    /// Find the closest CSS named color
+    ///
+    /// Returns the lowercase CSS name and its CIEDE2000 distance from this
+    /// color (0.0 for an exact match). Where several names share a color,
+    /// the alphabetically first one is returned (e.g., "aqua" over "cyan").
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// let (name, _) = Color::new(250, 140, 5).nearest_named();
+    /// assert_eq!(name, "darkorange");
+    /// ```
+    pub fn nearest_named(&self) -> (&'static str, f64) {
+        let lab = rgb_to_lab(self);
+        let mut best = ("black", f64::INFINITY);
+
+        for &(name, r, g, b) in named::NAMED_COLORS {
+            let distance = ciede2000(lab, rgb_to_lab(&Color::new(r, g, b)));
+            if distance < best.1 {
+                best = (name, distance);
+            }
+        }
+
+        best
+    }
+}
+
+impl std::str::FromStr for Color {
+    type Err = crate::error::ColorMapError;
+
+    /// Parses hex strings (see [`Color::from_hex`]), CSS color names
+    /// (see [`Color::from_name`]) and CSS functional notation such as
+    /// `rgb(255 87 51)` or `hsl(14, 100%, 60%)` (see [`Color::from_css_string`])
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color;
+    ///
+    /// let color: Color = "#FF5733".parse().unwrap();
+    /// assert_eq!(color, Color::new(255, 87, 51));
+    ///
+    /// let named: Color = "cornflowerblue".parse().unwrap();
+    /// assert_eq!(named, Color::new(100, 149, 237));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains('(') {
+            return Self::from_css_string(s);
+        }
+        Self::from_hex(s)
+            .or_else(|_| Self::from_name(s))
+            .map_err(|_| crate::error::ColorMapError::InvalidColor(s.trim().to_string()))
+    }
+}
+
+/// Formats as `RGB(r,g,b)`, or as a `#RRGGBB` hex string with `{:#}`
+impl std::fmt::Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.to_hex())
+        } else {
+            write!(f, "RGB({},{},{})", self.r, self.g, self.b)
+        }
+    }
+}
+
+/// Formats as a lowercase `rrggbb` hex string without the leading `#`
+impl std::fmt::LowerHex for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// Formats as an uppercase `RRGGBB` hex string without the leading `#`
+impl std::fmt::UpperHex for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+    }
+}
+
+/// RGB color with `f64` channels for multi-step processing
+///
+/// Channels are normalized to 0.0 - 1.0 but are not clamped between
+/// operations, so intermediate results keep their full precision. Convert
+/// back with [`ColorF::to_color`], which clamps and rounds exactly once.
+///
+/// # Examples
+/// ```
+/// use scala_chromatica::{Color, ColorF};
+///
+/// let dark = ColorF::from(Color::new(10, 20, 30));
+/// let restored = dark.to_linear().to_srgb().to_color();
+/// assert_eq!(restored, Color::new(10, 20, 30));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ColorF {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+}
+
+impl ColorF {
+    /// Create a color from normalized channels
+    pub const fn new(r: f64, g: f64, b: f64) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Clamp to 0.0 - 1.0 and round to the nearest 8-bit color
+    pub fn to_color(&self) -> Color {
+        let channel = |c: f64| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Color::new(channel(self.r), channel(self.g), channel(self.b))
+    }
+
+    /// Linear interpolation without intermediate rounding (`t` clamped to 0.0 - 1.0)
+    pub fn lerp(&self, other: &ColorF, t: f64) -> ColorF {
+        let t = t.clamp(0.0, 1.0);
+        ColorF {
+            r: self.r + (other.r - self.r) * t,
+            g: self.g + (other.g - self.g) * t,
+            b: self.b + (other.b - self.b) * t,
+        }
+    }
+
+    /// Interpolate in Oklab without intermediate rounding; see [`Color::lerp_oklab`]
+    pub fn lerp_oklab(&self, other: &ColorF, t: f64) -> ColorF {
+        let t = t.clamp(0.0, 1.0);
+        let (l1, a1, b1) = self.to_oklab();
+        let (l2, a2, b2) = other.to_oklab();
+        ColorF::from_oklab(l1 + (l2 - l1) * t, a1 + (a2 - a1) * t, b1 + (b2 - b1) * t)
+    }
+
+    /// Interpolate in Oklch without intermediate rounding; see [`Color::lerp_oklch`]
+    pub fn lerp_oklch(&self, other: &ColorF, t: f64, hue_path: HuePath) -> ColorF {
+        let t = t.clamp(0.0, 1.0);
+        let (l1, a1, b1) = self.to_oklab();
+        let (l2, a2, b2) = other.to_oklab();
+        let (_, c1, mut h1) = oklab_to_oklch(l1, a1, b1);
+        let (_, c2, mut h2) = oklab_to_oklch(l2, a2, b2);
+
+        // Grays come out of Oklab with a tiny, meaningless hue
+        if c1 < OKLCH_ACHROMATIC {
+            h1 = h2;
+        } else if c2 < OKLCH_ACHROMATIC {
+            h2 = h1;
+        }
+
+        let h = h1 + hue_path.delta(h1, h2) * t;
+        let (l, a, b) = oklch_to_oklab(l1 + (l2 - l1) * t, c1 + (c2 - c1) * t, h);
+        ColorF::from_oklab(l, a, b)
+    }
+
+    /// Convert to Oklab as `(L, a, b)`; see [`Color::to_oklab`]
+    pub fn to_oklab(&self) -> (f64, f64, f64) {
+        let linear = self.to_linear();
+        linear_rgb_to_oklab(linear.r, linear.g, linear.b)
+    }
+
+    /// Create a color from Oklab without clamping to the sRGB gamut
+    pub fn from_oklab(l: f64, a: f64, b: f64) -> Self {
+        let (r, g, b) = oklab_to_linear_rgb(l, a, b);
+        ColorF { r, g, b }.to_srgb()
+    }
+
+    /// Interpolate in HSV space along `hue_path`; see [`Color::lerp_hsv`]
+    pub fn lerp_hsv(&self, other: &ColorF, t: f64, hue_path: HuePath) -> ColorF {
+        let t = t.clamp(0.0, 1.0);
+        let (mut h1, s1, v1) = self.to_hsv();
+        let (mut h2, s2, v2) = other.to_hsv();
+
+        if s1 == 0.0 {
+            h1 = h2;
+        } else if s2 == 0.0 {
+            h2 = h1;
+        }
+
+        let h = (h1 + hue_path.delta(h1, h2) * t).rem_euclid(360.0);
+        ColorF::from_hsv(h, s1 + (s2 - s1) * t, v1 + (v2 - v1) * t)
+    }
+
+    /// Create a color from HSV (hue in degrees, wrapped; saturation and value clamped to 0.0 - 1.0)
+    pub fn from_hsv(h: f64, s: f64, v: f64) -> Self {
+        let (r, g, b) = hsv_to_rgb(h, s, v);
+        Self { r, g, b }
+    }
+
+    /// Convert to HSV as `(hue, saturation, value)`; grays report a hue of 0.0
+    pub fn to_hsv(&self) -> (f64, f64, f64) {
+        rgb_to_hsv(self.r, self.g, self.b)
+    }
+
+    /// Create a color from HSL (hue in degrees, wrapped; saturation and lightness clamped)
+    pub fn from_hsl(h: f64, s: f64, l: f64) -> Self {
+        let (r, g, b) = hsl_to_rgb(h, s, l);
+        Self { r, g, b }
+    }
+
+    /// Convert to HSL as `(hue, saturation, lightness)`; grays report a hue of 0.0
+    pub fn to_hsl(&self) -> (f64, f64, f64) {
+        rgb_to_hsl(self.r, self.g, self.b)
+    }
+
+    /// Decode sRGB-encoded channels to linear light
+    pub fn to_linear(&self) -> ColorF {
+        ColorF::new(
+            decode_srgb(self.r),
+            decode_srgb(self.g),
+            decode_srgb(self.b),
+        )
+    }
+
+    /// Encode linear-light channels as sRGB (the inverse of [`ColorF::to_linear`])
+    pub fn to_srgb(&self) -> ColorF {
+        ColorF::new(
+            encode_srgb(self.r),
+            encode_srgb(self.g),
+            encode_srgb(self.b),
+        )
+    }
+
+    /// Tonemap linear HDR radiance with the Reinhard operator `x / (1 + x)`
+    ///
+    /// Channels are scaled by `exposure` first. The result is sRGB-encoded,
+    /// so a radiance of 1.0 at exposure 1.0 maps to 50% linear gray.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, ColorF};
+    ///
+    /// let radiance = ColorF::new(1.0, 1.0, 1.0);
+    /// assert_eq!(radiance.tonemap_reinhard(1.0), Color::new(188, 188, 188));
+    /// ```
+    pub fn tonemap_reinhard(&self, exposure: f64) -> Color {
+        self.tonemap(|x| {
+            let x = x * exposure.max(0.0);
+            x / (1.0 + x)
+        })
+    }
+
+    /// Tonemap linear HDR radiance with the ACES filmic curve
+    ///
+    /// Uses Krzysztof Narkowicz's fit of the ACES reference transform, which
+    /// keeps more contrast than Reinhard and saturates to white around 10.0.
+    /// The result is sRGB-encoded.
+    pub fn tonemap_aces(&self) -> Color {
+        self.tonemap(|x| (x * (2.51 * x + 0.03)) / (x * (2.43 * x + 0.59) + 0.14))
+    }
+
+    /// Apply a per-channel tonemapping curve to non-negative radiance and
+    /// encode the result as sRGB
+    fn tonemap(&self, curve: impl Fn(f64) -> f64) -> Color {
+        let channel = |c: f64| curve(c.max(0.0)).clamp(0.0, 1.0);
+        ColorF::new(channel(self.r), channel(self.g), channel(self.b))
+            .to_srgb()
+            .to_color()
+    }
+}
+
+impl From<Color> for ColorF {
+    fn from(color: Color) -> Self {
+        ColorF::new(
+            color.r as f64 / 255.0,
+            color.g as f64 / 255.0,
+            color.b as f64 / 255.0,
+        )
+    }
+}
+
+impl From<ColorF> for Color {
+    /// Same as [`ColorF::to_color`]
+    fn from(color: ColorF) -> Self {
+        color.to_color()
+    }
+}
+
+/// Saturating per-channel addition, see [`Color::saturating_add`]
+///
+/// Channels stop at 255 instead of wrapping, so `200 + 200` is `255`.
+impl std::ops::Add for Color {
+    type Output = Color;
+
+    fn add(self, other: Color) -> Color {
+        self.saturating_add(&other)
+    }
+}
+
+/// Saturating per-channel subtraction, see [`Color::saturating_sub`]
+///
+/// Channels stop at 0 instead of wrapping, so `50 - 100` is `0`.
+impl std::ops::Sub for Color {
+    type Output = Color;
+
+    fn sub(self, other: Color) -> Color {
+        self.saturating_sub(&other)
+    }
+}
+
+/// Scales every channel, rounding and clamping to 0 - 255
+impl std::ops::Mul<f64> for Color {
+    type Output = Color;
+
+    fn mul(self, factor: f64) -> Color {
+        self.scaled(factor)
+    }
+}
+
+/// Per-channel multiply in normalized space (`a * b / 255`, rounded)
+///
+/// Same as [`Color::blend`] with [`BlendMode::Multiply`]: white is the
+/// identity, black absorbs, and the result never exceeds either operand.
+impl std::ops::Mul for Color {
+    type Output = Color;
+
+    fn mul(self, other: Color) -> Color {
+        self.blend(&other, BlendMode::Multiply)
+    }
+}
+
+/// Divides every channel, rounding and clamping to 0 - 255
+///
+/// Dividing by zero gives white for non-zero channels and black for zero ones.
+impl std::ops::Div<f64> for Color {
+    type Output = Color;
+
+    fn div(self, divisor: f64) -> Color {
+        let channel = |c: u8| {
+            if c == 0 {
+                0
+            } else {
+                (c as f64 / divisor).round().clamp(0.0, 255.0) as u8
+            }
+        };
+        Color {
+            r: channel(self.r),
+            g: channel(self.g),
+            b: channel(self.b),
+        }
+    }
+}
+
+/// Assert that two colors are equal within a per-channel tolerance
+///
+/// Panics with both colors and the largest channel difference when the
+/// check fails. An optional trailing format message is appended.
+///
+/// # Examples
+/// ```
+/// use scala_chromatica::{assert_color_approx_eq, Color};
+///
+/// assert_color_approx_eq!(Color::new(127, 128, 129), Color::new(128, 128, 128), 1);
+/// ```
+#[macro_export]
+macro_rules! assert_color_approx_eq {
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        $crate::assert_color_approx_eq!($left, $right, $tolerance, "")
+    };
+    ($left:expr, $right:expr, $tolerance:expr, $($arg:tt)+) => {{
+        let left: $crate::Color = $left;
+        let right: $crate::Color = $right;
+        let tolerance: u8 = $tolerance;
+        if !left.approx_eq(&right, tolerance) {
+            panic!(
+                "assertion failed: colors differ by {} (tolerance {})\n  left: {:?}\n right: {:?}\n{}",
+                left.max_channel_diff(&right),
+                tolerance,
+                left,
+                right,
+                format_args!($($arg)+)
+            );
+        }
+    }};
+}
+
+/// Format a CSS number with at most one decimal place ("14", "207.3")
+fn css_decimal(value: f64) -> String {
+    let formatted = format!("{:.1}", value);
+    match formatted.strip_suffix(".0") {
+        Some(whole) => whole.to_string(),
+        None => formatted,
+    }
+}
+
+/// Round an 8-bit channel to the nearest level at `bits` of precision
+fn reduce_channel(c: u8, bits: u8) -> u8 {
+    let max = (1u32 << bits.min(8)) - 1;
+    ((c as u32 * max + 127) / 255) as u8
+}
+
+/// Expand a channel level at `bits` of precision back to 8 bits
+fn expand_channel(level: u8, bits: u8) -> u8 {
+    let max = (1u32 << bits.min(8)) - 1;
+    if max == 0 {
+        return 0;
+    }
+    ((level as u32 * 255 + max / 2) / max) as u8
+}
+
+/// CIEDE2000 difference between two L*a*b* colors
+///
+/// Follows Sharma, Wu and Dalal, "The CIEDE2000 Color-Difference Formula:
+/// Implementation Notes, Supplementary Test Data, and Mathematical Observations" (2005).
+fn ciede2000(lab1: (f64, f64, f64), lab2: (f64, f64, f64)) -> f64 {
+    let (l1, a1, b1) = lab1;
+    let (l2, a2, b2) = lab2;
+    let pow25_7 = 25.0_f64.powi(7);
+
+    let c_bar = ((a1 * a1 + b1 * b1).sqrt() + (a2 * a2 + b2 * b2).sqrt()) / 2.0;
+    let g = 0.5 * (1.0 - (c_bar.powi(7) / (c_bar.powi(7) + pow25_7)).sqrt());
+
+    let a1p = (1.0 + g) * a1;
+    let a2p = (1.0 + g) * a2;
+    let c1p = (a1p * a1p + b1 * b1).sqrt();
+    let c2p = (a2p * a2p + b2 * b2).sqrt();
+
+    let hue = |b: f64, a: f64| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+    };
+    let h1p = hue(b1, a1p);
+    let h2p = hue(b2, a2p);
+
+    let delta_lp = l2 - l1;
+    let delta_cp = c2p - c1p;
+    let delta_hp = if c1p * c2p == 0.0 {
+        0.0
+    } else if (h2p - h1p).abs() <= 180.0 {
+        h2p - h1p
+    } else if h2p - h1p > 180.0 {
+        h2p - h1p - 360.0
+    } else {
+        h2p - h1p + 360.0
+    };
+    let delta_big_hp = 2.0 * (c1p * c2p).sqrt() * (delta_hp.to_radians() / 2.0).sin();
+
+    let l_bar_p = (l1 + l2) / 2.0;
+    let c_bar_p = (c1p + c2p) / 2.0;
+    let h_bar_p = if c1p * c2p == 0.0 {
+        h1p + h2p
+    } else if (h1p - h2p).abs() <= 180.0 {
+        (h1p + h2p) / 2.0
+    } else if h1p + h2p < 360.0 {
+        (h1p + h2p + 360.0) / 2.0
+    } else {
+        (h1p + h2p - 360.0) / 2.0
+    };
+
+    let t = 1.0 - 0.17 * (h_bar_p - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_bar_p).to_radians().cos()
+        + 0.32 * (3.0 * h_bar_p + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_bar_p - 63.0).to_radians().cos();
+    let delta_theta = 30.0 * (-((h_bar_p - 275.0) / 25.0).powi(2)).exp();
+    let r_c = 2.0 * (c_bar_p.powi(7) / (c_bar_p.powi(7) + pow25_7)).sqrt();
+    let s_l = 1.0 + 0.015 * (l_bar_p - 50.0).powi(2) / (20.0 + (l_bar_p - 50.0).powi(2)).sqrt();
+    let s_c = 1.0 + 0.045 * c_bar_p;
+    let s_h = 1.0 + 0.015 * c_bar_p * t;
+    let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+
+    let dl = delta_lp / s_l;
+    let dc = delta_cp / s_c;
+    let dh = delta_big_hp / s_h;
+    (dl * dl + dc * dc + dh * dh + r_t * dc * dh).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_creation() {
+        let color = Color::new(255, 128, 64);
+        assert_eq!(color.r, 255);
+        assert_eq!(color.g, 128);
+        assert_eq!(color.b, 64);
+    }
+
+    #[test]
+    fn test_color_constants() {
+        let black = Color::black();
+        assert_eq!(black.r, 0);
+        assert_eq!(black.g, 0);
+        assert_eq!(black.b, 0);
+
+        let white = Color::white();
+        assert_eq!(white.r, 255);
+        assert_eq!(white.g, 255);
+        assert_eq!(white.b, 255);
+    }
+
+    #[test]
+    fn test_steps() {
+        let from = Color::new(10, 200, 30);
+        let to = Color::new(250, 0, 130);
+
+        let steps: Vec<Color> = from.steps(&to, 5).collect();
+        assert_eq!(steps.len(), 5);
+        assert_eq!(steps[0], from);
+        assert_eq!(steps[2], from.lerp(&to, 0.5));
+        assert_eq!(steps[4], to);
+
+        assert_eq!(from.steps(&to, 5).len(), 5);
+        assert_eq!(from.steps(&to, 1).collect::<Vec<_>>(), vec![from]);
+        assert_eq!(from.steps(&to, 0).len(), 0);
+        assert_eq!(from.steps(&to, 2).collect::<Vec<_>>(), vec![from, to]);
+
+        let reversed: Vec<Color> = from.steps(&to, 5).rev().collect();
+        assert_eq!(reversed.first(), Some(&to));
+        assert_eq!(reversed.last(), Some(&from));
+        assert!(reversed.iter().rev().eq(steps.iter()));
+    }
+
+    #[test]
+    fn test_lerp() {
+        let red = Color::new(255, 0, 0);
+        let blue = Color::new(0, 0, 255);
+
+        let mid = red.lerp(&blue, 0.5);
+        assert_eq!(mid.r, 127);
+        assert_eq!(mid.g, 0);
+        assert_eq!(mid.b, 127);
+
+        let at_red = red.lerp(&blue, 0.0);
+        assert_eq!(at_red.r, 255);
+
+        let at_blue = red.lerp(&blue, 1.0);
+        assert_eq!(at_blue.b, 255);
+    }
+
+    #[test]
+    fn test_hsv_conversion() {
+        // Pure red (H=0)
+        let red = Color::from_hsv(0.0, 1.0, 1.0);
+        assert_eq!(red.r, 255);
+        assert_eq!(red.g, 0);
+        assert_eq!(red.b, 0);
+
+        // Pure green (H=120)
+        let green = Color::from_hsv(120.0, 1.0, 1.0);
+        assert_eq!(green.r, 0);
+        assert_eq!(green.g, 255);
+        assert_eq!(green.b, 0);
 
         // Pure blue (H=240)
         let blue = Color::from_hsv(240.0, 1.0, 1.0);
@@ -211,6 +1971,43 @@ mod tests {
         assert_eq!(blue.b, 255);
     }
 
+    #[test]
+    fn test_from_hsv_out_of_range() {
+        // Hues wrap with a euclidean modulo
+        assert_eq!(
+            Color::from_hsv(360.0, 1.0, 1.0),
+            Color::from_hsv(0.0, 1.0, 1.0)
+        );
+        assert_eq!(
+            Color::from_hsv(-120.0, 1.0, 1.0),
+            Color::from_hsv(240.0, 1.0, 1.0)
+        );
+        assert_eq!(
+            Color::from_hsv(720.5, 0.8, 0.9),
+            Color::from_hsv(0.5, 0.8, 0.9)
+        );
+        assert_eq!(
+            Color::from_hsv(-30.0, 1.0, 1.0),
+            Color::from_hsv(330.0, 1.0, 1.0)
+        );
+        assert_eq!(Color::from_hsv(-1e-15, 1.0, 1.0), Color::RED);
+
+        // Saturation and value are clamped
+        assert_eq!(
+            Color::from_hsv(200.0, 1.05, 1.0001),
+            Color::from_hsv(200.0, 1.0, 1.0)
+        );
+        assert_eq!(
+            Color::from_hsv(200.0, -0.1, 0.5),
+            Color::from_hsv(0.0, 0.0, 0.5)
+        );
+        assert_eq!(Color::from_hsv(90.0, 1.0, -0.2), Color::BLACK);
+        assert_eq!(
+            ColorF::from_hsv(0.0, 2.0, 1.5),
+            ColorF::from_hsv(0.0, 1.0, 1.0)
+        );
+    }
+
     #[test]
     fn test_from_hex() {
         // Test #RRGGBB format
@@ -225,40 +2022,499 @@ mod tests {
         assert_eq!(color2.g, 255);
         assert_eq!(color2.b, 0);
 
-        // Test #RGB format
-        let color3 = Color::from_hex("#F0A").unwrap();
-        assert_eq!(color3.r, 255);
-        assert_eq!(color3.g, 0);
-        assert_eq!(color3.b, 170);
+        // Test #RGB format
+        let color3 = Color::from_hex("#F0A").unwrap();
+        assert_eq!(color3.r, 255);
+        assert_eq!(color3.g, 0);
+        assert_eq!(color3.b, 170);
+
+        // Test RGB format without #
+        let color4 = Color::from_hex("C8F").unwrap();
+        assert_eq!(color4.r, 204);
+        assert_eq!(color4.g, 136);
+        assert_eq!(color4.b, 255);
+
+        // Test with whitespace
+        let color5 = Color::from_hex("  #ABCDEF  ").unwrap();
+        assert_eq!(color5.r, 171);
+        assert_eq!(color5.g, 205);
+        assert_eq!(color5.b, 239);
+
+        // Test invalid formats
+        assert!(Color::from_hex("#GGGGGG").is_err());
+        assert!(Color::from_hex("#12345").is_err());
+        assert!(Color::from_hex("").is_err());
+        assert!(Color::from_hex("é1").is_err());
+        assert!(Color::from_hex("#ééé").is_err());
+        assert!(Color::from_hex("+1FF00").is_err());
+    }
+
+    #[test]
+    fn test_to_hex() {
+        let color1 = Color::new(255, 87, 51);
+        assert_eq!(color1.to_hex(), "#FF5733");
+
+        let color2 = Color::new(0, 255, 0);
+        assert_eq!(color2.to_hex(), "#00FF00");
+
+        let color3 = Color::new(255, 0, 170);
+        assert_eq!(color3.to_hex(), "#FF00AA");
+    }
+
+    #[test]
+    fn test_hwb() {
+        // Examples from CSS Color 4
+        assert_eq!(Color::from_hwb(0.0, 0.0, 0.0), Color::new(255, 0, 0));
+        assert_eq!(Color::from_hwb(120.0, 0.0, 0.0), Color::new(0, 255, 0));
+        assert_eq!(Color::from_hwb(240.0, 0.0, 0.0), Color::new(0, 0, 255));
+        assert_eq!(Color::from_hwb(0.0, 0.2, 0.2), Color::new(204, 51, 51));
+        assert_eq!(Color::from_hwb(0.0, 1.0, 0.0), Color::white());
+        assert_eq!(Color::from_hwb(0.0, 0.0, 1.0), Color::black());
+        assert_eq!(Color::from_hwb(-360.0, 0.0, 0.0), Color::new(255, 0, 0));
+
+        // w + b >= 1 gives the normalized gray regardless of hue
+        assert_eq!(Color::from_hwb(90.0, 0.4, 0.6), Color::new(102, 102, 102));
+        assert_eq!(Color::from_hwb(200.0, 0.6, 0.6), Color::new(128, 128, 128));
+
+        let (h, w, b) = Color::new(204, 51, 51).to_hwb();
+        assert!(h.abs() < 1e-9);
+        assert!((w - 0.2).abs() < 1e-9);
+        assert!((b - 0.2).abs() < 1e-9);
+
+        for color in [
+            Color::new(255, 87, 51),
+            Color::new(12, 200, 130),
+            Color::new(90, 30, 220),
+            Color::new(128, 128, 128),
+        ] {
+            let (h, w, b) = color.to_hwb();
+            assert_eq!(Color::from_hwb(h, w, b), color);
+        }
+    }
+
+    #[test]
+    fn test_nearest_named() {
+        // Exact matches report zero distance
+        for name in ["tomato", "rebeccapurple", "white", "aqua"] {
+            let color = Color::from_name(name).unwrap();
+            assert_eq!(color.nearest_named(), (name, 0.0));
+        }
+        assert_eq!(Color::new(0, 255, 255).nearest_named().0, "aqua");
+
+        let cases = [
+            (Color::new(250, 140, 5), "darkorange"),
+            (Color::new(254, 1, 0), "red"),
+            (Color::new(100, 149, 230), "cornflowerblue"),
+            (Color::new(0, 0, 130), "navy"),
+            (Color::new(128, 0, 132), "purple"),
+            (Color::new(3, 2, 2), "black"),
+            (Color::new(100, 100, 100), "dimgray"),
+        ];
+        for (color, expected) in cases {
+            let (name, distance) = color.nearest_named();
+            assert_eq!(name, expected, "{:?}", color);
+            assert!(
+                distance > 0.0 && distance < 3.0,
+                "{} for {:?}",
+                distance,
+                color
+            );
+        }
+    }
+
+    #[test]
+    fn test_arithmetic_operators() {
+        let a = Color::new(200, 200, 200);
+        let b = Color::new(100, 50, 0);
+
+        assert_eq!(a + Color::new(100, 100, 100), Color::white());
+        assert_eq!(a + b, Color::new(255, 250, 200));
+        assert_eq!(b - a, Color::black());
+        assert_eq!(a - b, Color::new(100, 150, 200));
+        assert_eq!(a.saturating_add(&b), a + b);
+        assert_eq!(a.saturating_sub(&b), a - b);
+
+        assert_eq!(a * 0.5, Color::new(100, 100, 100));
+        assert_eq!(b * 3.0, Color::new(255, 150, 0));
+        assert_eq!(a * -1.0, Color::black());
+        assert_eq!(Color::new(3, 5, 7) * 0.5, Color::new(2, 3, 4));
+        assert_eq!(a * 1.0, a);
+        assert_eq!(b * 1.0, b);
+
+        assert_eq!(a * Color::white(), a);
+        assert_eq!(a * Color::black(), Color::black());
+        assert_eq!(a * b, b * a);
+        assert_eq!(
+            Color::new(255, 128, 51) * Color::new(128, 128, 255),
+            Color::new(128, 64, 51)
+        );
+
+        assert_eq!(a / 2.0, Color::new(100, 100, 100));
+        assert_eq!(b / 0.5, Color::new(200, 100, 0));
+        assert_eq!(b / 0.0, Color::new(255, 255, 0));
+    }
+
+    #[test]
+    fn test_lerp_hsv() {
+        let red = Color::new(255, 0, 0);
+        let yellow = Color::new(255, 255, 0);
+        let blue = Color::new(0, 0, 255);
+
+        // Red -> yellow stays among the oranges on the short path
+        for i in 1..10 {
+            let (h, s, v) = red
+                .lerp_hsv(&yellow, i as f64 / 10.0, HuePath::Shorter)
+                .to_hsv();
+            assert!(h > 0.0 && h < 60.0, "hue {}", h);
+            assert!(s > 0.99 && v > 0.99);
+        }
+        assert_eq!(
+            red.lerp_hsv(&yellow, 0.5, HuePath::Shorter),
+            Color::new(255, 128, 0)
+        );
+
+        // Red (0) -> blue (240): shorter goes through magenta, longer through green
+        assert_eq!(
+            red.lerp_hsv(&blue, 0.5, HuePath::Shorter),
+            Color::new(255, 0, 255)
+        );
+        assert_eq!(
+            red.lerp_hsv(&blue, 0.5, HuePath::Longer),
+            Color::new(0, 255, 0)
+        );
+        assert_eq!(
+            red.lerp_hsv(&blue, 0.5, HuePath::Increasing),
+            Color::new(0, 255, 0)
+        );
+        assert_eq!(
+            red.lerp_hsv(&blue, 0.5, HuePath::Decreasing),
+            Color::new(255, 0, 255)
+        );
+        assert_eq!(
+            blue.lerp_hsv(&red, 0.5, HuePath::Increasing),
+            Color::new(255, 0, 255)
+        );
+
+        // Endpoints are reproduced exactly
+        assert_eq!(red.lerp_hsv(&blue, 0.0, HuePath::Longer), red);
+        assert_eq!(red.lerp_hsv(&blue, 1.0, HuePath::Longer), blue);
+
+        // A gray endpoint borrows the other hue instead of spinning from red
+        let gray = Color::new(128, 128, 128);
+        let (h, _, _) = gray.lerp_hsv(&blue, 0.5, HuePath::Longer).to_hsv();
+        assert!((h - 240.0).abs() < 1.0, "hue {}", h);
+    }
+
+    #[test]
+    fn test_packed_conversions() {
+        let color = Color::new(0x12, 0x34, 0x56);
+
+        assert_eq!(color.to_argb_u32(), 0xFF12_3456);
+        assert_eq!(color.to_rgba_u32(), 0x1234_56FF);
+        assert_eq!(Color::from_argb_u32(0xFF12_3456), color);
+        assert_eq!(Color::from_rgba_u32(0x1234_56FF), color);
+        // Alpha is ignored on the way in
+        assert_eq!(Color::from_argb_u32(0x0012_3456), color);
+        assert_eq!(Color::from_rgba_u32(0x1234_5600), color);
+
+        assert_eq!(color.to_array(), [0x12, 0x34, 0x56]);
+        assert_eq!(Color::from_array([0x12, 0x34, 0x56]), color);
+        assert_eq!(color.to_rgba_array(), [0x12, 0x34, 0x56, 0xFF]);
+        assert_eq!(Color::from_rgba_array([0x12, 0x34, 0x56, 0x80]), color);
+
+        assert_eq!(Color::white().to_argb_u32(), u32::MAX);
+        assert_eq!(Color::black().to_rgba_u32(), 0xFF);
+    }
+
+    #[test]
+    fn test_lerp_srgb_linear() {
+        let black = Color::black();
+        let white = Color::white();
+        assert_eq!(black.lerp(&white, 0.5), Color::new(127, 127, 127));
+        assert_eq!(
+            black.lerp_srgb_linear(&white, 0.5),
+            Color::new(188, 188, 188)
+        );
+
+        // Every channel value survives the decode/encode round trip at the ends
+        for v in 0..=255u8 {
+            let a = Color::new(v, 255 - v, v / 2);
+            let b = Color::new(255 - v, v, 200);
+            assert_eq!(a.lerp_srgb_linear(&b, 0.0), a);
+            assert_eq!(a.lerp_srgb_linear(&b, 1.0), b);
+        }
+
+        // t is clamped like lerp
+        assert_eq!(black.lerp_srgb_linear(&white, 2.0), white);
+    }
+
+    #[test]
+    fn test_text_color_choice() {
+        // The WCAG crossover falls between these two grays
+        assert_eq!(
+            Color::new(117, 117, 117).contrasting_text_color(),
+            Color::white()
+        );
+        assert_eq!(
+            Color::new(118, 118, 118).contrasting_text_color(),
+            Color::black()
+        );
+
+        // Around the threshold both methods agree, and the pick really is the better one
+        let black_and_white = [Color::black(), Color::white()];
+        for v in 100..=140u8 {
+            for background in [
+                Color::new(v, v, v),
+                Color::new(v, v, 255),
+                Color::new(255, v, 0),
+            ] {
+                let text = background.contrasting_text_color();
+                assert_eq!(background.best_text_color(&black_and_white), text);
+                assert!(
+                    background.contrast_ratio(&text) >= background.contrast_ratio(&text.inverted())
+                );
+            }
+        }
+
+        let navy = Color::new(0, 0, 128);
+        let candidates = [
+            Color::new(0, 0, 255),
+            Color::new(255, 255, 0),
+            Color::white(),
+        ];
+        assert_eq!(navy.best_text_color(&candidates), Color::white());
+        assert_eq!(
+            navy.best_text_color(&candidates[..2]),
+            Color::new(255, 255, 0)
+        );
+        assert_eq!(navy.best_text_color(&[]), Color::white());
+    }
+
+    #[test]
+    fn test_simulate_cvd() {
+        let kinds = [
+            ColorVisionDeficiency::Protanopia,
+            ColorVisionDeficiency::Deuteranopia,
+            ColorVisionDeficiency::Tritanopia,
+        ];
+
+        // Severity 0 is the identity; achromatic colors are unaffected at any severity
+        for kind in kinds {
+            for color in [
+                Color::new(255, 87, 51),
+                Color::new(12, 200, 130),
+                Color::new(3, 4, 250),
+            ] {
+                assert_eq!(color.simulate_cvd(kind, 0.0), color);
+            }
+            assert_eq!(Color::black().simulate_cvd(kind, 1.0), Color::black());
+            assert!(
+                Color::white()
+                    .simulate_cvd(kind, 1.0)
+                    .distance_rgb(&Color::white())
+                    < 2.0
+            );
+        }
+
+        // Under deuteranopia red and green collapse onto nearly the same hue
+        let red = Color::new(255, 0, 0);
+        let green = Color::new(0, 255, 0);
+        let sim_red = red.simulate_cvd(ColorVisionDeficiency::Deuteranopia, 1.0);
+        let sim_green = green.simulate_cvd(ColorVisionDeficiency::Deuteranopia, 1.0);
+        assert!((sim_red.to_hsv().0 - sim_green.to_hsv().0).abs() < 5.0);
+        assert!(sim_red.delta_e2000(&sim_green) < 0.5 * red.delta_e2000(&green));
+
+        assert_eq!(
+            red.simulate_colorblindness(ColorBlindType::Deuteranopia),
+            sim_red
+        );
+
+        // Partial severity sits between normal and full simulation
+        let partial = red.simulate_cvd(ColorVisionDeficiency::Deuteranopia, 0.5);
+        assert!(partial.g > 0 && partial.g < sim_red.g);
+    }
+
+    #[test]
+    fn test_rgb565_layout() {
+        assert_eq!(Color::new(255, 0, 0).to_rgb565(), 0xF800);
+        assert_eq!(Color::new(0, 255, 0).to_rgb565(), 0x07E0);
+        assert_eq!(Color::new(0, 0, 255).to_rgb565(), 0x001F);
+        assert_eq!(Color::white().to_rgb565(), 0xFFFF);
+        // 128 -> 16/31, 64 -> 16/63, 32 -> 4/31: 10000 010000 00100 = 0x8204
+        assert_eq!(Color::new(128, 64, 32).to_rgb565(), 0x8204);
+
+        assert_eq!(Color::from_rgb565(0xF800), Color::new(255, 0, 0));
+        assert_eq!(Color::from_rgb565(0x07E0), Color::new(0, 255, 0));
+        assert_eq!(Color::from_rgb565(0x8204), Color::new(132, 65, 33));
+    }
+
+    #[test]
+    fn test_quantize_stable() {
+        for v in 0..=255u8 {
+            let color = Color::new(v, v.wrapping_mul(7), 255 - v);
+            let once = color.quantize(5, 6, 5);
+            assert_eq!(once.quantize(5, 6, 5), once);
+            assert_eq!(Color::from_rgb565(color.to_rgb565()), once);
+            assert_eq!(Color::from_rgb565(once.to_rgb565()), once);
+            assert_eq!(color.quantize(8, 8, 8), color);
+            assert_eq!(color.quantize(0, 0, 0), Color::black());
+        }
+
+        assert_eq!(
+            Color::new(100, 150, 200).quantize(2, 2, 2),
+            Color::new(85, 170, 170)
+        );
+    }
+
+    #[test]
+    fn test_web_safe() {
+        assert_eq!(Color::new(0, 25, 26).to_web_safe(), Color::new(0, 0, 51));
+        assert_eq!(
+            Color::new(255, 230, 100).to_web_safe(),
+            Color::new(255, 255, 102)
+        );
+        assert_eq!(
+            Color::new(153, 204, 51).to_web_safe(),
+            Color::new(153, 204, 51)
+        );
+    }
+
+    #[test]
+    fn test_hash_and_ord() {
+        use std::collections::{BTreeSet, HashMap};
+
+        let swatches = [
+            Color::RED,
+            Color::BLUE,
+            Color::RED,
+            Color::new(255, 0, 0),
+            Color::BLUE,
+        ];
+        let mut counts = HashMap::new();
+        for color in swatches {
+            *counts.entry(color).or_insert(0) += 1;
+        }
+        assert_eq!(counts[&Color::RED], 3);
+        assert_eq!(counts[&Color::BLUE], 2);
+
+        // Lexicographic by r, then g, then b
+        let mut palette = vec![
+            Color::new(1, 0, 0),
+            Color::new(0, 2, 0),
+            Color::new(0, 1, 9),
+            Color::new(0, 1, 3),
+        ];
+        palette.sort();
+        assert_eq!(
+            palette,
+            vec![
+                Color::new(0, 1, 3),
+                Color::new(0, 1, 9),
+                Color::new(0, 2, 0),
+                Color::new(1, 0, 0),
+            ]
+        );
+        assert!(Color::black() < Color::white());
+        assert_eq!(
+            Color::new(5, 5, 5).partial_cmp(&Color::new(5, 5, 6)),
+            Some(std::cmp::Ordering::Less)
+        );
+
+        let deduped: BTreeSet<Color> = swatches.into_iter().collect();
+        assert_eq!(
+            deduped.into_iter().collect::<Vec<_>>(),
+            vec![Color::BLUE, Color::RED]
+        );
+    }
+
+    #[test]
+    fn test_color_f_precision() {
+        // Decode to linear light and re-encode: quantizing the intermediate
+        // linear values to 8 bits crushes the shadows into a few bands
+        let mut stepwise = Vec::new();
+        let mut once = Vec::new();
+        for v in 0..=40u8 {
+            let color = ColorF::from(Color::new(v, v, v));
+
+            let linear_u8 = color.to_linear().to_color();
+            stepwise.push(ColorF::from(linear_u8).to_srgb().to_color());
+            once.push(color.to_linear().to_srgb().to_color());
+        }
 
-        // Test RGB format without #
-        let color4 = Color::from_hex("C8F").unwrap();
-        assert_eq!(color4.r, 204);
-        assert_eq!(color4.g, 136);
-        assert_eq!(color4.b, 255);
+        let distinct = |colors: &[Color]| {
+            let mut colors = colors.to_vec();
+            colors.dedup();
+            colors.len()
+        };
+        assert_eq!(distinct(&once), 41);
+        assert!(distinct(&stepwise) < 20);
+        assert_eq!(
+            once,
+            (0..=40).map(|v| Color::new(v, v, v)).collect::<Vec<_>>()
+        );
+    }
 
-        // Test with whitespace
-        let color5 = Color::from_hex("  #ABCDEF  ").unwrap();
-        assert_eq!(color5.r, 171);
-        assert_eq!(color5.g, 205);
-        assert_eq!(color5.b, 239);
+    #[test]
+    fn test_color_f_conversions() {
+        let color = Color::new(255, 87, 51);
+        let f = ColorF::from(color);
+        assert_eq!(f.to_color(), color);
+        assert_eq!(Color::from(f), color);
+        assert_eq!(f.to_hsv(), color.to_hsv());
+        assert_eq!(f.to_hsl(), color.to_hsl());
 
-        // Test invalid formats
-        assert!(Color::from_hex("#GGGGGG").is_err());
-        assert!(Color::from_hex("#12345").is_err());
-        assert!(Color::from_hex("").is_err());
+        let (h, s, l) = f.to_hsl();
+        assert_eq!(ColorF::from_hsl(h, s, l).to_color(), color);
+        let (h, s, v) = f.to_hsv();
+        assert_eq!(ColorF::from_hsv(h + 360.0, s, v).to_color(), color);
+
+        // Out-of-range channels survive until the final conversion
+        let bright = ColorF::new(1.5, -0.25, 0.5);
+        assert_eq!(bright.to_color(), Color::new(255, 0, 128));
+        let mid = ColorF::new(0.0, 0.0, 0.0).lerp(&ColorF::new(1.0, 0.5, 0.25), 0.5);
+        assert_eq!(mid, ColorF::new(0.5, 0.25, 0.125));
     }
 
     #[test]
-    fn test_to_hex() {
-        let color1 = Color::new(255, 87, 51);
-        assert_eq!(color1.to_hex(), "#FF5733");
+    fn test_const_colors() {
+        // Evaluated at compile time; fails to build if any constructor loses `const`
+        const PALETTE: [Color; 4] = [
+            Color::black(),
+            Color::RED,
+            Color::new(1, 2, 3),
+            Color::white(),
+        ];
+        static PRIMARIES: [Color; 3] = [Color::RED, Color::GREEN, Color::BLUE];
 
-        let color2 = Color::new(0, 255, 0);
-        assert_eq!(color2.to_hex(), "#00FF00");
+        assert_eq!(PALETTE[0], Color::BLACK);
+        assert_eq!(PALETTE[3], Color::WHITE);
+        assert_eq!(PRIMARIES[1], Color::from_hex("#00FF00").unwrap());
+        assert_eq!(
+            Color::CYAN,
+            Color::GREEN.blend(&Color::BLUE, BlendMode::Add)
+        );
+        assert_eq!(
+            Color::MAGENTA,
+            Color::RED.blend(&Color::BLUE, BlendMode::Add)
+        );
+        assert_eq!(
+            Color::YELLOW,
+            Color::RED.blend(&Color::GREEN, BlendMode::Add)
+        );
+    }
 
-        let color3 = Color::new(255, 0, 170);
-        assert_eq!(color3.to_hex(), "#FF00AA");
+    #[test]
+    fn test_format_forms() {
+        let color = Color::new(255, 87, 51);
+        assert_eq!(format!("{}", color), "RGB(255,87,51)");
+        assert_eq!(format!("{:#}", color), "#FF5733");
+        assert_eq!(format!("{:x}", color), "ff5733");
+        assert_eq!(format!("{:X}", color), "FF5733");
+
+        // Single-digit channels are zero-padded
+        let dark = Color::new(1, 0, 10);
+        assert_eq!(format!("{:x}", dark), "01000a");
+        assert_eq!(format!("#{:X}", dark), dark.to_hex());
     }
 
     #[test]
@@ -268,4 +2524,909 @@ mod tests {
         let parsed = Color::from_hex(&hex).unwrap();
         assert_eq!(original, parsed);
     }
+
+    #[test]
+    fn test_lab_xyz() {
+        let (l, a, b) = Color::WHITE.to_lab();
+        assert!((l - 100.0).abs() < 1e-3 && a.abs() < 1e-3 && b.abs() < 1e-3);
+        assert_eq!(Color::BLACK.to_lab(), (0.0, 0.0, 0.0));
+
+        // sRGB red is L* 53.24, a* 80.09, b* 67.20
+        let (l, a, b) = Color::RED.to_lab();
+        assert!((l - 53.24).abs() < 0.01);
+        assert!((a - 80.09).abs() < 0.01);
+        assert!((b - 67.20).abs() < 0.01);
+
+        let (x, y, z) = Color::WHITE.to_xyz();
+        assert!((x - 0.95047).abs() < 1e-4 && (y - 1.0).abs() < 1e-4 && (z - 1.08883).abs() < 1e-4);
+
+        // Out-of-gamut input clamps instead of wrapping
+        assert_eq!(Color::from_lab(150.0, 0.0, 0.0), Color::WHITE);
+        assert_eq!(Color::from_xyz(-1.0, -1.0, -1.0), Color::BLACK);
+    }
+
+    #[test]
+    fn test_oklab() {
+        let (l, a, b) = Color::WHITE.to_oklab();
+        assert!((l - 1.0).abs() < 1e-4 && a.abs() < 1e-4 && b.abs() < 1e-4);
+
+        // Reference value for sRGB red from Ottosson's post
+        let (l, a, b) = Color::RED.to_oklab();
+        assert!((l - 0.6279).abs() < 1e-3);
+        assert!((a - 0.2249).abs() < 1e-3);
+        assert!((b - 0.1258).abs() < 1e-3);
+
+        for r in (0..=255).step_by(51) {
+            for g in (0..=255).step_by(51) {
+                for b in (0..=255).step_by(51) {
+                    let color = Color::new(r, g, b);
+                    let (l, a, bb) = color.to_oklab();
+                    assert_eq!(Color::from_oklab(l, a, bb), color);
+                }
+            }
+        }
+        assert_eq!(Color::from_oklab(2.0, 0.0, 0.0), Color::WHITE);
+
+        let (red, blue) = (Color::RED, Color::BLUE);
+        assert_eq!(red.lerp_oklab(&blue, 0.0), red);
+        assert_eq!(red.lerp_oklab(&blue, 1.0), blue);
+        assert_eq!(red.lerp_oklab(&blue, 2.0), blue);
+    }
+
+    #[test]
+    fn test_oklch() {
+        let (l, c, h) = Color::RED.to_oklch();
+        assert!((l - 0.6279).abs() < 1e-3);
+        assert!((c - 0.2577).abs() < 1e-3);
+        assert!((h - 29.23).abs() < 0.1);
+        assert!(Color::new(128, 128, 128).to_oklch().1 < 1e-4);
+
+        for color in [
+            Color::RED,
+            Color::BLUE,
+            Color::new(70, 130, 180),
+            Color::WHITE,
+        ] {
+            let (l, c, h) = color.to_oklch();
+            assert_eq!(Color::from_oklch(l, c, h), color);
+            assert_eq!(Color::from_oklch(l, c, h + 360.0), color);
+        }
+
+        // Red to blue: the short arc passes purple, the long arc passes green
+        let short = Color::RED.lerp_oklch(&Color::BLUE, 0.5, HuePath::Shorter);
+        let long = Color::RED.lerp_oklch(&Color::BLUE, 0.5, HuePath::Longer);
+        assert!(short.g < short.r.min(short.b), "{:?}", short);
+        assert!(long.g > long.r.max(long.b), "{:?}", long);
+        assert_eq!(
+            Color::RED.lerp_oklch(&Color::BLUE, 1.0, HuePath::Longer),
+            Color::BLUE
+        );
+
+        // A gray endpoint doesn't drag the hue around
+        let gray = Color::new(128, 128, 128);
+        let (_, _, h) = Color::RED
+            .lerp_oklch(&gray, 0.5, HuePath::Shorter)
+            .to_oklch();
+        assert!((h - 29.23).abs() < 2.0, "hue {}", h);
+    }
+
+    #[test]
+    fn test_lab_xyz_roundtrip() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let color = Color::new(r, g, b);
+                    let (l, a, bb) = color.to_lab();
+                    assert_eq!(Color::from_lab(l, a, bb), color);
+                    let (x, y, z) = color.to_xyz();
+                    assert_eq!(Color::from_xyz(x, y, z), color);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_hdr() {
+        assert_eq!(
+            Color::from_hdr(ColorF::new(0.5, 3.0, -1.0)),
+            Color::new(188, 255, 0)
+        );
+        assert_eq!(Color::from_hdr(ColorF::new(0.0, 0.0, 0.0)), Color::BLACK);
+    }
+
+    #[test]
+    fn test_tonemap_known_values() {
+        let gray = |v: f64| ColorF::new(v, v, v);
+        let reinhard: Vec<u8> = [0.0, 0.25, 1.0, 4.0, 100.0]
+            .iter()
+            .map(|&v| gray(v).tonemap_reinhard(1.0).r)
+            .collect();
+        assert_eq!(reinhard, vec![0, 124, 188, 231, 254]);
+        assert_eq!(gray(1.0).tonemap_reinhard(2.0).r, 213);
+        assert_eq!(gray(1.0).tonemap_reinhard(-1.0), Color::BLACK);
+
+        let aces: Vec<u8> = [0.0, 0.25, 1.0, 4.0, 100.0]
+            .iter()
+            .map(|&v| gray(v).tonemap_aces().r)
+            .collect();
+        assert_eq!(aces, vec![0, 165, 232, 252, 255]);
+        assert_eq!(ColorF::new(-2.0, 0.0, 0.0).tonemap_aces(), Color::BLACK);
+    }
+
+    #[test]
+    fn test_tonemap_monotonic() {
+        let warm = ColorF::new(1.0, 0.6, 0.2);
+        let mut previous = (0.0, 0.0);
+        for i in 0..=500 {
+            let scale = i as f64 * 0.05;
+            let hdr = ColorF::new(warm.r * scale, warm.g * scale, warm.b * scale);
+            let reinhard = hdr.tonemap_reinhard(1.0).perceived_brightness();
+            let aces = hdr.tonemap_aces().perceived_brightness();
+            assert!(
+                reinhard >= previous.0 && aces >= previous.1,
+                "inverted at {}",
+                scale
+            );
+            previous = (reinhard, aces);
+        }
+    }
+
+    #[test]
+    fn test_warmth() {
+        for gray in [Color::BLACK, Color::WHITE, Color::new(128, 128, 128)] {
+            assert_eq!(gray.warmth(), 0.0);
+            assert!(!gray.is_warm() && !gray.is_cool());
+        }
+
+        assert!((Color::new(255, 128, 0).warmth() - 1.0).abs() < 0.01);
+        assert!((Color::new(0, 128, 255).warmth() + 1.0).abs() < 0.01);
+        for warm in [Color::RED, Color::YELLOW, Color::new(200, 100, 50)] {
+            assert!(warm.is_warm(), "{:?}", warm);
+        }
+        for cool in [Color::BLUE, Color::CYAN, Color::new(40, 90, 160)] {
+            assert!(cool.is_cool(), "{:?}", cool);
+        }
+
+        // Greens and purples are neutral, and desaturation pulls toward 0
+        assert!(Color::GREEN.warmth().abs() < 0.01);
+        assert!(Color::MAGENTA.warmth().abs() < Color::WARMTH_NEUTRAL_BAND);
+        assert!(Color::new(140, 120, 110).warmth() < Color::new(255, 120, 0).warmth());
+    }
+
+    #[test]
+    fn test_distance_rgb() {
+        let black = Color::black();
+        let white = Color::white();
+        assert_eq!(black.distance_rgb(&black), 0.0);
+        assert!((black.distance_rgb(&white) - (3.0 * 255.0 * 255.0_f64).sqrt()).abs() < 1e-9);
+        assert_eq!(Color::new(3, 0, 0).distance_rgb(&Color::new(0, 4, 0)), 5.0);
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let a = Color::new(100, 50, 0);
+        let b = Color::new(97, 52, 1);
+        assert_eq!(a.max_channel_diff(&b), 3);
+        assert_eq!(b.max_channel_diff(&a), 3);
+        assert_eq!(Color::BLACK.max_channel_diff(&Color::WHITE), 255);
+        assert!(a.approx_eq(&b, 3));
+        assert!(!a.approx_eq(&b, 2));
+        assert!(a.approx_eq(&a, 0));
+        assert_color_approx_eq!(a, b, 3);
+        assert_color_approx_eq!(a, b, 3, "with message {}", 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "colors differ by 3 (tolerance 2)")]
+    fn test_assert_color_approx_eq_fails() {
+        assert_color_approx_eq!(Color::new(100, 50, 0), Color::new(97, 52, 1), 2);
+    }
+
+    #[test]
+    fn test_distance_redmean() {
+        let base = Color::new(60, 120, 180);
+        assert_eq!(base.distance_redmean(&base), 0.0);
+
+        // Equal-magnitude shifts: plain RGB distance can't tell them apart,
+        // redmean ranks the blue shift as less noticeable
+        let blue_shift = Color::new(60, 120, 210);
+        let green_shift = Color::new(60, 150, 180);
+        assert_eq!(
+            base.distance_rgb(&blue_shift),
+            base.distance_rgb(&green_shift)
+        );
+        assert!(base.distance_redmean(&blue_shift) < base.distance_redmean(&green_shift));
+
+        // Symmetric, and black to white spans about 3 * 255
+        assert_eq!(
+            base.distance_redmean(&green_shift),
+            green_shift.distance_redmean(&base)
+        );
+        let span = Color::BLACK.distance_redmean(&Color::WHITE);
+        assert!((span - 764.8).abs() < 0.1, "span {}", span);
+    }
+
+    #[test]
+    fn test_delta_e76() {
+        let red = Color::new(255, 0, 0);
+        assert_eq!(red.delta_e76(&red), 0.0);
+        assert_eq!(Color::black().distance_delta_e_76(&Color::black()), 0.0);
+        assert_eq!(
+            red.distance_delta_e_76(&Color::white()),
+            red.delta_e76(&Color::white())
+        );
+
+        // L* spans 0 to 100 between black and white
+        let diff = Color::black().delta_e76(&Color::white());
+        assert!((diff - 100.0).abs() < 0.01, "got {}", diff);
+    }
+
+    #[test]
+    fn test_delta_e2000_sharma_dataset() {
+        // Test data from Sharma, Wu and Dalal (2005), Table 1
+        #[rustfmt::skip]
+        let data = [
+            ((50.0000, 2.6772, -79.7751), (50.0000, 0.0000, -82.7485), 2.0425),
+            ((50.0000, 3.1571, -77.2803), (50.0000, 0.0000, -82.7485), 2.8615),
+            ((50.0000, 2.8361, -74.0200), (50.0000, 0.0000, -82.7485), 3.4412),
+            ((50.0000, -1.3802, -84.2814), (50.0000, 0.0000, -82.7485), 1.0000),
+            ((50.0000, -1.1848, -84.8006), (50.0000, 0.0000, -82.7485), 1.0000),
+            ((50.0000, -0.9009, -85.5211), (50.0000, 0.0000, -82.7485), 1.0000),
+            ((50.0000, 0.0000, 0.0000), (50.0000, -1.0000, 2.0000), 2.3669),
+            ((50.0000, -1.0000, 2.0000), (50.0000, 0.0000, 0.0000), 2.3669),
+            ((50.0000, 2.4900, -0.0010), (50.0000, -2.4900, 0.0009), 7.1792),
+            ((50.0000, 2.4900, -0.0010), (50.0000, -2.4900, 0.0010), 7.1792),
+            ((50.0000, 2.4900, -0.0010), (50.0000, -2.4900, 0.0011), 7.2195),
+            ((50.0000, 2.4900, -0.0010), (50.0000, -2.4900, 0.0012), 7.2195),
+            ((50.0000, -0.0010, 2.4900), (50.0000, 0.0009, -2.4900), 4.8045),
+            ((50.0000, -0.0010, 2.4900), (50.0000, 0.0010, -2.4900), 4.8045),
+            ((50.0000, -0.0010, 2.4900), (50.0000, 0.0011, -2.4900), 4.7461),
+            ((50.0000, 2.5000, 0.0000), (50.0000, 0.0000, -2.5000), 4.3065),
+            ((50.0000, 2.5000, 0.0000), (73.0000, 25.0000, -18.0000), 27.1492),
+            ((50.0000, 2.5000, 0.0000), (61.0000, -5.0000, 29.0000), 22.8977),
+            ((50.0000, 2.5000, 0.0000), (56.0000, -27.0000, -3.0000), 31.9030),
+            ((50.0000, 2.5000, 0.0000), (58.0000, 24.0000, 15.0000), 19.4535),
+            ((50.0000, 2.5000, 0.0000), (50.0000, 3.1736, 0.5854), 1.0000),
+            ((50.0000, 2.5000, 0.0000), (50.0000, 3.2972, 0.0000), 1.0000),
+            ((50.0000, 2.5000, 0.0000), (50.0000, 1.8634, 0.5757), 1.0000),
+            ((50.0000, 2.5000, 0.0000), (50.0000, 3.2592, 0.3350), 1.0000),
+            ((60.2574, -34.0099, 36.2677), (60.4626, -34.1751, 39.4387), 1.2644),
+            ((63.0109, -31.0961, -5.8663), (62.8187, -29.7946, -4.0864), 1.2630),
+            ((61.2901, 3.7196, -5.3901), (61.4292, 2.2480, -4.9620), 1.8731),
+            ((35.0831, -44.1164, 3.7933), (35.0232, -40.0716, 1.5901), 1.8645),
+            ((22.7233, 20.0904, -46.6940), (23.0331, 14.9730, -42.5619), 2.0373),
+            ((36.4612, 47.8580, 18.3852), (36.2715, 50.5065, 21.2231), 1.4146),
+            ((90.8027, -2.0831, 1.4410), (91.1528, -1.6435, 0.0447), 1.4441),
+            ((90.9257, -0.5406, -0.9208), (88.6381, -0.8985, -0.7239), 1.5381),
+            ((6.7747, -0.2908, -2.4247), (5.8714, -0.0985, -2.2286), 0.6377),
+            ((2.0776, 0.0795, -1.1350), (0.9033, -0.0636, -0.5514), 0.9082),
+        ];
+
+        for (i, (lab1, lab2, expected)) in data.iter().enumerate() {
+            let actual = ciede2000(*lab1, *lab2);
+            assert!(
+                (actual - expected).abs() < 1e-4,
+                "pair {}: expected {}, got {}",
+                i + 1,
+                expected,
+                actual
+            );
+            // The formula is symmetric
+            assert!((ciede2000(*lab2, *lab1) - actual).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_delta_e2000_colors() {
+        let color = Color::new(12, 200, 99);
+        assert_eq!(color.delta_e2000(&color), 0.0);
+
+        // A one-step nudge is imperceptible, a hue change is not
+        let nudged = Color::new(13, 200, 99);
+        assert!(color.delta_e2000(&nudged) < 1.0);
+        assert!(color.delta_e2000(&Color::new(200, 12, 99)) > 10.0);
+    }
+
+    #[test]
+    fn test_blend_identities() {
+        let color = Color::new(37, 128, 211);
+        assert_eq!(color.blend(&Color::white(), BlendMode::Multiply), color);
+        assert_eq!(color.blend(&Color::black(), BlendMode::Screen), color);
+        assert_eq!(color.blend(&Color::black(), BlendMode::Add), color);
+        assert_eq!(color.blend(&Color::black(), BlendMode::Subtract), color);
+        assert_eq!(color.blend(&Color::black(), BlendMode::Difference), color);
+        assert_eq!(color.blend(&color, BlendMode::Darken), color);
+        assert_eq!(color.blend(&color, BlendMode::Lighten), color);
+    }
+
+    #[test]
+    fn test_blend_modes() {
+        let gray = Color::new(128, 128, 128);
+        let light = Color::new(200, 200, 200);
+
+        // 128/255 * 200/255 * 255 = 100.4
+        assert_eq!(
+            gray.blend(&light, BlendMode::Multiply),
+            Color::new(100, 100, 100)
+        );
+        // 255 - 127 * 55 / 255 = 227.6
+        assert_eq!(
+            gray.blend(&light, BlendMode::Screen),
+            Color::new(228, 228, 228)
+        );
+        // Base is just over half, so overlay uses the screen branch
+        assert_eq!(
+            gray.blend(&light, BlendMode::Overlay),
+            Color::new(200, 200, 200)
+        );
+        assert_eq!(
+            Color::new(64, 64, 64).blend(&light, BlendMode::Overlay),
+            Color::new(100, 100, 100)
+        );
+        assert_eq!(gray.blend(&light, BlendMode::Darken), gray);
+        assert_eq!(gray.blend(&light, BlendMode::Lighten), light);
+        assert_eq!(
+            gray.blend(&light, BlendMode::Difference),
+            Color::new(72, 72, 72)
+        );
+
+        // Saturation instead of wrapping
+        assert_eq!(gray.blend(&light, BlendMode::Add), Color::white());
+        assert_eq!(gray.blend(&light, BlendMode::Subtract), Color::black());
+    }
+
+    #[test]
+    fn test_blend_photo_modes() {
+        let gray = Color::new(128, 128, 128);
+        let light = Color::new(200, 200, 200);
+        let dark = Color::new(64, 64, 64);
+
+        // Soft light with a 50% gray blend color is (almost) a no-op
+        assert_eq!(
+            Color::new(37, 128, 211).blend(&Color::new(128, 128, 128), BlendMode::SoftLight),
+            Color::new(37, 128, 211)
+        );
+        // b > 0.5: a + (2b - 1)(sqrt(a) - a) = 0.502 + 0.569 * 0.206 -> 158.0
+        assert_eq!(
+            gray.blend(&light, BlendMode::SoftLight),
+            Color::new(158, 158, 158)
+        );
+        // b <= 0.5: a - (1 - 2b) a (1 - a) = 0.502 - 0.498 * 0.25 -> 96.2
+        assert_eq!(
+            gray.blend(&dark, BlendMode::SoftLight),
+            Color::new(96, 96, 96)
+        );
+
+        // 64 / (1 - 200/255) = 296 -> white; 64 / (1 - 128/255) = 128.5
+        assert_eq!(gray.blend(&light, BlendMode::Dodge), Color::white());
+        assert_eq!(
+            dark.blend(&gray, BlendMode::Dodge),
+            Color::new(129, 129, 129)
+        );
+        assert_eq!(
+            Color::black().blend(&Color::white(), BlendMode::Dodge),
+            Color::black()
+        );
+
+        // 1 - (127/255) / (200/255) = 0.365 -> 93.0; dark base burns to black
+        assert_eq!(gray.blend(&light, BlendMode::Burn), Color::new(93, 93, 93));
+        assert_eq!(dark.blend(&gray, BlendMode::Burn), Color::black());
+        assert_eq!(
+            Color::white().blend(&Color::black(), BlendMode::Burn),
+            Color::white()
+        );
+
+        // Normal is alpha compositing of self over other
+        let red = Color::new(255, 0, 0);
+        let blue = Color::new(0, 0, 255);
+        assert_eq!(red.blend(&blue, BlendMode::Normal(1.0)), red);
+        assert_eq!(red.blend(&blue, BlendMode::Normal(0.0)), blue);
+        assert_eq!(
+            red.blend(&blue, BlendMode::Normal(0.25)),
+            blue.lerp(&red, 0.25)
+        );
+    }
+
+    #[test]
+    fn test_hsl_roundtrip() {
+        for color in [
+            Color::new(255, 0, 0),
+            Color::new(12, 200, 99),
+            Color::new(128, 128, 128),
+            Color::new(250, 128, 114),
+        ] {
+            let (h, s, l) = color.to_hsl();
+            assert_eq!(Color::from_hsl(h, s, l), color);
+        }
+
+        let (h, s, l) = Color::new(0, 0, 255).to_hsl();
+        assert_eq!((h, s, l), (240.0, 1.0, 0.5));
+    }
+
+    #[test]
+    fn test_lighten_darken() {
+        assert_eq!(Color::new(200, 30, 60).lighten(1.0), Color::white());
+        assert_eq!(Color::black().darken(0.5), Color::black());
+        assert_eq!(Color::white().lighten(0.3), Color::white());
+
+        let color = Color::new(70, 130, 180);
+        let roundtrip = color.lighten(0.2).darken(0.2);
+        assert_color_approx_eq!(roundtrip, color, 1);
+    }
+
+    #[test]
+    fn test_saturate_desaturate() {
+        let color = Color::new(70, 130, 180);
+        let gray = color.desaturate(1.0);
+        assert_eq!(gray.r, gray.g);
+        assert_eq!(gray.g, gray.b);
+
+        let (_, s_before, _) = color.to_hsl();
+        let (_, s_after, _) = color.saturate(0.2).to_hsl();
+        assert!(s_after > s_before);
+    }
+
+    #[test]
+    fn test_gamma() {
+        let color = Color::new(12, 128, 250);
+        assert_eq!(color.with_gamma(1.0), color);
+        assert_eq!(color.apply_gamma_decoding(1.0), color);
+
+        // Encoding brightens midtones, decoding darkens them
+        let gray = Color::new(128, 128, 128);
+        assert_eq!(gray.apply_gamma_encoding(2.2), Color::new(186, 186, 186));
+        assert_eq!(gray.apply_gamma_decoding(2.2), Color::new(56, 56, 56));
+        assert_eq!(gray.with_gamma(2.2), gray.apply_gamma_encoding(2.2));
+
+        // Black and white are fixed points
+        assert_eq!(Color::black().with_gamma(2.2), Color::black());
+        assert_eq!(Color::white().apply_gamma_decoding(2.2), Color::white());
+
+        // Invalid gamma leaves the color unchanged
+        assert_eq!(gray.with_gamma(0.0), gray);
+        assert_eq!(gray.with_gamma(-2.0), gray);
+        assert_eq!(gray.apply_gamma_decoding(f64::NAN), gray);
+    }
+
+    #[test]
+    fn test_to_grayscale() {
+        let red = Color::new(255, 0, 0);
+        let green = Color::new(0, 255, 0);
+        let blue = Color::new(0, 0, 255);
+
+        assert_eq!(
+            red.to_grayscale(GrayscaleMethod::Average),
+            Color::new(85, 85, 85)
+        );
+        assert_eq!(
+            red.to_grayscale(GrayscaleMethod::Luminosity),
+            Color::new(76, 76, 76)
+        );
+        assert_eq!(
+            green.to_grayscale(GrayscaleMethod::Luminosity),
+            Color::new(150, 150, 150)
+        );
+        assert_eq!(
+            blue.to_grayscale(GrayscaleMethod::Luminosity),
+            Color::new(29, 29, 29)
+        );
+        assert_eq!(
+            red.to_grayscale(GrayscaleMethod::Rec709),
+            Color::new(54, 54, 54)
+        );
+        assert_eq!(
+            green.to_grayscale(GrayscaleMethod::Rec709),
+            Color::new(182, 182, 182)
+        );
+        assert_eq!(
+            blue.to_grayscale(GrayscaleMethod::Rec709),
+            Color::new(18, 18, 18)
+        );
+
+        // Grays and the extremes are unchanged by every method
+        for method in [
+            GrayscaleMethod::Average,
+            GrayscaleMethod::Luminosity,
+            GrayscaleMethod::Rec709,
+        ] {
+            assert_eq!(Color::white().to_grayscale(method), Color::white());
+            assert_eq!(Color::black().to_grayscale(method), Color::black());
+            let gray = Color::new(99, 99, 99);
+            assert_eq!(gray.to_grayscale(method), gray);
+        }
+    }
+
+    #[test]
+    fn test_from_css_string() {
+        let expected = Color::new(255, 0, 0);
+        assert_eq!(Color::from_css_string("rgb(255, 0, 0)").unwrap(), expected);
+        assert_eq!(
+            Color::from_css_string("rgb( 255 , 0 , 0 )").unwrap(),
+            expected
+        );
+        assert_eq!(
+            Color::from_css_string("  RGB(255,0,0)  ").unwrap(),
+            expected
+        );
+        assert_eq!(
+            Color::from_css_string("rgba(255, 0, 0, 0.5)").unwrap(),
+            expected
+        );
+        assert_eq!(Color::from_css_string("#F00").unwrap(), expected);
+        assert_eq!(Color::from_css_string("#FF0000").unwrap(), expected);
+
+        assert_eq!(Color::from_css_string("Red").unwrap(), expected);
+
+        // CSS Color 4: components clamp, rgb/rgba are aliases, hsl is supported
+        assert_eq!(Color::from_css_string("rgb(256, 0, 0)").unwrap(), expected);
+        assert_eq!(
+            Color::from_css_string("rgb(255, 0, 0, 1)").unwrap(),
+            expected
+        );
+        assert_eq!(Color::from_css_string("rgba(255, 0, 0)").unwrap(), expected);
+        assert_eq!(Color::from_css_string("rgb(100% 0% 0%)").unwrap(), expected);
+        assert_eq!(
+            Color::from_css_string("hsl(0, 100%, 50%)").unwrap(),
+            expected
+        );
+
+        assert!(Color::from_css_string("rgb(255, 0)").is_err());
+        assert!(Color::from_css_string("rgba(255, 0, 0, x)").is_err());
+        assert!(Color::from_css_string("rgb 255, 0, 0").is_err());
+        assert!(Color::from_css_string("#é1").is_err());
+        assert!(Color::from_css_string("rgb(é, 0, 0)").is_err());
+        assert!(Color::from_css_string("").is_err());
+    }
+
+    #[test]
+    fn test_css_string_roundtrip() {
+        let color = Color::new(12, 200, 99);
+        assert_eq!(color.to_css_string(), "rgb(12, 200, 99)");
+        assert_eq!(
+            Color::from_css_string(&color.to_css_string()).unwrap(),
+            color
+        );
+    }
+
+    #[test]
+    fn test_to_css_hsl() {
+        assert_eq!(Color::RED.to_css_rgb(), "rgb(255, 0, 0)");
+        assert_eq!(Color::RED.to_css_hsl(), "hsl(0, 100%, 50%)");
+        assert_eq!(Color::WHITE.to_css_hsl(), "hsl(0, 0%, 100%)");
+        assert_eq!(Color::new(128, 128, 128).to_css_hsl(), "hsl(0, 0%, 50.2%)");
+        assert_eq!(Color::new(255, 87, 51).to_css_hsl(), "hsl(10.6, 100%, 60%)");
+
+        for r in (0..=255).step_by(17) {
+            for g in (0..=255).step_by(51) {
+                for b in (0..=255).step_by(85) {
+                    let color = Color::new(r, g, b);
+                    let parsed = Color::from_css_string(&color.to_css_hsl()).unwrap();
+                    assert_color_approx_eq!(parsed, color, 1, "{}", color.to_css_hsl());
+                    assert_eq!(Color::from_css_string(&color.to_css_rgb()).unwrap(), color);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_perceived_brightness() {
+        assert_eq!(Color::black().perceived_brightness(), 0.0);
+        assert!((Color::white().perceived_brightness() - 1.0).abs() < 1e-9);
+        assert!((Color::new(255, 0, 0).perceived_brightness() - 0.2126).abs() < 1e-9);
+
+        assert!(Color::white().is_light());
+        assert!(Color::new(255, 255, 0).is_light());
+        assert!(Color::black().is_dark());
+        assert!(Color::new(0, 0, 255).is_dark());
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        let black = Color::black();
+        let white = Color::white();
+        assert!((black.contrast_ratio(&white) - 21.0).abs() < 1e-9);
+        assert!((white.contrast_ratio(&black) - 21.0).abs() < 1e-9);
+        assert_eq!(white.contrast_ratio(&white), 1.0);
+
+        // #777777 on white is just under the WCAG AA threshold of 4.5
+        let gray = Color::new(0x77, 0x77, 0x77);
+        assert!((gray.contrast_ratio(&white) - 4.48).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_inverted() {
+        assert_eq!(Color::black().inverted(), Color::white());
+        assert_eq!(Color::new(255, 87, 51).inverted(), Color::new(0, 168, 204));
+
+        let gray = Color::new(100, 100, 100).inverted();
+        assert_eq!(gray, Color::new(155, 155, 155));
+
+        let color = Color::new(12, 200, 99);
+        assert_eq!(color.inverted().inverted(), color);
+    }
+
+    #[test]
+    fn test_complement() {
+        assert_eq!(Color::new(255, 0, 0).complement(), Color::new(0, 255, 255));
+        assert_eq!(Color::new(0, 0, 255).complement(), Color::new(255, 255, 0));
+        assert_eq!(Color::black().complement(), Color::black());
+        assert_eq!(Color::new(80, 80, 80).complement(), Color::new(80, 80, 80));
+
+        // Orange (hue 30) becomes azure (hue 210)
+        let orange = Color::new(255, 127, 0);
+        assert_eq!(orange.complement(), Color::new(0, 128, 255));
+
+        let color = Color::new(12, 200, 99);
+        assert_eq!(color.complement().complement(), color);
+    }
+
+    #[test]
+    fn test_color_harmonies() {
+        assert_eq!(Color::RED.complementary(), Color::CYAN);
+        let color = Color::new(200, 80, 40);
+        assert_eq!(color.complementary(), color.rotate_hue(180.0));
+        assert_color_approx_eq!(color.complementary(), color.complement(), 1);
+
+        assert_eq!(Color::RED.triadic(), [Color::GREEN, Color::BLUE]);
+        assert_eq!(
+            Color::RED.tetradic(),
+            [
+                Color::new(128, 255, 0),
+                Color::CYAN,
+                Color::new(128, 0, 255)
+            ]
+        );
+        assert_eq!(Color::RED.analogous(60.0), [Color::MAGENTA, Color::YELLOW]);
+
+        // Saturation and value survive the rotation
+        let (_, s, v) = color.to_hsv();
+        for harmony in color.triadic().into_iter().chain(color.tetradic()) {
+            let (_, hs, hv) = harmony.to_hsv();
+            assert!((hs - s).abs() < 0.01 && (hv - v).abs() < 0.01);
+        }
+
+        let gray = Color::new(90, 90, 90);
+        assert_eq!(gray.triadic(), [gray; 2]);
+        assert_eq!(gray.analogous(15.0), [gray; 2]);
+    }
+
+    #[test]
+    fn test_from_wavelength() {
+        let dominant = |nm: f64| {
+            let c = Color::from_wavelength(nm);
+            if c.r > c.g && c.r > c.b {
+                'r'
+            } else if c.g > c.r && c.g > c.b {
+                'g'
+            } else {
+                'b'
+            }
+        };
+        assert_eq!(dominant(470.0), 'b');
+        assert_eq!(dominant(550.0), 'g');
+        assert_eq!(dominant(650.0), 'r');
+
+        assert_eq!(Color::from_wavelength(379.0), Color::BLACK);
+        assert_eq!(Color::from_wavelength(781.0), Color::BLACK);
+        assert_eq!(Color::from_wavelength(f64::NAN), Color::BLACK);
+
+        // The edges roll off softly: dim near the limits, full in the middle
+        assert!(Color::from_wavelength(385.0).b < 100);
+        assert!(Color::from_wavelength(775.0).r < 60);
+        assert_eq!(Color::from_wavelength(600.0).r, 255);
+
+        // Continuous: a fine sweep never jumps
+        let mut previous = Color::from_wavelength(370.0);
+        for step in 0..=4200 {
+            let color = Color::from_wavelength(370.0 + step as f64 * 0.1);
+            assert!(
+                color.max_channel_diff(&previous) <= 16,
+                "seam at {} nm",
+                370.0 + step as f64 * 0.1
+            );
+            previous = color;
+        }
+    }
+
+    #[test]
+    fn test_from_temperature() {
+        // D65 daylight white
+        assert_color_approx_eq!(
+            Color::from_temperature(6500.0),
+            Color::new(255, 249, 253),
+            1
+        );
+
+        // Warm amber: full red, moderate green, almost no blue
+        let amber = Color::from_temperature(2000.0);
+        assert_eq!(amber.r, 255);
+        assert!((120..160).contains(&amber.g));
+        assert!(amber.b < 40);
+
+        // Hot stars are blue-white
+        let hot = Color::from_temperature(12000.0);
+        assert!(hot.b == 255 && hot.r < hot.g);
+
+        assert_eq!(
+            Color::from_temperature(500.0),
+            Color::from_temperature(1000.0)
+        );
+        assert_eq!(
+            Color::from_temperature(30000.0),
+            Color::from_temperature(12000.0)
+        );
+    }
+
+    #[test]
+    fn test_from_kelvin() {
+        // Candlelight is strongly orange
+        let candle = Color::from_kelvin(1900.0);
+        assert_eq!(candle.r, 255);
+        assert!(candle.g > 100 && candle.g < 160);
+        assert_eq!(candle.b, 0);
+
+        // 6600 K is close to white
+        let daylight = Color::from_kelvin(6600.0);
+        assert!(daylight.r == 255 && daylight.g >= 250 && daylight.b == 255);
+
+        // Hot stars are blue-tinted
+        let blue_star = Color::from_kelvin(20000.0);
+        assert_eq!(blue_star.b, 255);
+        assert!(blue_star.r < blue_star.g && blue_star.g < blue_star.b);
+
+        // Out-of-range temperatures clamp
+        assert_eq!(Color::from_kelvin(10.0), Color::from_kelvin(1000.0));
+        assert_eq!(Color::from_kelvin(1.0e6), Color::from_kelvin(40000.0));
+    }
+
+    #[test]
+    fn test_to_hsv() {
+        assert_eq!(Color::new(255, 0, 0).to_hsv(), (0.0, 1.0, 1.0));
+        assert_eq!(Color::new(0, 0, 255).to_hsv(), (240.0, 1.0, 1.0));
+        assert_eq!(Color::new(0, 0, 0).to_hsv(), (0.0, 0.0, 0.0));
+
+        let (h, s, v) = Color::new(255, 128, 0).to_hsv();
+        assert!((h - 30.1176).abs() < 1e-3);
+        assert_eq!((s, v), (1.0, 1.0));
+    }
+
+    #[test]
+    fn test_rotate_hue() {
+        let red = Color::new(255, 0, 0);
+        assert_eq!(red.rotate_hue(120.0), Color::new(0, 255, 0));
+        assert_eq!(red.rotate_hue(240.0), Color::new(0, 0, 255));
+        assert_eq!(red.rotate_hue(-120.0), Color::new(0, 0, 255));
+        assert_eq!(red.rotate_hue(480.0), Color::new(0, 255, 0));
+
+        let color = Color::new(12, 200, 99);
+        assert_eq!(color.rotate_hue(0.0), color);
+        assert_eq!(color.rotate_hue(360.0), color);
+        assert_eq!(color.rotate_hue(-720.0), color);
+
+        let gray = Color::new(128, 128, 128);
+        assert_eq!(gray.rotate_hue(90.0), gray);
+    }
+
+    #[test]
+    fn test_mix() {
+        let red = Color::new(255, 0, 0);
+        let green = Color::new(0, 255, 0);
+        let blue = Color::new(0, 0, 255);
+
+        // Weights are normalized
+        assert_eq!(
+            Color::mix(&[(red, 1.0), (blue, 1.0)]),
+            Color::new(128, 0, 128)
+        );
+        assert_eq!(
+            Color::mix(&[(red, 10.0), (blue, 10.0)]),
+            Color::new(128, 0, 128)
+        );
+
+        // Order doesn't matter
+        let a = Color::mix(&[(red, 0.5), (green, 0.3), (blue, 0.2)]);
+        let b = Color::mix(&[(blue, 0.2), (red, 0.5), (green, 0.3)]);
+        assert_eq!(a, b);
+        assert_eq!(a, Color::new(128, 77, 51));
+
+        // A color mixed with itself is unchanged
+        let color = Color::new(12, 200, 99);
+        assert_eq!(Color::mix(&[(color, 1.0), (color, 1.0)]), color);
+        assert_eq!(Color::mix(&[(color, 0.7)]), color);
+
+        // Degenerate weights
+        assert_eq!(Color::mix(&[]), Color::black());
+        assert_eq!(Color::mix(&[(color, 0.0)]), Color::black());
+        assert_eq!(Color::mix(&[(color, 1.0), (red, -5.0)]), color);
+    }
+
+    #[test]
+    fn test_average() {
+        let (red, green, blue) = (Color::RED, Color::GREEN, Color::BLUE);
+        assert_eq!(Color::average(&[red, green, blue]), Color::new(85, 85, 85));
+        assert_eq!(
+            Color::average(&[red, blue]),
+            Color::mix(&[(red, 0.5), (blue, 0.5)])
+        );
+        assert_eq!(Color::average(&[Color::WHITE; 4]), Color::WHITE);
+        assert_eq!(
+            Color::average(&[Color::BLACK, Color::WHITE, Color::WHITE]),
+            Color::new(170, 170, 170)
+        );
+        assert_eq!(Color::average(&[]), Color::black());
+    }
+
+    #[test]
+    fn test_mix_linear() {
+        // Linear-light averaging of black and white is brighter than 128
+        let mid = Color::mix_linear(&[(Color::black(), 1.0), (Color::white(), 1.0)]);
+        assert_eq!(mid, Color::new(188, 188, 188));
+
+        let color = Color::new(12, 200, 99);
+        assert_eq!(Color::mix_linear(&[(color, 1.0), (color, 3.0)]), color);
+        assert_eq!(Color::mix_linear(&[]), Color::black());
+    }
+
+    #[test]
+    fn test_from_str() {
+        let expected = Color::new(255, 87, 51);
+        assert_eq!("#FF5733".parse::<Color>().unwrap(), expected);
+        assert_eq!("ff5733".parse::<Color>().unwrap(), expected);
+        assert_eq!("  #ff5733\n".parse::<Color>().unwrap(), expected);
+        assert_eq!("#F0A".parse::<Color>().unwrap(), Color::new(255, 0, 170));
+        assert_eq!("F0A".parse::<Color>().unwrap(), Color::new(255, 0, 170));
+        assert_eq!("rgb(255 87 51)".parse::<Color>().unwrap(), expected);
+        assert_eq!("hsl(0, 100%, 50%)".parse::<Color>().unwrap(), Color::RED);
+
+        let err = "rgb(255, 87, oops)".parse::<Color>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid component 'oops' in color 'rgb(255, 87, oops)'"
+        );
+        let err = "#12345".parse::<Color>().unwrap_err();
+        assert_eq!(err.to_string(), "Invalid color: '#12345'");
+        assert!("".parse::<Color>().is_err());
+        assert!("#GGG".parse::<Color>().is_err());
+
+        // Multi-byte characters fail cleanly instead of splitting a char
+        let err = "é1".parse::<Color>().unwrap_err();
+        assert_eq!(err.to_string(), "Invalid color: 'é1'");
+        assert!("#ééé".parse::<Color>().is_err());
+        assert!("#1é2345".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(Color::from_name("red").unwrap(), Color::new(255, 0, 0));
+        assert_eq!(
+            Color::from_name("CornflowerBlue").unwrap(),
+            Color::new(100, 149, 237)
+        );
+        assert_eq!(
+            Color::from_name(" rebeccapurple ").unwrap(),
+            Color::new(102, 51, 153)
+        );
+        assert_eq!(
+            Color::from_name("grey").unwrap(),
+            Color::from_name("gray").unwrap()
+        );
+        assert!(Color::from_name("notacolor").is_err());
+
+        assert_eq!("white".parse::<Color>().unwrap(), Color::white());
+        assert_eq!("Navy".parse::<Color>().unwrap(), Color::new(0, 0, 128));
+        assert!(matches!(
+            "blurple".parse::<Color>(),
+            Err(crate::error::ColorMapError::InvalidColor(_))
+        ));
+    }
+
+    #[test]
+    fn test_named_color_table() {
+        assert_eq!(named::NAMED_COLORS.len(), 148);
+        // Binary search requires the table to stay sorted
+        assert!(named::NAMED_COLORS.windows(2).all(|w| w[0].0 < w[1].0));
+        for (name, r, g, b) in named::NAMED_COLORS {
+            assert_eq!(Color::from_name(name).unwrap(), Color::new(*r, *g, *b));
+        }
+    }
 }
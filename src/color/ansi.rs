@@ -0,0 +1,104 @@
+//! ANSI terminal escapes for colors
+//!
+//! Truecolor escapes only render correctly in terminals that support 24-bit
+//! color; the `*_256` helpers fall back to the xterm 256-color palette.
+
+use super::Color;
+
+/// Escape sequence that resets all terminal colors and attributes
+pub const ANSI_RESET: &str = "\x1b[0m";
+
+/// Channel levels of the xterm 6x6x6 color cube (palette indices 16 - 231)
+const XTERM_CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl Color {
+    /// Truecolor foreground escape, `\x1b[38;2;R;G;Bm`
+    pub fn ansi_fg(&self) -> String {
+        format!("\x1b[38;2;{};{};{}m", self.r, self.g, self.b)
+    }
+
+    /// Truecolor background escape, `\x1b[48;2;R;G;Bm`
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::color::ANSI_RESET;
+    /// use scala_chromatica::Color;
+    ///
+    /// print!("{}  {}", Color::new(255, 87, 51).ansi_bg(), ANSI_RESET);
+    /// assert_eq!(Color::new(1, 2, 3).ansi_bg(), "\x1b[48;2;1;2;3m");
+    /// ```
+    pub fn ansi_bg(&self) -> String {
+        format!("\x1b[48;2;{};{};{}m", self.r, self.g, self.b)
+    }
+
+    /// 256-color foreground escape, `\x1b[38;5;Nm` (see [`Color::to_xterm_256`])
+    pub fn ansi_fg_256(&self) -> String {
+        format!("\x1b[38;5;{}m", self.to_xterm_256())
+    }
+
+    /// 256-color background escape, `\x1b[48;5;Nm` (see [`Color::to_xterm_256`])
+    pub fn ansi_bg_256(&self) -> String {
+        format!("\x1b[48;5;{}m", self.to_xterm_256())
+    }
+
+    /// Nearest xterm 256-color palette index
+    ///
+    /// Picks the closer (by RGB distance) of the nearest 6x6x6 cube color
+    /// (16 - 231) and the nearest grayscale ramp entry (232 - 255). The 16
+    /// system colors are skipped because terminals theme them differently.
+    pub fn to_xterm_256(&self) -> u8 {
+        let cube_index = |v: u8| match v {
+            0..=47 => 0,
+            48..=114 => 1,
+            _ => (v - 35) / 40,
+        };
+        let (ri, gi, bi) = (cube_index(self.r), cube_index(self.g), cube_index(self.b));
+        let cube = Color::new(
+            XTERM_CUBE_LEVELS[ri as usize],
+            XTERM_CUBE_LEVELS[gi as usize],
+            XTERM_CUBE_LEVELS[bi as usize],
+        );
+
+        // Gray ramp levels are 8, 18, ..., 238
+        let average = (self.r as f64 + self.g as f64 + self.b as f64) / 3.0;
+        let gray_index = ((average - 8.0) / 10.0).round().clamp(0.0, 23.0) as u8;
+        let gray_level = 8 + 10 * gray_index;
+        let gray = Color::new(gray_level, gray_level, gray_level);
+
+        if self.distance_rgb(&gray) < self.distance_rgb(&cube) {
+            232 + gray_index
+        } else {
+            16 + 36 * ri + 6 * gi + bi
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truecolor_escapes() {
+        let color = Color::new(255, 87, 51);
+        assert_eq!(color.ansi_fg(), "\x1b[38;2;255;87;51m");
+        assert_eq!(color.ansi_bg(), "\x1b[48;2;255;87;51m");
+    }
+
+    #[test]
+    fn test_xterm_256() {
+        assert_eq!(Color::RED.to_xterm_256(), 196);
+        assert_eq!(Color::GREEN.to_xterm_256(), 46);
+        assert_eq!(Color::BLUE.to_xterm_256(), 21);
+        assert_eq!(Color::BLACK.to_xterm_256(), 16);
+        assert_eq!(Color::WHITE.to_xterm_256(), 231);
+        assert_eq!(Color::new(95, 135, 175).to_xterm_256(), 67);
+
+        // Grays land on the grayscale ramp
+        assert_eq!(Color::new(128, 128, 128).to_xterm_256(), 244);
+        assert_eq!(Color::new(8, 8, 8).to_xterm_256(), 232);
+        assert_eq!(Color::new(238, 238, 238).to_xterm_256(), 255);
+
+        assert_eq!(Color::RED.ansi_fg_256(), "\x1b[38;5;196m");
+        assert_eq!(Color::new(128, 128, 128).ansi_bg_256(), "\x1b[48;5;244m");
+    }
+}
@@ -0,0 +1,156 @@
+//! 16-bit per channel colors for high-bit-depth exports
+
+use super::{Color, ColorF};
+use crate::error::{ColorMapError, Result};
+use serde::{Deserialize, Serialize};
+
+/// An RGB color with 16-bit channels (0 - 65535)
+///
+/// Meant for LUTs and image pipelines (16-bit PNG/TIFF, EXR) where 8-bit
+/// channels band visibly across long ramps. Converting from [`Color`] is
+/// lossless; converting back rounds to the nearest 8-bit value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Color16 {
+    pub r: u16,
+    pub g: u16,
+    pub b: u16,
+}
+
+impl Color16 {
+    /// Create a new 16-bit color
+    pub const fn new(r: u16, g: u16, b: u16) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Expand an 8-bit color exactly (`v * 257`, so 255 maps to 65535)
+    pub const fn from_color(color: Color) -> Self {
+        Self::new(
+            color.r as u16 * 257,
+            color.g as u16 * 257,
+            color.b as u16 * 257,
+        )
+    }
+
+    /// Round to the nearest 8-bit color
+    pub const fn to_color(&self) -> Color {
+        const fn reduce(v: u16) -> u8 {
+            ((v as u32 + 128) / 257) as u8
+        }
+        Color::new(reduce(self.r), reduce(self.g), reduce(self.b))
+    }
+
+    /// Quantize a high-precision color, clamping channels to 0.0 - 1.0
+    pub fn from_color_f(color: ColorF) -> Self {
+        let channel = |v: f64| (v.clamp(0.0, 1.0) * 65535.0).round() as u16;
+        Self::new(channel(color.r), channel(color.g), channel(color.b))
+    }
+
+    /// Parse a 16-bit hex string: `#RRRRGGGGBBBB`, or `#RRRRGGGGBBBBAAAA`
+    /// with the alpha group ignored
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Color16;
+    ///
+    /// let color = Color16::from_hex("#FFFF80000000").unwrap();
+    /// assert_eq!(color, Color16::new(65535, 32768, 0));
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        let digits = hex.trim().trim_start_matches('#');
+        let invalid = || ColorMapError::InvalidHexColor(digits.to_string());
+
+        if !matches!(digits.len(), 12 | 16) || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+
+        let channel = |i: usize| u16::from_str_radix(&digits[i * 4..i * 4 + 4], 16);
+        Ok(Self::new(
+            channel(0).map_err(|_| invalid())?,
+            channel(1).map_err(|_| invalid())?,
+            channel(2).map_err(|_| invalid())?,
+        ))
+    }
+
+    /// Format as a 12-digit hex string (e.g., "#FFFF80000000")
+    pub fn to_hex(&self) -> String {
+        format!("#{:04X}{:04X}{:04X}", self.r, self.g, self.b)
+    }
+
+    /// Linear interpolation between two colors, rounded to 16 bits
+    ///
+    /// `t` is clamped to 0.0 - 1.0.
+    pub fn lerp(&self, other: &Color16, t: f64) -> Color16 {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |a: u16, b: u16| (a as f64 + (b as f64 - a as f64) * t).round() as u16;
+        Color16 {
+            r: channel(self.r, other.r),
+            g: channel(self.g, other.g),
+            b: channel(self.b, other.b),
+        }
+    }
+}
+
+impl From<Color> for Color16 {
+    fn from(color: Color) -> Self {
+        Color16::from_color(color)
+    }
+}
+
+impl From<Color16> for Color {
+    fn from(color: Color16) -> Self {
+        color.to_color()
+    }
+}
+
+impl From<ColorF> for Color16 {
+    fn from(color: ColorF) -> Self {
+        Color16::from_color_f(color)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_roundtrip() {
+        for v in 0..=255u8 {
+            let color = Color::new(v, 255 - v, v / 2);
+            assert_eq!(Color16::from(color).to_color(), color);
+        }
+        assert_eq!(
+            Color16::from(Color::WHITE),
+            Color16::new(65535, 65535, 65535)
+        );
+
+        // Rounds to the nearest 8-bit level
+        assert_eq!(
+            Color16::new(385, 386, 65535).to_color(),
+            Color::new(1, 2, 255)
+        );
+    }
+
+    #[test]
+    fn test_hex() {
+        let color = Color16::new(65535, 32768, 1);
+        assert_eq!(color.to_hex(), "#FFFF80000001");
+        assert_eq!(Color16::from_hex(&color.to_hex()).unwrap(), color);
+        assert_eq!(Color16::from_hex(" ffff80000001abcd ").unwrap(), color);
+
+        assert!(Color16::from_hex("#FFFFFF").is_err());
+        assert!(Color16::from_hex("#+FFF00000000").is_err());
+        assert!(Color16::from_hex("#GGGG00000000").is_err());
+    }
+
+    #[test]
+    fn test_lerp() {
+        let black = Color16::new(0, 0, 0);
+        let white = Color16::new(65535, 65535, 65535);
+        assert_eq!(black.lerp(&white, 0.5), Color16::new(32768, 32768, 32768));
+        assert_eq!(black.lerp(&white, 2.0), white);
+        assert_eq!(
+            Color16::from(ColorF::new(-0.5, 0.5, 1.5)),
+            Color16::new(0, 32768, 65535)
+        );
+    }
+}
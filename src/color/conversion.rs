@@ -0,0 +1,277 @@
+//! Color space conversions shared by the color model and perceptual features
+//!
+//! All conversions assume sRGB primaries with a D65 white point.
+
+use super::Color;
+
+/// Convert an sRGB channel (0-255) to linear light (0.0-1.0)
+pub(crate) fn srgb_to_linear(c: u8) -> f64 {
+    decode_srgb(c as f64 / 255.0)
+}
+
+/// Convert a linear light value (0.0-1.0) back to an sRGB channel (0-255)
+pub(crate) fn linear_to_srgb(c: f64) -> u8 {
+    (encode_srgb(c.clamp(0.0, 1.0)) * 255.0).round() as u8
+}
+
+/// Apply the sRGB transfer function in reverse (encoded 0.0-1.0 to linear light)
+pub(crate) fn decode_srgb(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Apply the sRGB transfer function (linear light to encoded 0.0-1.0)
+pub(crate) fn encode_srgb(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// D65 reference white in CIE XYZ (Y = 1.0)
+const WHITE_D65: (f64, f64, f64) = (0.95047, 1.0, 1.08883);
+
+/// Convert an sRGB color to CIE XYZ (D65, Y of white = 1.0)
+pub(crate) fn rgb_to_xyz(color: &Color) -> (f64, f64, f64) {
+    let r = srgb_to_linear(color.r);
+    let g = srgb_to_linear(color.g);
+    let b = srgb_to_linear(color.b);
+
+    (
+        0.4124564 * r + 0.3575761 * g + 0.1804375 * b,
+        0.2126729 * r + 0.7151522 * g + 0.0721750 * b,
+        0.0193339 * r + 0.1191920 * g + 0.9503041 * b,
+    )
+}
+
+/// Convert CIE XYZ (D65, Y of white = 1.0) to sRGB, clamping out-of-gamut channels
+pub(crate) fn xyz_to_rgb(x: f64, y: f64, z: f64) -> Color {
+    let (r, g, b) = xyz_to_linear_rgb(x, y, z);
+    Color::new(linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b))
+}
+
+/// Convert CIE XYZ (D65) to unclamped linear-light sRGB channels
+pub(crate) fn xyz_to_linear_rgb(x: f64, y: f64, z: f64) -> (f64, f64, f64) {
+    (
+        3.2404542 * x - 1.5371385 * y - 0.4985314 * z,
+        -0.9692660 * x + 1.8760108 * y + 0.0415560 * z,
+        0.0556434 * x - 0.2040259 * y + 1.0572252 * z,
+    )
+}
+
+/// CIE 1931 xy chromaticity of a blackbody radiator at `kelvin`
+///
+/// Uses Krystek's (1985) rational approximation of the Planckian locus in
+/// CIE 1960 UCS, valid from 1000 K to 15000 K.
+pub(crate) fn planckian_xy(kelvin: f64) -> (f64, f64) {
+    let t = kelvin;
+    let u = (0.860117757 + 1.54118254e-4 * t + 1.28641212e-7 * t * t)
+        / (1.0 + 8.42420235e-4 * t + 7.08145163e-7 * t * t);
+    let v = (0.317398726 + 4.22806245e-5 * t + 4.20481691e-8 * t * t)
+        / (1.0 - 2.89741816e-5 * t + 1.61456053e-7 * t * t);
+
+    let d = 2.0 * u - 8.0 * v + 4.0;
+    (3.0 * u / d, 2.0 * v / d)
+}
+
+/// Convert CIE XYZ to CIE L*a*b* relative to the D65 white point
+pub(crate) fn xyz_to_lab(x: f64, y: f64, z: f64) -> (f64, f64, f64) {
+    let f = |t: f64| {
+        let delta: f64 = 6.0 / 29.0;
+        if t > delta.powi(3) {
+            t.cbrt()
+        } else {
+            t / (3.0 * delta * delta) + 4.0 / 29.0
+        }
+    };
+
+    let (fx, fy, fz) = (f(x / WHITE_D65.0), f(y / WHITE_D65.1), f(z / WHITE_D65.2));
+    (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+}
+
+/// Convert CIE L*a*b* (D65 white point) to CIE XYZ
+pub(crate) fn lab_to_xyz(l: f64, a: f64, b: f64) -> (f64, f64, f64) {
+    let f_inv = |t: f64| {
+        let delta: f64 = 6.0 / 29.0;
+        if t > delta {
+            t.powi(3)
+        } else {
+            3.0 * delta * delta * (t - 4.0 / 29.0)
+        }
+    };
+
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+    (
+        WHITE_D65.0 * f_inv(fx),
+        WHITE_D65.1 * f_inv(fy),
+        WHITE_D65.2 * f_inv(fz),
+    )
+}
+
+/// Convert an sRGB color to CIE L*a*b* (D65 white point)
+pub(crate) fn rgb_to_lab(color: &Color) -> (f64, f64, f64) {
+    let (x, y, z) = rgb_to_xyz(color);
+    xyz_to_lab(x, y, z)
+}
+
+/// Convert an sRGB color to Oklab as `(L, a, b)` (Björn Ottosson, 2020)
+pub(crate) fn rgb_to_oklab(color: &Color) -> (f64, f64, f64) {
+    linear_rgb_to_oklab(
+        srgb_to_linear(color.r),
+        srgb_to_linear(color.g),
+        srgb_to_linear(color.b),
+    )
+}
+
+/// Convert Oklab to sRGB, clamping out-of-gamut channels
+pub(crate) fn oklab_to_rgb(l: f64, a: f64, b: f64) -> Color {
+    let (r, g, b) = oklab_to_linear_rgb(l, a, b);
+    Color::new(linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b))
+}
+
+/// Convert linear-light sRGB channels to Oklab as `(L, a, b)`
+pub(crate) fn linear_rgb_to_oklab(r: f64, g: f64, b: f64) -> (f64, f64, f64) {
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+    (
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    )
+}
+
+/// Convert Oklab to unclamped linear-light sRGB channels
+pub(crate) fn oklab_to_linear_rgb(l: f64, a: f64, b: f64) -> (f64, f64, f64) {
+    let l_ = (l + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m_ = (l - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s_ = (l - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+    (
+        4.0767416621 * l_ - 3.3077115913 * m_ + 0.2309699292 * s_,
+        -1.2684380046 * l_ + 2.6097574011 * m_ - 0.3413193965 * s_,
+        -0.0041960863 * l_ - 0.7034186147 * m_ + 1.7076147010 * s_,
+    )
+}
+
+/// Convert Oklab to its cylindrical form Oklch as `(L, C, h)`, hue in degrees
+pub(crate) fn oklab_to_oklch(l: f64, a: f64, b: f64) -> (f64, f64, f64) {
+    (l, a.hypot(b), normalize_hue(b.atan2(a).to_degrees()))
+}
+
+/// Convert Oklch (hue in degrees) back to Oklab
+pub(crate) fn oklch_to_oklab(l: f64, c: f64, h: f64) -> (f64, f64, f64) {
+    let (sin, cos) = h.to_radians().sin_cos();
+    (l, c * cos, c * sin)
+}
+
+/// Wrap a hue in degrees into 0.0 - 360.0 (exclusive)
+fn normalize_hue(h: f64) -> f64 {
+    let h = h.rem_euclid(360.0);
+    // rem_euclid rounds tiny negative hues up to exactly 360.0
+    if h >= 360.0 {
+        0.0
+    } else {
+        h
+    }
+}
+
+/// Convert HSV (hue in degrees, wrapped; saturation and value clamped) to normalized RGB
+pub(crate) fn hsv_to_rgb(h: f64, s: f64, v: f64) -> (f64, f64, f64) {
+    let h = normalize_hue(h);
+    let s = s.clamp(0.0, 1.0);
+    let v = v.clamp(0.0, 1.0);
+
+    let c = v * s;
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+    let m = v - c;
+
+    let (r, g, b) = if h < 60.0 {
+        (c, x, 0.0)
+    } else if h < 120.0 {
+        (x, c, 0.0)
+    } else if h < 180.0 {
+        (0.0, c, x)
+    } else if h < 240.0 {
+        (0.0, x, c)
+    } else if h < 300.0 {
+        (x, 0.0, c)
+    } else {
+        (c, 0.0, x)
+    };
+
+    (r + m, g + m, b + m)
+}
+
+/// Convert HSL (hue in degrees, wrapped; saturation and lightness clamped) to normalized RGB
+pub(crate) fn hsl_to_rgb(h: f64, s: f64, l: f64) -> (f64, f64, f64) {
+    let h = normalize_hue(h);
+    let s = s.clamp(0.0, 1.0);
+    let l = l.clamp(0.0, 1.0);
+
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+    let m = l - c / 2.0;
+
+    let (r, g, b) = if h < 60.0 {
+        (c, x, 0.0)
+    } else if h < 120.0 {
+        (x, c, 0.0)
+    } else if h < 180.0 {
+        (0.0, c, x)
+    } else if h < 240.0 {
+        (0.0, x, c)
+    } else if h < 300.0 {
+        (x, 0.0, c)
+    } else {
+        (c, 0.0, x)
+    };
+
+    (r + m, g + m, b + m)
+}
+
+/// Convert normalized RGB channels to HSV (hue in degrees, 0.0 for grays)
+pub(crate) fn rgb_to_hsv(r: f64, g: f64, b: f64) -> (f64, f64, f64) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    if delta == 0.0 {
+        return (0.0, 0.0, max);
+    }
+
+    (hue_from_rgb(r, g, b, max, delta), delta / max, max)
+}
+
+/// Convert normalized RGB channels to HSL (hue in degrees, 0.0 for grays)
+pub(crate) fn rgb_to_hsl(r: f64, g: f64, b: f64) -> (f64, f64, f64) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let l = (max + min) / 2.0;
+
+    if delta == 0.0 {
+        return (0.0, 0.0, l);
+    }
+
+    let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+    (hue_from_rgb(r, g, b, max, delta), s, l)
+}
+
+/// Hue in degrees shared by the HSV and HSL conversions (requires `delta > 0`)
+fn hue_from_rgb(r: f64, g: f64, b: f64, max: f64, delta: f64) -> f64 {
+    if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    }
+}
@@ -0,0 +1,201 @@
+//! CSS Color Module Level 4 functional notation: `rgb()`, `rgba()`, `hsl()` and `hsla()`
+
+use super::Color;
+use crate::error::{ColorMapError, Result};
+
+/// Parse a functional CSS color into the color and its alpha (0.0 - 1.0)
+///
+/// Accepts the legacy comma syntax (`rgb(255, 0, 0, 0.5)`) and the modern
+/// space syntax with an optional slash alpha (`rgb(255 0 0 / 50%)`).
+/// `rgba` and `hsla` are aliases of `rgb` and `hsl`. Out-of-range
+/// components are clamped as the spec requires.
+pub(crate) fn parse_functional(input: &str) -> Result<(Color, f64)> {
+    let trimmed = input.trim();
+    let invalid = || ColorMapError::InvalidColor(trimmed.to_string());
+    let bad_component = |component: &str| ColorMapError::InvalidColorComponent {
+        input: trimmed.to_string(),
+        component: component.to_string(),
+    };
+
+    let open = trimmed.find('(').ok_or_else(invalid)?;
+    let function = trimmed[..open].trim_end().to_ascii_lowercase();
+    let args = trimmed[open + 1..].strip_suffix(')').ok_or_else(invalid)?;
+    let (channels, alpha) = split_components(args).ok_or_else(invalid)?;
+
+    let alpha = match alpha {
+        Some(token) => parse_alpha(token).ok_or_else(|| bad_component(token))?,
+        None => 1.0,
+    };
+
+    let color = match function.as_str() {
+        "rgb" | "rgba" => {
+            let mut rgb = [0; 3];
+            for (value, token) in rgb.iter_mut().zip(channels) {
+                *value = parse_rgb_channel(token).ok_or_else(|| bad_component(token))?;
+            }
+            Color::from_array(rgb)
+        }
+        "hsl" | "hsla" => {
+            let [h, s, l] = channels;
+            Color::from_hsl(
+                parse_hue(h).ok_or_else(|| bad_component(h))?,
+                parse_percentage(s).ok_or_else(|| bad_component(s))?,
+                parse_percentage(l).ok_or_else(|| bad_component(l))?,
+            )
+        }
+        _ => return Err(invalid()),
+    };
+
+    Ok((color, alpha))
+}
+
+/// Split arguments into three channel tokens and an optional alpha token
+///
+/// Commas select the legacy syntax; otherwise components are separated by
+/// whitespace and alpha follows a `/`. Mixing the two is rejected.
+fn split_components(args: &str) -> Option<([&str; 3], Option<&str>)> {
+    let (channels, alpha): (Vec<&str>, Option<&str>) = if args.contains(',') {
+        if args.contains('/') {
+            return None;
+        }
+        let mut parts: Vec<&str> = args.split(',').map(str::trim).collect();
+        let alpha = if parts.len() == 4 { parts.pop() } else { None };
+        (parts, alpha)
+    } else {
+        match args.split_once('/') {
+            Some((channels, alpha)) => (channels.split_whitespace().collect(), Some(alpha.trim())),
+            None => (args.split_whitespace().collect(), None),
+        }
+    };
+
+    Some((channels.try_into().ok()?, alpha))
+}
+
+/// A finite CSS `<number>`
+fn parse_number(token: &str) -> Option<f64> {
+    token.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+/// An `rgb()` channel: a number (0 - 255) or a percentage, clamped and rounded
+fn parse_rgb_channel(token: &str) -> Option<u8> {
+    let value = match token.strip_suffix('%') {
+        Some(percent) => parse_number(percent)? / 100.0 * 255.0,
+        None => parse_number(token)?,
+    };
+    Some(value.clamp(0.0, 255.0).round() as u8)
+}
+
+/// An alpha value: a number (0.0 - 1.0) or a percentage, clamped
+fn parse_alpha(token: &str) -> Option<f64> {
+    let value = match token.strip_suffix('%') {
+        Some(percent) => parse_number(percent)? / 100.0,
+        None => parse_number(token)?,
+    };
+    Some(value.clamp(0.0, 1.0))
+}
+
+/// A hue in degrees; bare numbers are degrees, `deg`, `grad`, `rad` and
+/// `turn` units are converted
+fn parse_hue(token: &str) -> Option<f64> {
+    let lower = token.to_ascii_lowercase();
+    let units = [
+        ("deg", 1.0),
+        ("grad", 0.9),
+        ("rad", 180.0 / std::f64::consts::PI),
+        ("turn", 360.0),
+    ];
+    for (unit, degrees) in units {
+        if let Some(value) = lower.strip_suffix(unit) {
+            return parse_number(value).map(|v| v * degrees);
+        }
+    }
+    parse_number(&lower)
+}
+
+/// HSL saturation or lightness as 0.0 - 1.0; bare numbers count as
+/// percentages like the modern syntax allows
+fn parse_percentage(token: &str) -> Option<f64> {
+    let value = parse_number(token.strip_suffix('%').unwrap_or(token))?;
+    Some((value / 100.0).clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Color {
+        parse_functional(s).unwrap().0
+    }
+
+    #[test]
+    fn test_rgb_syntaxes() {
+        let coral = Color::new(255, 87, 51);
+        assert_eq!(parse("rgb(255, 87, 51)"), coral);
+        assert_eq!(parse("rgb(255 87 51)"), coral);
+        assert_eq!(parse("  RGBA( 255 ,87,  51 )  "), coral);
+        assert_eq!(parse("rgb(100% 0% 0%)"), Color::RED);
+        assert_eq!(parse("rgb(100%, 50%, 0%)"), Color::new(255, 128, 0));
+        assert_eq!(parse("rgb(127.6 0 0)"), Color::new(128, 0, 0));
+
+        // Out-of-range components are clamped, not rejected
+        assert_eq!(parse("rgb(300, -20, 120%)"), Color::new(255, 0, 255));
+    }
+
+    #[test]
+    fn test_alpha() {
+        assert_eq!(parse_functional("rgb(1 2 3)").unwrap().1, 1.0);
+        assert_eq!(parse_functional("rgba(1, 2, 3, 0.25)").unwrap().1, 0.25);
+        assert_eq!(parse_functional("rgb(1 2 3 / 40%)").unwrap().1, 0.4);
+        assert_eq!(parse_functional("hsla(0 0% 0% / 2)").unwrap().1, 1.0);
+        assert_eq!(
+            parse_functional("rgb(1 2 3/ 0.5)").unwrap(),
+            (Color::new(1, 2, 3), 0.5)
+        );
+    }
+
+    #[test]
+    fn test_hsl_syntaxes() {
+        assert_eq!(parse("hsl(0, 100%, 50%)"), Color::RED);
+        assert_eq!(parse("hsl(120 100% 50%)"), Color::GREEN);
+        assert_eq!(parse("hsl(240deg 100% 50%)"), Color::BLUE);
+        assert_eq!(parse("hsl(0.5turn 100% 50%)"), Color::CYAN);
+        assert_eq!(parse("hsl(200grad, 100%, 50%)"), Color::CYAN);
+        assert_eq!(parse("hsl(3.14159265rad 100% 50%)"), Color::CYAN);
+        assert_eq!(parse("hsl(-60 100% 50%)"), Color::MAGENTA);
+        assert_eq!(parse("hsl(14, 100%, 60%)"), Color::new(255, 99, 51));
+        assert_eq!(parse("hsl(0 150% -5%)"), Color::BLACK);
+    }
+
+    #[test]
+    fn test_errors_name_the_component() {
+        let err = parse_functional("rgb(255, x, 0)").unwrap_err();
+        assert!(matches!(
+            &err,
+            ColorMapError::InvalidColorComponent { component, .. } if component == "x"
+        ));
+        assert!(err.to_string().contains("'x'"));
+
+        let err = parse_functional("hsl(10qux 50% 50%)").unwrap_err();
+        assert!(err.to_string().contains("'10qux'"));
+        let err = parse_functional("rgb(1 2 3 / half)").unwrap_err();
+        assert!(err.to_string().contains("'half'"));
+        assert!(parse_functional("rgb(inf 0 0)").is_err());
+    }
+
+    #[test]
+    fn test_malformed() {
+        for s in [
+            "rgb(255, 0)",
+            "rgb(1 2 3 4)",
+            "rgb(1, 2, 3, 4, 5)",
+            "rgb(1, 2 / 3)",
+            "rgb(1 2 3 /)",
+            "rgb 1 2 3",
+            "rgb(1 2 3",
+            "cmyk(0 0 0)",
+            "",
+        ] {
+            assert!(parse_functional(s).is_err(), "{} should fail", s);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! Translucent colors and source-over compositing
+
+use super::{css, Color};
+use crate::error::Result;
+use serde::{Deserialize, Serialize};
+
+/// An RGB color with an alpha channel (0 = transparent, 255 = opaque)
+///
+/// Channels are straight (not premultiplied) and gamma-encoded, so
+/// compositing happens on the sRGB values like most image editors do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rgba {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Rgba {
+    /// Create a new translucent color
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Attach an alpha value to an opaque color
+    pub const fn from_color(color: Color, a: u8) -> Self {
+        Self::new(color.r, color.g, color.b, a)
+    }
+
+    /// Parse a CSS color string, keeping its alpha
+    ///
+    /// Accepts everything [`Color::from_css_string`] does; hex and named
+    /// colors are fully opaque.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Rgba;
+    ///
+    /// let color = Rgba::from_css_string("rgb(255 0 0 / 50%)").unwrap();
+    /// assert_eq!(color, Rgba::new(255, 0, 0, 128));
+    /// ```
+    pub fn from_css_string(s: &str) -> Result<Self> {
+        if s.contains('(') {
+            let (color, alpha) = css::parse_functional(s)?;
+            return Ok(Rgba::from_color(color, (alpha * 255.0).round() as u8));
+        }
+        Color::from_css_string(s).map(Rgba::from)
+    }
+
+    /// The color channels without alpha
+    pub const fn color(&self) -> Color {
+        Color::new(self.r, self.g, self.b)
+    }
+
+    /// Composite this color over an opaque background (source-over)
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, Rgba};
+    ///
+    /// let half_white = Rgba::new(255, 255, 255, 128);
+    /// assert_eq!(half_white.over(Color::black()), Color::new(128, 128, 128));
+    /// ```
+    pub fn over(&self, background: Color) -> Color {
+        self.over_rgba(Rgba::from_color(background, 255)).color()
+    }
+
+    /// Composite this color over another translucent color (source-over)
+    ///
+    /// Stack several layers by folding from the bottom up, e.g.
+    /// `top.over_rgba(middle.over_rgba(bottom))`. Compositing two fully
+    /// transparent colors gives transparent black.
+    pub fn over_rgba(&self, background: Rgba) -> Rgba {
+        let src_a = self.a as f64 / 255.0;
+        let dst_a = background.a as f64 / 255.0;
+        let out_a = src_a + dst_a * (1.0 - src_a);
+
+        if out_a == 0.0 {
+            return Rgba::new(0, 0, 0, 0);
+        }
+
+        let channel = |src: u8, dst: u8| {
+            let value = (src as f64 * src_a + dst as f64 * dst_a * (1.0 - src_a)) / out_a;
+            value.round().clamp(0.0, 255.0) as u8
+        };
+
+        Rgba {
+            r: channel(self.r, background.r),
+            g: channel(self.g, background.g),
+            b: channel(self.b, background.b),
+            a: (out_a * 255.0).round() as u8,
+        }
+    }
+}
+
+impl From<Color> for Rgba {
+    /// Converts to a fully opaque color
+    fn from(color: Color) -> Self {
+        Rgba::from_color(color, 255)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_over_extremes() {
+        let background = Color::new(10, 120, 230);
+        let source = Rgba::new(200, 50, 0, 255);
+
+        assert_eq!(source.over(background), source.color());
+        assert_eq!(Rgba::new(200, 50, 0, 0).over(background), background);
+        assert_eq!(
+            Rgba::new(200, 50, 0, 0).over_rgba(Rgba::new(1, 2, 3, 0)),
+            Rgba::new(0, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_over_half_alpha() {
+        // Composited in gamma space: 255 * 128/255 = 128
+        let half_white = Rgba::new(255, 255, 255, 128);
+        assert_eq!(half_white.over(Color::black()), Color::new(128, 128, 128));
+        assert_eq!(half_white.over(Color::white()), Color::white());
+    }
+
+    #[test]
+    fn test_over_rgba_stacking() {
+        let red = Rgba::new(255, 0, 0, 128);
+        let blue = Rgba::new(0, 0, 255, 128);
+
+        // Two half-transparent layers give three-quarter coverage
+        let stacked = red.over_rgba(blue);
+        assert_eq!(stacked.a, 192);
+        assert_eq!(stacked, Rgba::new(170, 0, 85, 192));
+
+        // Stacking then flattening matches flattening layer by layer
+        let background = Color::new(0, 255, 0);
+        let layered = red.over(blue.over(background));
+        let flattened = stacked.over(background);
+        assert!(layered.distance_rgb(&flattened) <= 2.0);
+
+        assert_eq!(Rgba::from(Color::white()), Rgba::new(255, 255, 255, 255));
+    }
+
+    #[test]
+    fn test_from_css_string() {
+        assert_eq!(
+            Rgba::from_css_string("rgba(10, 20, 30, 0.2)").unwrap(),
+            Rgba::new(10, 20, 30, 51)
+        );
+        assert_eq!(
+            Rgba::from_css_string("hsl(0 100% 50% / 0)").unwrap(),
+            Rgba::new(255, 0, 0, 0)
+        );
+        assert_eq!(
+            Rgba::from_css_string("#0A141E").unwrap(),
+            Rgba::new(10, 20, 30, 255)
+        );
+        assert!(Rgba::from_css_string("rgba(1, 2, 3, nope)").is_err());
+    }
+}
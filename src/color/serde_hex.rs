@@ -0,0 +1,127 @@
+//! Serde helpers that represent a [`Color`] as a `"#RRGGBB"` string
+//!
+//! Use with `#[serde(with = "scala_chromatica::color::serde_hex")]` on a
+//! `Color` field. Deserialization accepts both the hex string and the
+//! default `{"r": .., "g": .., "b": ..}` object, so existing files keep
+//! loading; [`ColorStop::color`](crate::ColorStop::color) uses it through
+//! `deserialize_with`.
+//!
+//! Hex strings are only used by human-readable formats such as JSON.
+//! Binary formats like bincode store the three channels as `Color`'s
+//! derived impls do, since they can't tell a string from a struct.
+//!
+//! # Examples
+//! ```
+//! use scala_chromatica::Color;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Theme {
+//!     #[serde(with = "scala_chromatica::color::serde_hex")]
+//!     accent: Color,
+//! }
+//!
+//! let theme = Theme { accent: Color::new(255, 87, 51) };
+//! assert_eq!(serde_json::to_string(&theme).unwrap(), r##"{"accent":"#FF5733"}"##);
+//!
+//! let old: Theme = serde_json::from_str(r#"{"accent":{"r":255,"g":87,"b":51}}"#).unwrap();
+//! assert_eq!(old.accent, theme.accent);
+//! ```
+
+use super::Color;
+use serde::de::value::MapAccessDeserializer;
+use serde::de::{self, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Serialize a color as an uppercase `"#RRGGBB"` string, or as channels in
+/// binary formats
+pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&color.to_hex())
+    } else {
+        color.serialize(serializer)
+    }
+}
+
+/// Deserialize a color from a hex string (any format [`Color::from_hex`]
+/// accepts) or an `{"r", "g", "b"}` object
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(ColorVisitor)
+    } else {
+        Color::deserialize(deserializer)
+    }
+}
+
+struct ColorVisitor;
+
+impl<'de> Visitor<'de> for ColorVisitor {
+    type Value = Color;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a \"#RRGGBB\" hex string or an {\"r\", \"g\", \"b\"} object")
+    }
+
+    fn visit_str<E: de::Error>(self, hex: &str) -> Result<Color, E> {
+        Color::from_hex(hex).map_err(E::custom)
+    }
+
+    // The derived impl reports out-of-range or missing channels precisely
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Color, A::Error> {
+        Color::deserialize(MapAccessDeserializer::new(map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(serde::Serialize, Deserialize, Debug, PartialEq)]
+    struct Wrapper(#[serde(with = "super")] Color);
+
+    #[test]
+    fn test_round_trip() {
+        let json = serde_json::to_string(&Wrapper(Color::new(255, 87, 51))).unwrap();
+        assert_eq!(json, r##""#FF5733""##);
+        let back: Wrapper = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.0, Color::new(255, 87, 51));
+    }
+
+    #[test]
+    fn test_accepts_both_representations() {
+        let parse = |json: &str| serde_json::from_str::<Wrapper>(json).map(|w| w.0);
+        assert_eq!(parse(r##""#f0a""##).unwrap(), Color::new(255, 0, 170));
+        assert_eq!(parse(r#""00FF00""#).unwrap(), Color::GREEN);
+        assert_eq!(
+            parse(r#"{"r": 1, "g": 2, "b": 3}"#).unwrap(),
+            Color::new(1, 2, 3)
+        );
+
+        assert!(parse(r##""#GGGGGG""##).is_err());
+        assert!(parse(r#"{"r": 300, "g": 0, "b": 0}"#)
+            .unwrap_err()
+            .to_string()
+            .contains("invalid value: integer `300`, expected u8"));
+        assert!(parse(r#"{"r": 1, "g": 2}"#)
+            .unwrap_err()
+            .to_string()
+            .contains("missing field `b`"));
+        assert!(parse("42").is_err());
+        assert!(parse(r#""é1""#).is_err());
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_bincode_round_trip() {
+        let color = Color::new(255, 87, 51);
+        let bytes = bincode::serialize(&color).unwrap();
+        assert_eq!(bytes, [255, 87, 51]);
+        assert_eq!(bincode::deserialize::<Color>(&bytes).unwrap(), color);
+
+        // Binary formats store channels through the helper too
+        let bytes = bincode::serialize(&Wrapper(color)).unwrap();
+        assert_eq!(bytes, [255, 87, 51]);
+        assert_eq!(bincode::deserialize::<Wrapper>(&bytes).unwrap().0, color);
+    }
+}
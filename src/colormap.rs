@@ -14,41 +14,378 @@
 //! let mid_color = map.get_color(0.5); // Gets color halfway between red and blue
 //! ```
 
-use crate::color::Color;
-use serde::{Deserialize, Serialize};
+use crate::color::conversion::{oklab_to_rgb, rgb_to_oklab};
+use crate::color::{
+    BlendMode, Color, Color16, ColorBlindType, ColorF, ColorVisionDeficiency, GrayscaleMethod,
+    HuePath, Rgba,
+};
+use crate::error::{ColorMapError, Result};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
 
 /// A color stop in a gradient (position + color)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ColorStop {
     /// Position along the gradient (0.0 to 1.0)
+    ///
+    /// Deserializing a position outside this range (or a non-finite one)
+    /// fails with the [`ColorMapError::InvalidPosition`] message.
+    #[serde(deserialize_with = "deserialize_position")]
     pub position: f64,
     /// RGB color at this position
+    ///
+    /// Accepts a `"#RRGGBB"` string as well as an `{"r", "g", "b"}` object
+    /// when deserializing, see [`serde_hex`](crate::color::serde_hex).
+    #[serde(deserialize_with = "crate::color::serde_hex::deserialize")]
     pub color: Color,
     /// Optional name for documentation/UI purposes
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    /// Where the 50% blend point of the segment starting at this stop sits,
+    /// as a fraction of the segment (0.5 if unset)
+    ///
+    /// Works like the midpoint diamonds in Photoshop and GIMP gradients.
+    /// Values are clamped to [`ColorStop::MIN_MIDPOINT`] - [`ColorStop::MAX_MIDPOINT`]
+    /// when sampling.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub midpoint: Option<f64>,
+    /// Easing curve for the segment starting at this stop (linear by default)
+    #[serde(default, skip_serializing_if = "Easing::is_linear")]
+    pub easing: Easing,
+    /// Opacity at this stop (0 = transparent, 255 if unset)
+    ///
+    /// Only [`ColorMap::get_rgba`] reads it; [`ColorMap::get_color`] stays opaque.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alpha: Option<u8>,
+    /// Free-form annotations, e.g. the data value a stop marks or who picked it
+    ///
+    /// Kept by every transform that keeps the stop itself ([`ColorMap::reversed`],
+    /// [`ColorMap::map_colors`], joins and position changes); stops generated
+    /// by sampling or blending start without metadata.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, String>,
 }
 
 impl ColorStop {
+    /// Smallest effective [`ColorStop::midpoint`]
+    pub const MIN_MIDPOINT: f64 = 0.01;
+
+    /// Largest effective [`ColorStop::midpoint`]
+    pub const MAX_MIDPOINT: f64 = 0.99;
+
     /// Create a new color stop
+    ///
+    /// Positions are clamped to 0.0 - 1.0; NaN is kept as is and fails
+    /// [`ColorMap::validate`]. Use [`ColorStop::try_new`] to reject both.
     pub fn new(position: f64, color: Color) -> Self {
         Self {
             position: position.clamp(0.0, 1.0),
             color,
             name: None,
+            midpoint: None,
+            easing: Easing::Linear,
+            alpha: None,
+            metadata: BTreeMap::new(),
         }
     }
 
+    /// Create a color stop, rejecting positions [`ColorStop::new`] would clamp
+    ///
+    /// Unlike `new`, which clamps out-of-range positions and lets NaN
+    /// through, this fails with [`ColorMapError::InvalidPosition`] for any
+    /// position that isn't a finite number in 0.0 - 1.0.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, ColorMapError, ColorStop};
+    ///
+    /// assert!(ColorStop::try_new(0.5, Color::RED).is_ok());
+    /// assert!(matches!(
+    ///     ColorStop::try_new(f64::NAN, Color::RED),
+    ///     Err(ColorMapError::InvalidPosition(p)) if p.is_nan()
+    /// ));
+    /// assert!(ColorStop::try_new(1.5, Color::RED).is_err());
+    /// ```
+    pub fn try_new(position: f64, color: Color) -> Result<Self> {
+        Ok(Self::new(check_position(position)?, color))
+    }
+
     /// Create a new color stop with a name
     pub fn with_name(position: f64, color: Color, name: impl Into<String>) -> Self {
         Self {
-            position: position.clamp(0.0, 1.0),
-            color,
             name: Some(name.into()),
+            ..Self::new(position, color)
+        }
+    }
+
+    /// Create a color stop from a hex string
+    ///
+    /// Accepts every format [`Color::from_hex`] does and returns its error
+    /// for malformed input.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, ColorMap, ColorStop};
+    ///
+    /// let sunset = ColorMap::with_stops(
+    ///     "Sunset",
+    ///     vec![
+    ///         ColorStop::from_hex(0.0, "#2E1A47")?,
+    ///         ColorStop::from_hex(0.5, "#FF5733")?,
+    ///         ColorStop::named_hex(1.0, "#FFD700", "gold")?,
+    ///     ],
+    /// );
+    /// assert_eq!(sunset.get_color(0.5), Color::new(255, 87, 51));
+    /// assert!(ColorStop::from_hex(0.5, "#GG0000").is_err());
+    /// # Ok::<(), scala_chromatica::ColorMapError>(())
+    /// ```
+    pub fn from_hex(position: f64, hex: &str) -> Result<Self> {
+        Ok(Self::new(position, Color::from_hex(hex)?))
+    }
+
+    /// Create a named color stop from a hex string
+    pub fn named_hex(position: f64, hex: &str, name: impl Into<String>) -> Result<Self> {
+        Ok(Self::with_name(position, Color::from_hex(hex)?, name))
+    }
+
+    /// Set where the blend toward the next stop reaches 50%
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, ColorMap, ColorStop};
+    ///
+    /// let map = ColorMap::with_stops(
+    ///     "Early",
+    ///     vec![
+    ///         ColorStop::new(0.0, Color::BLACK).with_midpoint(0.25),
+    ///         ColorStop::new(1.0, Color::WHITE),
+    ///     ],
+    /// );
+    /// assert_eq!(map.get_color(0.25), Color::new(127, 127, 127));
+    /// ```
+    pub fn with_midpoint(mut self, midpoint: f64) -> Self {
+        self.midpoint = Some(midpoint);
+        self
+    }
+
+    /// Set the opacity at this stop
+    pub fn with_alpha(mut self, alpha: u8) -> Self {
+        self.alpha = Some(alpha);
+        self
+    }
+
+    /// Attach a metadata entry, replacing any previous value for `key`
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, ColorStop};
+    ///
+    /// let stop = ColorStop::new(0.8, Color::RED)
+    ///     .with_metadata("threshold", "40 dB")
+    ///     .with_metadata("picked_by", "ana");
+    /// assert_eq!(stop.metadata["threshold"], "40 dB");
+    /// ```
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Opacity at this stop, 255 if unset
+    pub fn alpha_or_opaque(&self) -> u8 {
+        self.alpha.unwrap_or(255)
+    }
+
+    /// Set the easing curve for the segment toward the next stop
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, ColorMap, ColorStop, Easing};
+    ///
+    /// let bands = ColorMap::with_stops(
+    ///     "Bands",
+    ///     vec![
+    ///         ColorStop::new(0.0, Color::RED).with_easing(Easing::Step),
+    ///         ColorStop::new(0.5, Color::BLUE),
+    ///     ],
+    /// );
+    /// assert_eq!(bands.get_color(0.49), Color::RED);
+    /// assert_eq!(bands.get_color(0.5), Color::BLUE);
+    /// ```
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Warp a segment-local factor so 0.5 lands on the midpoint, then ease it
+    fn warp(&self, t: f64) -> f64 {
+        let t = match self.midpoint {
+            None => t,
+            Some(midpoint) => {
+                let midpoint = if midpoint.is_nan() {
+                    0.5
+                } else {
+                    midpoint.clamp(Self::MIN_MIDPOINT, Self::MAX_MIDPOINT)
+                };
+                if t <= midpoint {
+                    0.5 * t / midpoint
+                } else {
+                    0.5 + 0.5 * (t - midpoint) / (1.0 - midpoint)
+                }
+            }
+        };
+        self.easing.apply(t)
+    }
+}
+
+/// Easing curve applied to the blend factor within a gradient segment
+///
+/// Every curve maps 0.0 to 0.0 and 1.0 to 1.0, so colors stay continuous
+/// at the stops.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Easing {
+    /// Constant-speed blend
+    #[default]
+    Linear,
+    /// Starts slowly (quadratic, `t²`)
+    EaseIn,
+    /// Ends slowly (quadratic, `1 - (1 - t)²`)
+    EaseOut,
+    /// Starts and ends slowly (piecewise quadratic)
+    EaseInOut,
+    /// Hermite smoothstep, `3t² - 2t³`
+    SmoothStep,
+    /// Holds the starting color until the very end of the segment, for hard bands
+    Step,
+}
+
+impl Easing {
+    /// Check whether this is the linear default (used to skip serialization)
+    pub(crate) fn is_linear(&self) -> bool {
+        *self == Easing::Linear
+    }
+
+    /// Map a blend factor in 0.0 - 1.0 through the curve
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::Easing;
+    ///
+    /// assert_eq!(Easing::EaseIn.apply(0.5), 0.25);
+    /// assert_eq!(Easing::SmoothStep.apply(0.5), 0.5);
+    /// ```
+    pub fn apply(&self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => t * (2.0 - t),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - 2.0 * (1.0 - t) * (1.0 - t)
+                }
+            }
+            Easing::SmoothStep => t * t * (3.0 - 2.0 * t),
+            Easing::Step => {
+                if t < 1.0 {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+        }
+    }
+
+    /// The curve traversed backwards, for [`ColorMap::reversed`]
+    ///
+    /// `Step` has no mirror among the curves; `reversed` replaces those
+    /// segments with hard edges instead of calling this.
+    fn mirrored(self) -> Easing {
+        match self {
+            Easing::EaseIn => Easing::EaseOut,
+            Easing::EaseOut => Easing::EaseIn,
+            other => other,
+        }
+    }
+}
+
+/// How [`ColorMap::concat`] joins two colormaps at the split point
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Junction {
+    /// Keep both end colors for an abrupt change at the split
+    #[default]
+    Hard,
+    /// Merge the two end stops into one with their mixed color, so the
+    /// gradient stays continuous across the split
+    Blend,
+}
+
+/// Color space used to interpolate between neighboring stops
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InterpolationMode {
+    /// Linear interpolation of the RGB channels
+    #[default]
+    Rgb,
+    /// Interpolation of hue, saturation and value along the shorter hue arc
+    Hsv,
+    /// Linear interpolation in the perceptual Oklab space
+    Oklab,
+    /// Interpolation of Oklch lightness, chroma and hue along the shorter hue arc
+    Oklch,
+    /// Like [`InterpolationMode::Oklch`], but the long way around the hue wheel
+    OklchLongArc,
+}
+
+impl InterpolationMode {
+    /// Check whether this is the default mode (used to skip serialization)
+    pub(crate) fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Interpolate between two colors using this mode
+    fn interpolate(&self, from: &Color, to: &Color, t: f64) -> Color {
+        match self {
+            InterpolationMode::Rgb => from.lerp(to, t),
+            InterpolationMode::Hsv => from.lerp_hsv(to, t, HuePath::Shorter),
+            InterpolationMode::Oklab => from.lerp_oklab(to, t),
+            InterpolationMode::Oklch => from.lerp_oklch(to, t, HuePath::Shorter),
+            InterpolationMode::OklchLongArc => from.lerp_oklch(to, t, HuePath::Longer),
         }
     }
 }
 
+/// Descriptive information attached to a colormap
+///
+/// All fields are optional so that colormap files without metadata
+/// keep loading unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ColorMapMetadata {
+    /// Who created the colormap
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// Short human-readable description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Free-form tags for search and grouping
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// License the colormap is distributed under
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    /// Creation date (e.g. ISO 8601)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+}
+
+impl ColorMapMetadata {
+    /// Check whether no metadata has been set
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 /// A colormap with multiple color stops and smooth interpolation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ColorMap {
@@ -56,14 +393,38 @@ pub struct ColorMap {
     pub name: String,
     /// Ordered list of color stops
     pub stops: Vec<ColorStop>,
+    /// Optional descriptive metadata
+    #[serde(default, skip_serializing_if = "ColorMapMetadata::is_empty")]
+    pub metadata: ColorMapMetadata,
+    /// Color space used between stops (RGB unless specified)
+    #[serde(default, skip_serializing_if = "InterpolationMode::is_default")]
+    pub interpolation: InterpolationMode,
 }
 
 impl ColorMap {
+    /// Default tolerance for [`ColorMap::optimize_stops`], in RGB units
+    pub const DEFAULT_OPTIMIZE_TOLERANCE: f64 = 1.0;
+
+    /// Evenly spaced samples taken across the crossfade in [`ColorMap::append_gradient`]
+    const BLEND_ZONE_SAMPLES: usize = 16;
+
+    /// Evenly spaced samples per segment when [`ColorMap::blend`] follows curved segments
+    const BLEND_SEGMENT_SAMPLES: usize = 16;
+
+    /// Distance between the two stops that make a hard edge, e.g. in
+    /// [`ColorMap::concat_gradients`]
+    pub const JOIN_GAP: f64 = 1e-9;
+
+    /// Symbols used by [`ColorMap::to_ascii_preview`], from darkest to lightest
+    pub const ASCII_RAMP: &'static str = " .:-=+*#%@";
+
     /// Create a new colormap with a given name
     pub fn new(name: impl Into<String>) -> Self {
         Self {
             name: name.into(),
             stops: Vec::new(),
+            metadata: ColorMapMetadata::default(),
+            interpolation: InterpolationMode::default(),
         }
     }
 
@@ -72,15 +433,113 @@ impl ColorMap {
         let mut colormap = Self {
             name: name.into(),
             stops,
+            metadata: ColorMapMetadata::default(),
+            interpolation: InterpolationMode::default(),
         };
         colormap.sort_stops();
         colormap
     }
 
+    /// Create a colormap with `colors` spread evenly across 0.0 - 1.0
+    ///
+    /// The first color lands at 0.0 and the last at 1.0. An empty list
+    /// gives an empty map, and a single color is placed at 0.0.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, ColorMap};
+    ///
+    /// let map = ColorMap::equidistant("Traffic", vec![Color::RED, Color::YELLOW, Color::GREEN]);
+    /// assert_eq!(map.stops[1].position, 0.5);
+    /// assert_eq!(map.get_color(1.0), Color::GREEN);
+    /// ```
+    pub fn equidistant(name: impl Into<String>, colors: Vec<Color>) -> Self {
+        Self::equidistant_range(name, colors, 0.0, 1.0)
+    }
+
+    /// Create a colormap with `colors` spread evenly from `start` to `end`
+    ///
+    /// Same edge cases as [`ColorMap::equidistant`]; a single color is
+    /// placed at `start`.
+    pub fn equidistant_range(
+        name: impl Into<String>,
+        colors: Vec<Color>,
+        start: f64,
+        end: f64,
+    ) -> Self {
+        let last = colors.len().saturating_sub(1).max(1) as f64;
+        let stops = colors
+            .into_iter()
+            .enumerate()
+            .map(|(i, color)| ColorStop::new(start + (end - start) * i as f64 / last, color))
+            .collect();
+        Self::with_stops(name, stops)
+    }
+
+    /// Start building a colormap with [`ColorMapBuilder`]
+    pub fn builder() -> ColorMapBuilder {
+        ColorMapBuilder::new()
+    }
+
+    /// Attach metadata to the colormap (builder style)
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{ColorMap, ColorMapMetadata};
+    ///
+    /// let map = ColorMap::new("Documented").with_metadata(ColorMapMetadata {
+    ///     author: Some("Jane Doe".to_string()),
+    ///     tags: vec!["warm".to_string()],
+    ///     ..Default::default()
+    /// });
+    /// assert_eq!(map.metadata.tags, vec!["warm"]);
+    /// ```
+    pub fn with_metadata(mut self, metadata: ColorMapMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// The color stops, sorted by position
+    pub fn stops(&self) -> &[ColorStop] {
+        &self.stops
+    }
+
+    /// Mutable access to the color stops
+    ///
+    /// Changing positions through this slice bypasses the sort invariant;
+    /// call [`ColorMap::validate`] afterwards if positions were modified.
+    pub fn stops_mut(&mut self) -> &mut [ColorStop] {
+        &mut self.stops
+    }
+
     /// Add a color stop to the gradient
+    ///
+    /// A stop added at an existing stop's position makes a hard edge: it
+    /// goes [`ColorMap::JOIN_GAP`] after the existing one, so the map stays
+    /// valid.
     pub fn add_stop(&mut self, stop: ColorStop) {
         self.stops.push(stop);
-        self.sort_stops();
+        self.separate_hard_edges();
+    }
+
+    /// Add a color stop from a hex string, keeping stops sorted
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::ColorMap;
+    ///
+    /// let mut map = ColorMap::new("Ocean");
+    /// map.add_hex_stop(0.0, "#001F3F")?;
+    /// map.add_hex_stop(1.0, "#7FDBFF")?;
+    /// map.add_hex_stop(0.5, "#0074D9")?;
+    /// assert_eq!(map.stops[1].color.to_hex(), "#0074D9");
+    /// assert!(map.add_hex_stop(0.25, "blue").is_err());
+    /// assert_eq!(map.stops.len(), 3);
+    /// # Ok::<(), scala_chromatica::ColorMapError>(())
+    /// ```
+    pub fn add_hex_stop(&mut self, position: f64, hex: &str) -> Result<()> {
+        self.add_stop(ColorStop::from_hex(position, hex)?);
+        Ok(())
     }
 
     /// Remove a color stop by index (minimum 2 stops required)
@@ -90,58 +549,381 @@ impl ColorMap {
         }
     }
 
+    /// Remove stops that their neighbors already reproduce within `tolerance`
+    ///
+    /// Applies Ramer-Douglas-Peucker simplification to the gradient: a stop is
+    /// dropped when interpolating between the surviving stops around it lands
+    /// within `tolerance` (Euclidean RGB distance) of its color. The first and
+    /// last stops are always kept.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, ColorMap, ColorStop};
+    ///
+    /// let mut map = ColorMap::new("Redundant");
+    /// map.add_stop(ColorStop::new(0.0, Color::new(0, 0, 0)));
+    /// map.add_stop(ColorStop::new(0.5, Color::new(100, 100, 100)));
+    /// map.add_stop(ColorStop::new(1.0, Color::new(200, 200, 200)));
+    ///
+    /// map.optimize_stops(ColorMap::DEFAULT_OPTIMIZE_TOLERANCE);
+    /// assert_eq!(map.stops.len(), 2);
+    /// ```
+    pub fn optimize_stops(&mut self, tolerance: f64) {
+        if self.stops.len() < 3 || tolerance.is_nan() {
+            return;
+        }
+
+        let last = self.stops.len() - 1;
+        let mut keep = vec![false; self.stops.len()];
+        keep[0] = true;
+        keep[last] = true;
+        self.mark_significant_stops(0, last, tolerance, &mut keep);
+
+        let mut keep = keep.into_iter();
+        self.stops.retain(|_| keep.next().unwrap_or(true));
+    }
+
+    /// Recursive step of [`ColorMap::optimize_stops`] over `stops[start..=end]`
+    fn mark_significant_stops(&self, start: usize, end: usize, tolerance: f64, keep: &mut [bool]) {
+        if end <= start + 1 {
+            return;
+        }
+
+        let first = &self.stops[start];
+        let last = &self.stops[end];
+        let span = last.position - first.position;
+
+        let mut worst = None;
+        let mut worst_error = tolerance;
+        for (i, stop) in self.stops.iter().enumerate().take(end).skip(start + 1) {
+            let t = if span > 0.0 {
+                (stop.position - first.position) / span
+            } else {
+                0.0
+            };
+            let predicted = self.interpolation.interpolate(&first.color, &last.color, t);
+            let error = predicted.distance_rgb(&stop.color);
+            if error > worst_error {
+                worst = Some(i);
+                worst_error = error;
+            }
+        }
+
+        if let Some(i) = worst {
+            keep[i] = true;
+            self.mark_significant_stops(start, i, tolerance, keep);
+            self.mark_significant_stops(i, end, tolerance, keep);
+        }
+    }
+
+    /// Move every stop by `delta`, clamping positions to 0.0 - 1.0
+    ///
+    /// Stops pushed past either end pile up there; stops the move makes
+    /// coincide are merged, keeping the one that was originally furthest
+    /// along, so the result always stays valid. Hard edges that were already
+    /// there (stops at most `2 * JOIN_GAP` apart, or sharing a position) are
+    /// never merged and stay [`ColorMap::JOIN_GAP`] apart. A non-finite
+    /// `delta` is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::ColorMap;
+    ///
+    /// let mut map = ColorMap::grayscale_scheme();
+    /// map.shift_positions(0.1);
+    /// assert_eq!(map.stops[0].position, 0.1);
+    /// ```
+    pub fn shift_positions(&mut self, delta: f64) {
+        if !delta.is_finite() {
+            return;
+        }
+        self.transform_positions(|position| position + delta);
+    }
+
+    /// Scale stop positions around `origin`: `(pos - origin) * factor + origin`
+    ///
+    /// Positions are clamped to 0.0 - 1.0 and coincident stops merged as in
+    /// [`ColorMap::shift_positions`]. A negative factor mirrors the gradient
+    /// around `origin`. Non-finite arguments are ignored.
+    pub fn scale_positions(&mut self, factor: f64, origin: f64) {
+        if !factor.is_finite() || !origin.is_finite() {
+            return;
+        }
+        self.transform_positions(|position| (position - origin) * factor + origin);
+    }
+
+    /// Apply `f` to every position, then clamp, re-sort and merge stops that `f` made coincide
+    fn transform_positions(&mut self, f: impl Fn(f64) -> f64) {
+        self.sort_stops();
+
+        // Stops forming a hard edge beforehand share a group and are never merged
+        let mut group = 0usize;
+        let mut previous = f64::NEG_INFINITY;
+        let mut grouped: Vec<(usize, ColorStop)> = Vec::with_capacity(self.stops.len());
+        for mut stop in self.stops.drain(..) {
+            if stop.position - previous > 2.0 * Self::JOIN_GAP {
+                group += 1;
+            }
+            previous = stop.position;
+            stop.position = f(stop.position).clamp(0.0, 1.0);
+            grouped.push((group, stop));
+        }
+        grouped.sort_by(|a, b| a.1.position.total_cmp(&b.1.position));
+
+        // Sorting is stable, so among equal positions the later stop was originally further along
+        let mut merged: Vec<(usize, ColorStop)> = Vec::with_capacity(grouped.len());
+        for (group, stop) in grouped {
+            while merged.last().is_some_and(|(last_group, last)| {
+                *last_group != group && (stop.position - last.position).abs() <= f64::EPSILON
+            }) {
+                merged.pop();
+            }
+            merged.push((group, stop));
+        }
+        self.stops = merged.into_iter().map(|(_, stop)| stop).collect();
+        self.separate_hard_edges();
+    }
+
+    /// Sort the stops and pull stops sharing a position apart into hard edges
+    ///
+    /// Later stops at a repeated position move [`ColorMap::JOIN_GAP`] past
+    /// the one before; if that runs past 1.0, the stops at the end are moved
+    /// back instead. Used wherever outside input (files, `add_stop`) may mark
+    /// a hard edge by repeating a position, which [`ColorMap::validate`]
+    /// rejects.
+    pub(crate) fn separate_hard_edges(&mut self) {
+        self.sort_stops();
+        for i in 1..self.stops.len() {
+            let previous = self.stops[i - 1].position;
+            if self.stops[i].position - previous <= f64::EPSILON {
+                self.stops[i].position = previous + Self::JOIN_GAP;
+            }
+        }
+        let mut limit = 1.0;
+        for stop in self.stops.iter_mut().rev() {
+            // Stray NaN positions sort last; leave them for validate to report
+            if stop.position.is_nan() || stop.position <= limit {
+                break;
+            }
+            stop.position = limit;
+            limit -= Self::JOIN_GAP;
+        }
+    }
+
     /// Sort stops by position (maintains gradient order)
     fn sort_stops(&mut self) {
-        self.stops
-            .sort_by(|a, b| a.position.partial_cmp(&b.position).unwrap());
+        // total_cmp so stray NaN positions sort last instead of panicking
+        self.stops.sort_by(|a, b| a.position.total_cmp(&b.position));
+    }
+
+    /// Check the colormap for internal consistency
+    ///
+    /// A valid colormap has at least one stop, every position is finite and
+    /// within `[0.0, 1.0]`, and stops are sorted with no two sharing a position.
+    pub fn validate(&self) -> Result<()> {
+        if self.stops.is_empty() {
+            return Err(ColorMapError::InvalidStops(
+                "colormap has no stops".to_string(),
+            ));
+        }
+
+        for (i, stop) in self.stops.iter().enumerate() {
+            if !stop.position.is_finite() {
+                return Err(ColorMapError::InvalidStops(format!(
+                    "stop {} has non-finite position {}",
+                    i, stop.position
+                )));
+            }
+            if !(0.0..=1.0).contains(&stop.position) {
+                return Err(ColorMapError::InvalidStops(format!(
+                    "stop {} has position {} outside [0.0, 1.0]",
+                    i, stop.position
+                )));
+            }
+        }
+
+        for (i, pair) in self.stops.windows(2).enumerate() {
+            let gap = pair[1].position - pair[0].position;
+            if gap < 0.0 {
+                return Err(ColorMapError::InvalidStops(format!(
+                    "stops {} and {} are out of order ({} > {})",
+                    i,
+                    i + 1,
+                    pair[0].position,
+                    pair[1].position
+                )));
+            }
+            if gap <= f64::EPSILON {
+                return Err(ColorMapError::InvalidStops(format!(
+                    "stops {} and {} share position {}",
+                    i,
+                    i + 1,
+                    pair[0].position
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check whether [`ColorMap::validate`] succeeds
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
     }
 
     /// Get color at a specific position (0.0 to 1.0) by interpolating between stops
     pub fn get_color(&self, position: f64) -> Color {
-        let position = position.clamp(0.0, 1.0);
+        // Empty maps are allowed here and fall back to black below
+        debug_assert!(
+            self.stops.is_empty() || self.is_valid(),
+            "invalid colormap '{}': {}",
+            self.name,
+            self.validate().unwrap_err()
+        );
 
-        if self.stops.is_empty() {
-            return Color::black();
+        match self.segment_at(position) {
+            None => Color::black(),
+            Some((stop, _, 0.0)) => stop.color,
+            Some((stop1, stop2, t)) => {
+                self.interpolation
+                    .interpolate(&stop1.color, &stop2.color, t)
+            }
         }
+    }
 
-        if self.stops.len() == 1 {
-            return self.stops[0].color;
+    /// Get the color at a position without rounding to 8-bit channels
+    ///
+    /// Uses the same stops and interpolation mode as [`ColorMap::get_color`],
+    /// but keeps the result as a [`ColorF`] so further processing can
+    /// quantize once at the end.
+    pub fn get_color_f(&self, position: f64) -> ColorF {
+        match self.segment_at(position) {
+            None => ColorF::default(),
+            Some((stop1, stop2, t)) => {
+                let from = ColorF::from(stop1.color);
+                let to = ColorF::from(stop2.color);
+                match self.interpolation {
+                    InterpolationMode::Rgb => from.lerp(&to, t),
+                    InterpolationMode::Hsv => from.lerp_hsv(&to, t, HuePath::Shorter),
+                    InterpolationMode::Oklab => from.lerp_oklab(&to, t),
+                    InterpolationMode::Oklch => from.lerp_oklch(&to, t, HuePath::Shorter),
+                    InterpolationMode::OklchLongArc => from.lerp_oklch(&to, t, HuePath::Longer),
+                }
+            }
         }
+    }
+
+    /// Get the color and opacity at a position
+    ///
+    /// The color matches [`ColorMap::get_color`]; alpha is interpolated
+    /// linearly between the stops' [`ColorStop::alpha`] values, using the
+    /// same midpoint and easing. An empty map gives transparent black.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, ColorMap, ColorStop, Rgba};
+    ///
+    /// let fade = ColorMap::with_stops(
+    ///     "Fade",
+    ///     vec![
+    ///         ColorStop::new(0.0, Color::RED),
+    ///         ColorStop::new(1.0, Color::RED).with_alpha(0),
+    ///     ],
+    /// );
+    /// assert_eq!(fade.get_rgba(0.5), Rgba::new(255, 0, 0, 128));
+    /// ```
+    pub fn get_rgba(&self, position: f64) -> Rgba {
+        let alpha = match self.segment_at(position) {
+            None => return Rgba::new(0, 0, 0, 0),
+            Some((stop1, stop2, t)) => {
+                let (a1, a2) = (
+                    stop1.alpha_or_opaque() as f64,
+                    stop2.alpha_or_opaque() as f64,
+                );
+                (a1 + (a2 - a1) * t).round() as u8
+            }
+        };
+        Rgba::from_color(self.get_color(position), alpha)
+    }
+
+    /// Pair the colormap with an opacity ramp from `alpha_at_zero` at 0.0
+    /// to `alpha_at_one` at 1.0
+    ///
+    /// See [`ColorMapA`]; [`ColorMap::fade_in`] and [`ColorMap::fade_out`]
+    /// cover the common ramps.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::ColorMap;
+    ///
+    /// let vignette = ColorMap::grayscale_scheme().with_alpha_ramp(0, 200);
+    /// assert_eq!(vignette.get_color_a(0.5).1, 100);
+    /// ```
+    pub fn with_alpha_ramp(&self, alpha_at_zero: u8, alpha_at_one: u8) -> ColorMapA {
+        ColorMapA::new(self.clone(), alpha_at_zero, alpha_at_one)
+    }
+
+    /// Ramp from fully transparent at 0.0 to opaque at 1.0
+    pub fn fade_in(&self) -> ColorMapA {
+        self.with_alpha_ramp(0, 255)
+    }
+
+    /// Ramp from opaque at 0.0 to fully transparent at 1.0
+    pub fn fade_out(&self) -> ColorMapA {
+        self.with_alpha_ramp(255, 0)
+    }
+
+    /// Get the color at a position with 16-bit channels
+    ///
+    /// Interpolates at full precision (see [`ColorMap::get_color_f`]) and
+    /// quantizes once, so long ramps stay smooth where 8-bit output bands.
+    pub fn get_color16(&self, position: f64) -> Color16 {
+        Color16::from(self.get_color_f(position))
+    }
+
+    /// Stops surrounding `position` (clamped to 0.0 - 1.0) and the local
+    /// interpolation factor between them
+    ///
+    /// Outside the stop range, or with a single stop, both stops are the
+    /// nearest one and the factor is 0.0. Returns `None` for an empty map.
+    fn segment_at(&self, position: f64) -> Option<(&ColorStop, &ColorStop, f64)> {
+        let position = position.clamp(0.0, 1.0);
+        let first = self.stops.first()?;
+        let last = self.stops.last()?;
 
-        // Before first stop
-        if position <= self.stops[0].position {
-            return self.stops[0].color;
+        // Before first stop (this also covers single-stop maps)
+        if self.stops.len() == 1 || position <= first.position {
+            return Some((first, first, 0.0));
         }
 
         // After last stop
-        if position >= self.stops.last().unwrap().position {
-            return self.stops.last().unwrap().color;
+        if position >= last.position {
+            return Some((last, last, 0.0));
         }
 
-        // Find surrounding stops and interpolate
-        for i in 0..self.stops.len() - 1 {
-            let stop1 = &self.stops[i];
-            let stop2 = &self.stops[i + 1];
-
+        // Find surrounding stops
+        for pair in self.stops.windows(2) {
+            let (stop1, stop2) = (&pair[0], &pair[1]);
             if position >= stop1.position && position <= stop2.position {
                 let range = stop2.position - stop1.position;
                 let t = if range > 0.0 {
-                    (position - stop1.position) / range
+                    stop1.warp((position - stop1.position) / range)
                 } else {
                     0.0
                 };
-                return stop1.color.lerp(&stop2.color, t);
+                return Some((stop1, stop2, t));
             }
         }
 
-        // Fallback to last color
-        self.stops.last().unwrap().color
+        // Fallback to last stop
+        Some((last, last, 0.0))
     }
 
     /// Create a new colormap with all stops reversed
     ///
     /// This reverses the gradient by flipping all stop positions:
-    /// a stop at position 0.2 becomes 0.8, etc.
+    /// a stop at position 0.2 becomes 0.8, etc. Stop names and metadata
+    /// stay with their stops.
     ///
     /// # Examples
     /// ```
@@ -155,231 +937,3517 @@ impl ColorMap {
     /// // Now starts with blue at 0.0 and ends with red at 1.0
     /// ```
     pub fn reversed(&self) -> Self {
-        let reversed_stops = self
-            .stops
-            .iter()
-            .map(|stop| ColorStop {
-                position: 1.0 - stop.position,
-                color: stop.color,
-                name: stop.name.clone(),
-            })
-            .collect::<Vec<_>>();
+        // A segment's midpoint and easing move to the stop that now starts it, mirrored
+        let mut reversed_stops = Vec::with_capacity(self.stops.len());
+        for (i, stop) in self.stops.iter().enumerate() {
+            let segment = i.checked_sub(1).map(|prev| &self.stops[prev]);
+            let position = 1.0 - stop.position;
+            match segment {
+                // A step holds its start color, which now ends the segment:
+                // jump to it right away with a hard edge
+                Some(start)
+                    if start.easing == Easing::Step
+                        && stop.position - start.position > 2.0 * Self::JOIN_GAP =>
+                {
+                    reversed_stops.push(ColorStop {
+                        position,
+                        midpoint: None,
+                        easing: Easing::Linear,
+                        ..stop.clone()
+                    });
+                    reversed_stops.push(ColorStop {
+                        alpha: start.alpha,
+                        ..ColorStop::new(position + Self::JOIN_GAP, start.color)
+                    });
+                }
+                _ => reversed_stops.push(ColorStop {
+                    position,
+                    midpoint: segment.and_then(|start| start.midpoint).map(|m| 1.0 - m),
+                    easing: segment.map_or(Easing::Linear, |start| start.easing.mirrored()),
+                    ..stop.clone()
+                }),
+            }
+        }
 
-        Self::with_stops(format!("{} (Reversed)", self.name), reversed_stops)
+        let mut reversed = Self::with_stops(format!("{} (Reversed)", self.name), reversed_stops);
+        reversed.interpolation = self.interpolation;
+        reversed
     }
 
-    /// Default HSV-based color scheme (smooth rainbow)
-    pub fn default_scheme() -> Self {
-        Self::with_stops(
-            "Default",
-            vec![
-                ColorStop::new(0.0, Color::black()),
-                ColorStop::new(0.2, Color::from_hsv(240.0, 1.0, 1.0)), // Blue
-                ColorStop::new(0.5, Color::from_hsv(120.0, 1.0, 1.0)), // Green
-                ColorStop::new(0.8, Color::from_hsv(0.0, 1.0, 1.0)),   // Red
+    /// Extract the part of the gradient between `start` and `end`, stretched
+    /// to fill 0.0 - 1.0
+    ///
+    /// Stops inside the range are kept (with names and metadata) and
+    /// rescaled. Each end gets the stop already sitting there, or a new stop
+    /// with the original's color at that position, so sampling the result
+    /// at `t` matches sampling the original at `start + t * (end - start)`.
+    /// A segment with a midpoint or curved easing that either end cuts is
+    /// sampled into linear stops on both sides of the cut, since part of the
+    /// curve isn't a curve of its own. The name, interpolation mode and
+    /// metadata are unchanged, and an empty colormap stays empty.
+    ///
+    /// # Errors
+    /// [`ColorMapError::InvalidPosition`] if either bound is not a finite
+    /// number in 0.0 - 1.0, and [`ColorMapError::InvalidStops`] when
+    /// `start >= end`.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::ColorMap;
+    ///
+    /// let fire = ColorMap::fire_scheme();
+    /// let hot = fire.subrange(2.0 / 3.0, 1.0)?;
+    /// assert_eq!(hot.get_color(0.0), fire.get_color(2.0 / 3.0));
+    /// assert_eq!(hot.get_color(1.0), fire.get_color(1.0));
+    /// assert!(fire.subrange(0.5, 0.5).is_err());
+    /// # Ok::<(), scala_chromatica::ColorMapError>(())
+    /// ```
+    pub fn subrange(&self, start: f64, end: f64) -> Result<Self> {
+        let (start, end) = (check_position(start)?, check_position(end)?);
+        if start >= end {
+            return Err(ColorMapError::InvalidStops(format!(
+                "subrange start {} must be below end {}",
+                start, end
+            )));
+        }
+
+        let mut subrange = self.clone();
+        if self.stops.is_empty() {
+            return Ok(subrange);
+        }
+
+        let scale = end - start;
+        subrange.stops = self
+            .clipped_stops(start, end)
+            .into_iter()
+            .map(|stop| ColorStop {
+                position: ((stop.position - start) / scale).clamp(0.0, 1.0),
+                ..stop
+            })
+            .collect();
+        if let Some(first) = subrange.stops.first_mut() {
+            first.position = 0.0;
+        }
+        if let Some(last) = subrange.stops.last_mut() {
+            last.position = 1.0;
+        }
+        Ok(subrange)
+    }
+
+    /// Create a copy with `n` evenly spaced stops sampled from this gradient
+    ///
+    /// Stop `i` sits at `i / (n - 1)` with the color (and opacity) of
+    /// [`ColorMap::get_rgba`] there, for formats that need a fixed stop
+    /// count or to compare two gradients stop by stop. The name,
+    /// interpolation mode and metadata are kept; stop names, midpoints and
+    /// easing are not. An empty colormap stays empty.
+    ///
+    /// # Errors
+    /// [`ColorMapError::InvalidStops`] when `n` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, ColorMap};
+    ///
+    /// let gray = ColorMap::grayscale_scheme().resampled(5)?;
+    /// assert_eq!(gray.stops.len(), 5);
+    /// assert_eq!(gray.stops[2].position, 0.5);
+    /// assert_eq!(gray.stops[2].color, Color::new(128, 128, 128));
+    /// assert!(ColorMap::grayscale_scheme().resampled(1).is_err());
+    /// # Ok::<(), scala_chromatica::ColorMapError>(())
+    /// ```
+    pub fn resampled(&self, n: usize) -> Result<Self> {
+        if n < 2 {
+            return Err(ColorMapError::InvalidStops(format!(
+                "cannot resample to {} stops (need at least 2)",
+                n
+            )));
+        }
+
+        let mut resampled = self.clone();
+        if self.stops.is_empty() {
+            return Ok(resampled);
+        }
+
+        let last = (n - 1) as f64;
+        resampled.stops = (0..n)
+            .map(|i| {
+                let position = i as f64 / last;
+                let rgba = self.get_rgba(position);
+                ColorStop {
+                    alpha: (rgba.a != 255).then_some(rgba.a),
+                    ..ColorStop::new(position, rgba.color())
+                }
+            })
+            .collect();
+        Ok(resampled)
+    }
+
+    /// Remap the position axis like a photo editor's levels control
+    ///
+    /// Each stop moves to
+    ///
+    /// ```text
+    /// clamp((pos - in_black) / (in_white - in_black), 0.0, 1.0).powf(1.0 / gamma)
+    ///     * (out_white - out_black) + out_black
+    /// ```
+    ///
+    /// so colors appear in new places while staying the same. The gradient
+    /// below `in_black` and above `in_white` is clipped, pinning the colors
+    /// at those two points to `out_black` and `out_white`. A `gamma` above
+    /// 1.0 pushes stops toward the end, below 1.0 toward the start.
+    /// Positions are clamped to 0.0 - 1.0 and coincident stops merged as in
+    /// [`ColorMap::shift_positions`]. Non-finite arguments, a `gamma` that
+    /// isn't positive, or `in_white <= in_black` return an unchanged copy.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::ColorMap;
+    ///
+    /// let gray = ColorMap::grayscale_scheme();
+    /// let contrast = gray.level_adjust(0.25, 0.75, 1.0, 0.0, 1.0);
+    /// assert_eq!(contrast.get_color(0.0), gray.get_color(0.25));
+    /// assert_eq!(contrast.get_color(1.0), gray.get_color(0.75));
+    /// ```
+    pub fn level_adjust(
+        &self,
+        in_black: f64,
+        in_white: f64,
+        gamma: f64,
+        out_black: f64,
+        out_white: f64,
+    ) -> Self {
+        let mut adjusted = self.clone();
+        let finite = [in_black, in_white, gamma, out_black, out_white]
+            .iter()
+            .all(|v| v.is_finite());
+        if !finite || gamma <= 0.0 || in_white <= in_black || self.stops.is_empty() {
+            return adjusted;
+        }
+
+        let (start, end) = (in_black.max(0.0), in_white.min(1.0));
+        if start < end {
+            adjusted.stops = self.clipped_stops(start, end);
+        }
+        adjusted.transform_positions(|position| {
+            let t = ((position - in_black) / (in_white - in_black)).clamp(0.0, 1.0);
+            t.powf(1.0 / gamma) * (out_white - out_black) + out_black
+        });
+        adjusted
+    }
+
+    /// The stops strictly between `start` and `end` at their original
+    /// positions, plus the stop already at each end or one split there
+    fn clipped_stops(&self, start: f64, end: f64) -> Vec<ColorStop> {
+        let mut split = self.clone();
+        split.stops = split.split_at(start);
+        split.stops = split.split_at(end);
+
+        let at = |stop: &ColorStop, position: f64| (stop.position - position).abs() <= f64::EPSILON;
+        let boundary = |position: f64| split.stops.iter().find(|stop| at(stop, position)).cloned();
+        let inside = split
+            .stops
+            .iter()
+            .filter(|stop| stop.position > start && stop.position < end)
+            .filter(|stop| !at(stop, start) && !at(stop, end))
+            .cloned();
+
+        boundary(start)
+            .into_iter()
+            .chain(inside)
+            .chain(boundary(end))
+            .collect()
+    }
+
+    /// Create a new colormap rotated cyclically by `offset`
+    ///
+    /// Every stop moves to `(position + offset).rem_euclid(1.0)`, so
+    /// sampling the result at `t` matches sampling the original at
+    /// `(t - offset).rem_euclid(1.0)`. Negative offsets rotate the other way.
+    /// The original's endpoints meet at `offset`, where differing colors
+    /// make a hard edge; the original position that lands on 0.0 and 1.0 is
+    /// added at both ends (split from its segment if no stop sits there, as
+    /// in [`ColorMap::subrange`]).
+    /// Stop names and metadata move with their stops. A non-finite or whole
+    /// `offset` returns an identical copy.
+    ///
+    /// Useful for period cycling in fractal coloring.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, ColorMap};
+    ///
+    /// let ring = ColorMap::equidistant("Ring", vec![Color::RED, Color::BLUE, Color::RED]);
+    /// let turned = ring.shifted(0.5);
+    /// assert_eq!(turned.get_color(0.0), Color::BLUE);
+    /// assert_eq!(turned.get_color(0.5), Color::RED);
+    /// ```
+    pub fn shifted(&self, offset: f64) -> Self {
+        let offset = if offset.is_finite() {
+            offset.rem_euclid(1.0)
+        } else {
+            0.0
+        };
+        if offset == 0.0 || offset == 1.0 || self.stops.is_empty() {
+            return self.clone();
+        }
+
+        // The original position that becomes both 0.0 and 1.0
+        let split = 1.0 - offset;
+        let mut placed = self.clone();
+        placed.stops = self.placed_stops(0.0, 1.0);
+        let full = placed.split_at(split);
+        let at_split = |stop: &ColorStop| (stop.position - split).abs() <= f64::EPSILON;
+
+        // The tail of the original becomes the head of the result
+        let head = full
+            .iter()
+            .filter(|stop| stop.position >= split || at_split(stop))
+            .map(|stop| ColorStop {
+                position: if at_split(stop) {
+                    0.0
+                } else {
+                    (stop.position - split).clamp(0.0, 1.0)
+                },
+                ..stop.clone()
+            });
+        let tail = full
+            .iter()
+            .filter(|stop| stop.position <= split || at_split(stop))
+            .map(|stop| ColorStop {
+                position: if at_split(stop) {
+                    1.0
+                } else {
+                    (stop.position + offset).clamp(0.0, 1.0)
+                },
+                ..stop.clone()
+            });
+
+        let mut stops: Vec<ColorStop> = Vec::with_capacity(full.len() + 2);
+        for mut stop in head.chain(tail) {
+            if let Some(last) = stops.last() {
+                if stop.position - last.position <= f64::EPSILON {
+                    if stop.color == last.color && stop.alpha == last.alpha {
+                        // The stop that starts the next segment carries its shape
+                        stops.pop();
+                    } else {
+                        // Keep both colors at the seam for a hard edge
+                        stop.position = last.position + Self::JOIN_GAP;
+                    }
+                }
+            }
+            stops.push(stop);
+        }
+
+        let mut shifted = self.clone();
+        shifted.stops = stops;
+        shifted.sort_stops();
+        shifted
+    }
+
+    /// Join `other` after this colormap to form one longer gradient
+    ///
+    /// `self` is squeezed into 0.0 - 0.5 and `other` into 0.5 - 1.0.
+    /// `blend_zone` (clamped to 0.0 - 0.5) is the width of a crossfade
+    /// centered at 0.5 in which both maps contribute; 0.0 gives a hard join.
+    /// The result is named `"{self} + {other}"` and uses this map's
+    /// interpolation mode.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::ColorMap;
+    ///
+    /// let map = ColorMap::ocean_scheme().append_gradient(&ColorMap::fire_scheme(), 0.1);
+    /// assert_eq!(map.name, "Ocean + Fire");
+    /// assert_eq!(map.get_color(0.25), ColorMap::ocean_scheme().get_color(0.5));
+    /// ```
+    pub fn append_gradient(&self, other: &ColorMap, blend_zone: f64) -> Self {
+        let half_zone = blend_zone.clamp(0.0, 0.5) / 2.0;
+        let name = format!("{} + {}", self.name, other.name);
+        if half_zone <= 0.0 || half_zone.is_nan() {
+            let mut joined = Self::concat_gradients(&[self.clone(), other.clone()]);
+            joined.name = name;
+            return joined;
+        }
+
+        let (zone_start, zone_end) = (0.5 - half_zone, 0.5 + half_zone);
+        let first = |p: f64| self.get_color_f((p / 0.5).min(1.0));
+        let second = |p: f64| other.get_color_f(((p - 0.5) / 0.5).max(0.0));
+
+        // Sample the crossfade evenly, plus wherever either map has a stop
+        let mut zone_positions: Vec<f64> = (0..=Self::BLEND_ZONE_SAMPLES)
+            .map(|i| {
+                let t = i as f64 / Self::BLEND_ZONE_SAMPLES as f64;
+                zone_start * (1.0 - t) + zone_end * t
+            })
+            .chain(self.stops.iter().map(|s| s.position * 0.5))
+            .chain(other.stops.iter().map(|s| 0.5 + s.position * 0.5))
+            .filter(|p| (zone_start..=zone_end).contains(p))
+            .collect();
+        zone_positions.sort_by(|a, b| a.total_cmp(b));
+        zone_positions.dedup_by(|a, b| (*a - *b).abs() <= f64::EPSILON);
+
+        let zone_stops = zone_positions.into_iter().map(|p| {
+            let w = (p - zone_start) / (zone_end - zone_start);
+            ColorStop::new(p, first(p).lerp(&second(p), w).to_color())
+        });
+        let stops = self
+            .placed_stops(0.0, 0.5)
+            .into_iter()
+            .filter(|s| s.position < zone_start)
+            .chain(zone_stops)
+            .chain(
+                other
+                    .placed_stops(0.5, 1.0)
+                    .into_iter()
+                    .filter(|s| s.position > zone_end),
+            )
+            .collect();
+
+        let mut joined = Self::with_stops(name, stops);
+        joined.interpolation = self.interpolation;
+        joined
+    }
+
+    /// Join `other` before this colormap; the mirror of
+    /// [`ColorMap::append_gradient`], named `"{other} + {self}"`
+    pub fn prepend_gradient(&self, other: &ColorMap, blend_zone: f64) -> Self {
+        other.append_gradient(self, blend_zone)
+    }
+
+    /// Join `other` after this colormap at an arbitrary `split` point
+    ///
+    /// `self` is compressed into 0.0 - `split` and `other` into `split` -
+    /// 1.0, keeping every stop with its name and metadata. With
+    /// [`Junction::Hard`] both end colors meet at `split`; with
+    /// [`Junction::Blend`] they merge into a single stop mixed in this map's
+    /// interpolation mode. `split` is clamped to 0.0 - 1.0 (NaN means 0.5),
+    /// and a split at either end gives a rescaled copy of just one map. The
+    /// result is named `"{self} + {other}"` and uses this map's interpolation
+    /// mode.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{ColorMap, Junction};
+    ///
+    /// let ocean = ColorMap::ocean_scheme();
+    /// let fire = ColorMap::fire_scheme();
+    /// let map = ocean.concat(&fire, 0.25, Junction::Hard);
+    /// assert_eq!(map.get_color(0.125), ocean.get_color(0.5));
+    /// assert_eq!(map.get_color(1.0), fire.get_color(1.0));
+    /// ```
+    pub fn concat(&self, other: &ColorMap, split: f64, junction: Junction) -> Self {
+        let split = if split.is_nan() {
+            0.5
+        } else if split <= Self::JOIN_GAP {
+            0.0
+        } else if split >= 1.0 - Self::JOIN_GAP {
+            1.0
+        } else {
+            split
+        };
+
+        let mut stops = if split > 0.0 {
+            self.placed_stops(0.0, split)
+        } else {
+            Vec::new()
+        };
+        let mut tail = if split < 1.0 {
+            other.placed_stops(split, 1.0)
+        } else {
+            Vec::new()
+        };
+
+        if let (Some(last), Some(next)) = (stops.last(), tail.first_mut()) {
+            let same = last.color == next.color && last.alpha == next.alpha;
+            if same || junction == Junction::Blend {
+                // The stop that starts the next segment carries its shape
+                let (from, to) = (last.alpha_or_opaque() as f64, next.alpha_or_opaque() as f64);
+                let alpha = ((from + to) / 2.0).round() as u8;
+                next.color = self
+                    .interpolation
+                    .interpolate(&last.color, &next.color, 0.5);
+                next.alpha = (alpha != 255).then_some(alpha);
+                next.name = next.name.take().or_else(|| last.name.clone());
+                let mut metadata = last.metadata.clone();
+                metadata.append(&mut next.metadata);
+                next.metadata = metadata;
+                stops.pop();
+            } else {
+                // Keep both colors at the seam for a hard edge
+                next.position = last.position + Self::JOIN_GAP;
+            }
+        }
+        stops.append(&mut tail);
+
+        let mut joined = Self::with_stops(format!("{} + {}", self.name, other.name), stops);
+        joined.interpolation = self.interpolation;
+        joined
+    }
+
+    /// Join any number of colormaps end to end in equal proportions
+    ///
+    /// Map `i` of `n` covers `i / n` to `(i + 1) / n` with hard joins between
+    /// them. The result is named with the maps' names joined by `" + "` and
+    /// uses the first map's interpolation mode. An empty slice gives an
+    /// empty, unnamed colormap.
+    pub fn concat_gradients(maps: &[ColorMap]) -> Self {
+        let name = maps
+            .iter()
+            .map(|map| map.name.as_str())
+            .collect::<Vec<_>>()
+            .join(" + ");
+
+        let mut stops: Vec<ColorStop> = Vec::new();
+        for (i, map) in maps.iter().enumerate() {
+            let start = i as f64 / maps.len() as f64;
+            let end = (i + 1) as f64 / maps.len() as f64;
+            for mut stop in map.placed_stops(start, end) {
+                if let Some(last) = stops.last() {
+                    if stop.position - last.position <= f64::EPSILON {
+                        if stop.color == last.color {
+                            continue;
+                        }
+                        // Keep both colors at the seam for a hard edge
+                        stop.position = last.position + Self::JOIN_GAP;
+                    }
+                }
+                stops.push(stop);
+            }
+        }
+
+        let mut joined = Self::with_stops(name, stops);
+        if let Some(first) = maps.first() {
+            joined.interpolation = first.interpolation;
+        }
+        joined
+    }
+
+    /// Mix this colormap toward `other` by `t`, e.g. to animate between schemes
+    ///
+    /// Sampling the result at `p` gives `self.get_color_f(p)` blended
+    /// linearly toward `other.get_color_f(p)` by `t` (clamped to 0.0 - 1.0;
+    /// NaN counts as 0.0), with opacity mixed the same way. The result has a
+    /// stop wherever either map does, so it is exact at every original stop
+    /// and, for linear RGB maps, everywhere in between. Segments with
+    /// midpoints, easing or another interpolation mode are additionally
+    /// sampled at evenly spaced points on either side of each midpoint, and
+    /// [`Easing::Step`] jumps become hard edges. The result keeps this map's name and
+    /// metadata, and `t = 0.0` returns an identical copy of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, ColorMap};
+    ///
+    /// let fire = ColorMap::fire_scheme();
+    /// let halfway = fire.blend(&ColorMap::grayscale_scheme(), 0.5);
+    /// assert_eq!(halfway.get_color(0.0), Color::BLACK);
+    /// assert_eq!(halfway.get_color(1.0), Color::WHITE);
+    /// assert_eq!(fire.blend(&ColorMap::ocean_scheme(), 0.0).stops, fire.stops);
+    /// ```
+    pub fn blend(&self, other: &ColorMap, t: f64) -> Self {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        if t == 0.0 {
+            return self.clone();
+        }
+
+        let mut positions: Vec<f64> = [0.0, 1.0]
+            .into_iter()
+            .chain(self.breakpoints())
+            .chain(other.breakpoints())
+            .collect();
+        positions.sort_by(|a, b| a.total_cmp(b));
+        positions.dedup_by(|a, b| (*a - *b).abs() <= f64::EPSILON);
+
+        if !self.is_piecewise_linear() || !other.is_piecewise_linear() {
+            // A pointwise mix of curves isn't linear between the stops
+            let n = Self::BLEND_SEGMENT_SAMPLES;
+            let last = positions.last().copied();
+            positions = positions
+                .windows(2)
+                .flat_map(|w| {
+                    // Hard edges don't need filling in
+                    let count = if w[1] - w[0] > 2.0 * Self::JOIN_GAP {
+                        n
+                    } else {
+                        1
+                    };
+                    (0..count).map(move |i| w[0] + (w[1] - w[0]) * i as f64 / count as f64)
+                })
+                .chain(last)
+                .collect();
+        }
+
+        let stops = positions
+            .into_iter()
+            .map(|p| {
+                let color = self
+                    .get_color_f(p)
+                    .lerp(&other.get_color_f(p), t)
+                    .to_color();
+                let (a1, a2) = (self.get_rgba(p).a as f64, other.get_rgba(p).a as f64);
+                let alpha = (a1 + (a2 - a1) * t).round() as u8;
+                ColorStop {
+                    alpha: (alpha != 255).then_some(alpha),
+                    ..ColorStop::new(p, color)
+                }
+            })
+            .collect();
+
+        let mut blended = Self::with_stops(self.name.clone(), stops);
+        blended.metadata = self.metadata.clone();
+        blended
+    }
+
+    /// Positions where the gradient has a corner or a jump: the stops, each
+    /// segment's midpoint, and just before the end of each `Step` segment
+    fn breakpoints(&self) -> Vec<f64> {
+        let mut positions: Vec<f64> = self.stops.iter().map(|stop| stop.position).collect();
+        for pair in self.stops.windows(2) {
+            let (start, end) = (&pair[0], &pair[1]);
+            let width = end.position - start.position;
+            if width <= 2.0 * Self::JOIN_GAP {
+                continue;
+            }
+            if let Some(midpoint) = start.midpoint.filter(|m| !m.is_nan()) {
+                let midpoint = midpoint.clamp(ColorStop::MIN_MIDPOINT, ColorStop::MAX_MIDPOINT);
+                positions.push(start.position + width * midpoint);
+            }
+            if start.easing == Easing::Step {
+                positions.push(end.position - Self::JOIN_GAP);
+            }
+        }
+        positions
+    }
+
+    /// Check whether every segment is a plain linear RGB blend
+    fn is_piecewise_linear(&self) -> bool {
+        self.interpolation == InterpolationMode::Rgb
+            && self
+                .stops
+                .iter()
+                .all(|stop| stop.midpoint.is_none() && stop.easing.is_linear())
+    }
+
+    /// This map's stops with one at `position`, splitting the segment there
+    ///
+    /// Linear and [`Easing::Step`] segments get a single unnamed stop that
+    /// continues the segment's shape on both sides. Part of any other curve
+    /// isn't one of the curves, so such a segment is sampled into linear
+    /// stops on either side of the split, as [`ColorMap::blend`] does.
+    fn split_at(&self, position: f64) -> Vec<ColorStop> {
+        let mut stops = self.stops.clone();
+        if stops
+            .iter()
+            .any(|stop| (stop.position - position).abs() <= f64::EPSILON)
+        {
+            return stops;
+        }
+
+        let index = stops.partition_point(|stop| stop.position < position);
+        let sampled = |p: f64| {
+            let rgba = self.get_rgba(p);
+            ColorStop {
+                alpha: (rgba.a != 255).then_some(rgba.a),
+                ..ColorStop::new(p, rgba.color())
+            }
+        };
+        let (start, end) = match (index.checked_sub(1), stops.get(index)) {
+            (Some(left), Some(end)) => (&stops[left], end),
+            // Outside the stops the gradient is a constant color
+            _ => {
+                stops.insert(index, sampled(position));
+                return stops;
+            }
+        };
+
+        if start.easing == Easing::Step || (start.midpoint.is_none() && start.easing.is_linear()) {
+            let split = ColorStop {
+                midpoint: start.midpoint,
+                easing: start.easing,
+                ..sampled(position)
+            };
+            stops.insert(index, split);
+            return stops;
+        }
+
+        let n = Self::BLEND_SEGMENT_SAMPLES;
+        let (from, to) = (start.position, end.position);
+        let mut positions: Vec<f64> = [(from, position), (position, to)]
+            .into_iter()
+            .flat_map(|(lo, hi)| (1..n).map(move |i| lo + (hi - lo) * i as f64 / n as f64))
+            .chain([position])
+            .chain(
+                self.breakpoints()
+                    .into_iter()
+                    .filter(|&p| p > from && p < to),
+            )
+            .collect();
+        positions.sort_by(|a, b| a.total_cmp(b));
+        positions.dedup_by(|a, b| (*a - *b).abs() <= f64::EPSILON);
+
+        stops[index - 1].midpoint = None;
+        stops[index - 1].easing = Easing::Linear;
+        stops.splice(index..index, positions.into_iter().map(sampled));
+        stops
+    }
+
+    /// This map's stops rescaled into `start` - `end`, with extra stops at
+    /// either end if the map doesn't already reach 0.0 and 1.0
+    fn placed_stops(&self, start: f64, end: f64) -> Vec<ColorStop> {
+        let mut stops: Vec<ColorStop> = self
+            .stops
+            .iter()
+            .map(|stop| ColorStop {
+                position: start + stop.position * (end - start),
+                ..stop.clone()
+            })
+            .collect();
+        if self.stops.first().is_none_or(|s| s.position > 0.0) {
+            stops.insert(0, ColorStop::new(start, self.get_color(0.0)));
+        }
+        if self.stops.last().is_none_or(|s| s.position < 1.0) {
+            stops.push(ColorStop::new(end, self.get_color(1.0)));
+        }
+        stops
+    }
+
+    /// Create a photographic negative of the colormap
+    ///
+    /// Every stop color is inverted with [`Color::inverted`] while positions
+    /// stay put; unlike [`ColorMap::reversed`], the gradient keeps its
+    /// direction. The colormap name, stop names and all metadata are kept
+    /// unchanged, so inverting twice gives back an identical colormap.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, ColorMap};
+    ///
+    /// let dark = ColorMap::grayscale_scheme().inverted();
+    /// assert_eq!(dark.name, "Grayscale");
+    /// assert_eq!(dark.get_color(0.0), Color::WHITE);
+    /// ```
+    pub fn inverted(&self) -> Self {
+        self.map_colors(|color| color.inverted())
+    }
+
+    /// Invert every stop color in place; see [`ColorMap::inverted`]
+    pub fn invert_colors(&mut self) {
+        for stop in &mut self.stops {
+            stop.color = stop.color.inverted();
+        }
+    }
+
+    /// Create a new colormap by applying `f` to every stop color
+    ///
+    /// Everything else (name, stop positions, names and metadata, colormap
+    /// metadata) is preserved.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::ColorMap;
+    ///
+    /// let pastel_fire = ColorMap::fire_scheme().map_colors(|c| c.lighten(0.2));
+    /// ```
+    pub fn map_colors(&self, f: impl Fn(Color) -> Color) -> Self {
+        let mut colormap = self.clone();
+        for stop in &mut colormap.stops {
+            stop.color = f(stop.color);
+        }
+        colormap
+    }
+
+    /// Create a new colormap with every stop's hue rotated by `degrees`
+    ///
+    /// Grays have no hue and are left unchanged. Rotating by 120° turns an
+    /// ocean gradient into an analogous green-toned one.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, ColorMap};
+    ///
+    /// let map = ColorMap::equidistant("Reds", vec![Color::BLACK, Color::RED]);
+    /// assert_eq!(map.rotate_hue(120.0).get_color(1.0), Color::GREEN);
+    /// ```
+    pub fn rotate_hue(&self, degrees: f64) -> Self {
+        self.map_colors(|color| color.rotate_hue(degrees))
+    }
+
+    /// Create a muted copy of the colormap for backgrounds behind data
+    ///
+    /// Each stop color is interpolated toward its Rec. 709 luma gray
+    /// (`0.2126 R + 0.7152 G + 0.0722 B`) by `amount`: 0.0 leaves it
+    /// unchanged and 1.0 is the same as
+    /// [`to_grayscale(GrayscaleMethod::Rec709)`](ColorMap::to_grayscale).
+    pub fn desaturate(&self, amount: f64) -> Self {
+        self.map_colors(|color| color.lerp(&color.to_grayscale(GrayscaleMethod::Rec709), amount))
+    }
+
+    /// Create a copy of the colormap with every stop blended toward
+    /// `tint_color` by `strength` (0.0 = unchanged, 1.0 = solid tint)
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, ColorMap};
+    ///
+    /// let sepia = ColorMap::grayscale_scheme().tint(Color::new(112, 66, 20), 0.3);
+    /// assert!(sepia.get_color(1.0).r > sepia.get_color(1.0).b);
+    /// ```
+    pub fn tint(&self, tint_color: Color, strength: f64) -> Self {
+        self.map_colors(|color| color.lerp(&tint_color, strength))
+    }
+
+    /// Create a new colormap with every stop's HSV saturation multiplied by
+    /// `factor`, clamped to 0.0 - 1.0
+    pub fn adjust_saturation(&self, factor: f64) -> Self {
+        self.map_colors(|color| {
+            let (h, s, v) = color.to_hsv();
+            ColorF::from_hsv(h, (s * factor).clamp(0.0, 1.0), v).to_color()
+        })
+    }
+
+    /// Create a new colormap with every stop's HSV value multiplied by
+    /// `factor`, clamped to 0.0 - 1.0
+    pub fn adjust_value(&self, factor: f64) -> Self {
+        self.map_colors(|color| {
+            let (h, s, v) = color.to_hsv();
+            ColorF::from_hsv(h, s, (v * factor).clamp(0.0, 1.0)).to_color()
+        })
+    }
+
+    /// Create a grayscale version of the colormap, e.g. for print previews
+    pub fn to_grayscale(&self, method: GrayscaleMethod) -> Self {
+        self.map_colors(|color| color.to_grayscale(method))
+    }
+
+    /// Render the gradient as `width` ASCII characters, dark to light
+    ///
+    /// Each sample's [`Color::perceived_brightness`] picks a symbol from
+    /// [`ColorMap::ASCII_RAMP`], so black maps to a space and white to `@`.
+    /// Useful in logs and CI output where ANSI colors aren't available.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::ColorMap;
+    ///
+    /// assert_eq!(ColorMap::grayscale_scheme().to_ascii_preview(3), " :@");
+    /// ```
+    pub fn to_ascii_preview(&self, width: usize) -> String {
+        let ramp = Self::ASCII_RAMP.as_bytes();
+        self.sample_evenly(width)
+            .map(|color| {
+                let level = color.perceived_brightness() * (ramp.len() - 1) as f64;
+                ramp[(level.round() as usize).min(ramp.len() - 1)] as char
+            })
+            .collect()
+    }
+
+    /// [`ColorMap::to_ascii_preview`] followed by a second line listing the
+    /// hex color of each sample, separated by spaces
+    pub fn to_ascii_preview_with_legend(&self, width: usize) -> String {
+        let legend: Vec<String> = self.sample_evenly(width).map(|c| c.to_hex()).collect();
+        format!("{}\n{}", self.to_ascii_preview(width), legend.join(" "))
+    }
+
+    /// Sample `width` colors evenly across 0.0 - 1.0 as RGBA bytes
+    ///
+    /// Returns `width * 4` bytes, `[r, g, b, alpha]` per pixel, ready for
+    /// image crates and GPU texture uploads.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::ColorMap;
+    ///
+    /// let row = ColorMap::grayscale_scheme().sample_image_row(2, 255);
+    /// assert_eq!(row, vec![0, 0, 0, 255, 255, 255, 255, 255]);
+    /// ```
+    pub fn sample_image_row(&self, width: u32, alpha: u8) -> Vec<u8> {
+        self.sample_evenly(width as usize)
+            .flat_map(|c| [c.r, c.g, c.b, alpha])
+            .collect()
+    }
+
+    /// Sample `width` colors evenly across 0.0 - 1.0 as RGB bytes
+    /// (`width * 3` bytes)
+    pub fn sample_image_row_rgb(&self, width: u32) -> Vec<u8> {
+        self.sample_evenly(width as usize)
+            .flat_map(|c| [c.r, c.g, c.b])
+            .collect()
+    }
+
+    /// Render a 2D lookup texture as opaque RGBA bytes, rows top to bottom
+    ///
+    /// The x axis samples `self` and the y axis samples `other`; each pixel
+    /// is the two colors combined with [`BlendMode::Multiply`]. Returns
+    /// `width * height * 4` bytes.
+    pub fn sample_2d_gradient_rgba(&self, other: &ColorMap, width: u32, height: u32) -> Vec<u8> {
+        let columns: Vec<Color> = self.sample_evenly(width as usize).collect();
+        let mut data = Vec::with_capacity(columns.len() * height as usize * 4);
+        for row in other.sample_evenly(height as usize) {
+            for column in &columns {
+                let c = column.blend(&row, BlendMode::Multiply);
+                data.extend_from_slice(&[c.r, c.g, c.b, 255]);
+            }
+        }
+        data
+    }
+
+    /// `n` colors sampled at even intervals from 0.0 to 1.0 inclusive
+    ///
+    /// A single color samples position 0.0. Nearby samples may repeat; use
+    /// [`ColorMap::to_palette_distinct`] to drop similar colors.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, ColorMap};
+    ///
+    /// let palette = ColorMap::grayscale_scheme().to_palette(3);
+    /// assert_eq!(palette, vec![Color::BLACK, Color::new(128, 128, 128), Color::WHITE]);
+    /// ```
+    pub fn to_palette(&self, n: usize) -> Vec<Color> {
+        self.sample_evenly(n).collect()
+    }
+
+    /// [`ColorMap::to_palette`] without colors too close to an earlier one
+    ///
+    /// Samples are visited from 0.0 to 1.0 and a color is kept only when its
+    /// [`Color::distance_delta_e_76`] to every color already kept exceeds
+    /// `min_delta_e`, so the result has at most `n` colors and is always the
+    /// same for the same input.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::ColorMap;
+    ///
+    /// // A two-color gradient split 16 ways still only has a few clear steps
+    /// let swatches = ColorMap::grayscale_scheme().to_palette_distinct(16, 25.0);
+    /// assert_eq!(swatches.len(), 4);
+    /// ```
+    pub fn to_palette_distinct(&self, n: usize, min_delta_e: f64) -> Vec<Color> {
+        let mut palette: Vec<Color> = Vec::new();
+        for color in self.sample_evenly(n) {
+            if palette
+                .iter()
+                .all(|kept| kept.distance_delta_e_76(&color) > min_delta_e)
+            {
+                palette.push(color);
+            }
+        }
+        palette
+    }
+
+    /// CIE L* lightness of `samples` colors evenly spaced across 0.0 - 1.0
+    ///
+    /// Scientific colormaps should have a monotone profile so that data
+    /// order matches perceived order. See also
+    /// [`ColorMap::is_perceptually_monotone`].
+    pub fn lightness_profile(&self, samples: usize) -> Vec<f64> {
+        self.sample_evenly(samples)
+            .map(|color| color.to_lab().0)
+            .collect()
+    }
+
+    /// Check whether the lightness profile never reverses direction
+    ///
+    /// True when the sampled L* values are non-decreasing or non-increasing.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::ColorMap;
+    ///
+    /// assert!(ColorMap::grayscale_scheme().is_perceptually_monotone(64));
+    /// assert!(!ColorMap::rainbow_scheme().is_perceptually_monotone(64));
+    /// ```
+    pub fn is_perceptually_monotone(&self, samples: usize) -> bool {
+        let profile = self.lightness_profile(samples);
+        profile.windows(2).all(|w| w[0] <= w[1]) || profile.windows(2).all(|w| w[0] >= w[1])
+    }
+
+    /// Coefficient of variation of the steps between sampled L* values
+    ///
+    /// 0.0 means every step changes lightness by the same amount; higher
+    /// values are less uniform. Returns 0.0 with fewer than two samples and
+    /// infinity when lightness doesn't change overall.
+    pub fn perceptual_uniformity_score(&self, samples: usize) -> f64 {
+        let profile = self.lightness_profile(samples);
+        let steps: Vec<f64> = profile.windows(2).map(|w| w[1] - w[0]).collect();
+        if steps.is_empty() {
+            return 0.0;
+        }
+
+        let n = steps.len() as f64;
+        let mean = steps.iter().sum::<f64>() / n;
+        let variance = steps.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / n;
+        if mean.abs() < 1e-12 {
+            return f64::INFINITY;
+        }
+        variance.sqrt() / mean.abs()
+    }
+
+    /// `count` colors sampled at even intervals from 0.0 to 1.0 inclusive
+    fn sample_evenly(&self, count: usize) -> impl Iterator<Item = Color> + '_ {
+        (0..count).map(move |i| {
+            let t = if count > 1 {
+                i as f64 / (count - 1) as f64
+            } else {
+                0.0
+            };
+            self.get_color(t)
+        })
+    }
+
+    /// Mean [`Color::warmth`] of colors sampled evenly across the gradient
+    ///
+    /// Positive for warm colormaps, negative for cool ones; useful for
+    /// grouping a colormap library. An empty colormap scores 0.0.
+    pub fn average_warmth(&self) -> f64 {
+        const SAMPLES: usize = 64;
+        self.sample_evenly(SAMPLES).map(|c| c.warmth()).sum::<f64>() / SAMPLES as f64
+    }
+
+    /// Simulate the colormap as seen with a color vision deficiency
+    ///
+    /// See [`Color::simulate_cvd`]; every stop color is transformed.
+    pub fn simulate_cvd(&self, kind: ColorVisionDeficiency, severity: f64) -> Self {
+        self.map_colors(|color| color.simulate_cvd(kind, severity))
+    }
+
+    /// Simulate the colormap under full dichromacy, e.g. to check whether a
+    /// scheme stays readable; same as `simulate_cvd(cbtype, 1.0)`
+    pub fn simulate_colorblindness(&self, cbtype: ColorBlindType) -> Self {
+        self.simulate_cvd(cbtype, 1.0)
+    }
+
+    /// Default HSV-based color scheme (smooth rainbow)
+    pub fn default_scheme() -> Self {
+        Self::with_stops(
+            "Default",
+            vec![
+                ColorStop::new(0.0, Color::black()),
+                ColorStop::new(0.2, Color::from_hsv(240.0, 1.0, 1.0)), // Blue
+                ColorStop::new(0.5, Color::from_hsv(120.0, 1.0, 1.0)), // Green
+                ColorStop::new(0.8, Color::from_hsv(0.0, 1.0, 1.0)),   // Red
+                ColorStop::new(1.0, Color::white()),
+            ],
+        )
+    }
+
+    /// Fire color scheme (black -> red -> orange -> yellow -> white)
+    pub fn fire_scheme() -> Self {
+        Self::with_stops(
+            "Fire",
+            vec![
+                ColorStop::new(0.0, Color::black()),
+                ColorStop::new(0.25, Color::new(128, 0, 0)), // Dark red
+                ColorStop::new(0.5, Color::new(255, 0, 0)),  // Red
+                ColorStop::new(0.75, Color::new(255, 128, 0)), // Orange
+                ColorStop::new(0.9, Color::new(255, 255, 0)), // Yellow
+                ColorStop::new(1.0, Color::white()),
+            ],
+        )
+    }
+
+    /// Ocean color scheme (black -> deep blue -> cyan -> white)
+    pub fn ocean_scheme() -> Self {
+        Self::with_stops(
+            "Ocean",
+            vec![
+                ColorStop::new(0.0, Color::black()),
+                ColorStop::new(0.3, Color::new(0, 0, 128)), // Deep blue
+                ColorStop::new(0.6, Color::new(0, 128, 255)), // Sky blue
+                ColorStop::new(0.85, Color::new(0, 255, 255)), // Cyan
+                ColorStop::new(1.0, Color::white()),
+            ],
+        )
+    }
+
+    /// Grayscale color scheme (black -> gray -> white)
+    pub fn grayscale_scheme() -> Self {
+        Self::with_stops(
+            "Grayscale",
+            vec![
+                ColorStop::new(0.0, Color::black()),
+                ColorStop::new(0.5, Color::new(128, 128, 128)),
                 ColorStop::new(1.0, Color::white()),
             ],
-        )
+        )
+    }
+
+    /// Rainbow color scheme (full spectrum)
+    pub fn rainbow_scheme() -> Self {
+        Self::with_stops(
+            "Rainbow",
+            vec![
+                ColorStop::new(0.0, Color::from_hsv(0.0, 1.0, 1.0)), // Red
+                ColorStop::new(0.17, Color::from_hsv(60.0, 1.0, 1.0)), // Yellow
+                ColorStop::new(0.33, Color::from_hsv(120.0, 1.0, 1.0)), // Green
+                ColorStop::new(0.5, Color::from_hsv(180.0, 1.0, 1.0)), // Cyan
+                ColorStop::new(0.67, Color::from_hsv(240.0, 1.0, 1.0)), // Blue
+                ColorStop::new(0.83, Color::from_hsv(300.0, 1.0, 1.0)), // Magenta
+                ColorStop::new(1.0, Color::from_hsv(360.0, 1.0, 1.0)), // Red
+            ],
+        )
+    }
+
+    /// Viridis color scheme from Matplotlib (dark purple -> blue -> green -> yellow)
+    pub fn viridis_scheme() -> Self {
+        Self::with_stops(
+            "Viridis",
+            vec![
+                ColorStop::new(0.0, Color::new(68, 1, 84)),
+                ColorStop::new(0.1, Color::new(72, 37, 118)),
+                ColorStop::new(0.2, Color::new(65, 68, 135)),
+                ColorStop::new(0.3, Color::new(53, 96, 141)),
+                ColorStop::new(0.4, Color::new(42, 120, 142)),
+                ColorStop::new(0.5, Color::new(33, 144, 140)),
+                ColorStop::new(0.6, Color::new(34, 168, 132)),
+                ColorStop::new(0.7, Color::new(67, 191, 113)),
+                ColorStop::new(0.8, Color::new(122, 209, 81)),
+                ColorStop::new(0.9, Color::new(187, 223, 39)),
+                ColorStop::new(1.0, Color::new(253, 231, 37)),
+            ],
+        )
+    }
+
+    /// Plasma color scheme from Matplotlib (deep blue -> magenta -> orange -> yellow)
+    pub fn plasma_scheme() -> Self {
+        Self::with_stops(
+            "Plasma",
+            vec![
+                ColorStop::new(0.0, Color::new(13, 8, 135)),
+                ColorStop::new(0.1, Color::new(65, 4, 157)),
+                ColorStop::new(0.2, Color::new(106, 0, 168)),
+                ColorStop::new(0.3, Color::new(143, 13, 164)),
+                ColorStop::new(0.4, Color::new(177, 42, 144)),
+                ColorStop::new(0.5, Color::new(204, 70, 120)),
+                ColorStop::new(0.6, Color::new(225, 100, 98)),
+                ColorStop::new(0.7, Color::new(242, 132, 75)),
+                ColorStop::new(0.8, Color::new(252, 166, 54)),
+                ColorStop::new(0.9, Color::new(252, 206, 37)),
+                ColorStop::new(1.0, Color::new(240, 249, 33)),
+            ],
+        )
+    }
+
+    /// Magma color scheme from Matplotlib (black -> purple -> coral -> pale yellow)
+    pub fn magma_scheme() -> Self {
+        Self::with_stops(
+            "Magma",
+            vec![
+                ColorStop::new(0.0, Color::new(0, 0, 4)),
+                ColorStop::new(0.1, Color::new(20, 14, 54)),
+                ColorStop::new(0.2, Color::new(59, 15, 112)),
+                ColorStop::new(0.3, Color::new(100, 26, 128)),
+                ColorStop::new(0.4, Color::new(140, 41, 129)),
+                ColorStop::new(0.5, Color::new(183, 55, 121)),
+                ColorStop::new(0.6, Color::new(222, 73, 104)),
+                ColorStop::new(0.7, Color::new(247, 112, 92)),
+                ColorStop::new(0.8, Color::new(254, 159, 109)),
+                ColorStop::new(0.9, Color::new(254, 207, 146)),
+                ColorStop::new(1.0, Color::new(252, 253, 191)),
+            ],
+        )
+    }
+
+    /// Inferno color scheme from Matplotlib (black -> purple -> orange -> pale yellow)
+    pub fn inferno_scheme() -> Self {
+        Self::with_stops(
+            "Inferno",
+            vec![
+                ColorStop::new(0.0, Color::new(0, 0, 4)),
+                ColorStop::new(0.1, Color::new(22, 11, 57)),
+                ColorStop::new(0.2, Color::new(66, 10, 104)),
+                ColorStop::new(0.3, Color::new(106, 23, 110)),
+                ColorStop::new(0.4, Color::new(147, 38, 103)),
+                ColorStop::new(0.5, Color::new(188, 55, 84)),
+                ColorStop::new(0.6, Color::new(221, 81, 58)),
+                ColorStop::new(0.7, Color::new(243, 120, 25)),
+                ColorStop::new(0.8, Color::new(252, 165, 10)),
+                ColorStop::new(0.9, Color::new(246, 215, 70)),
+                ColorStop::new(1.0, Color::new(252, 255, 164)),
+            ],
+        )
+    }
+
+    /// Generate a sequential colormap by interpolating in Oklab
+    ///
+    /// Oklab is perceptually uniform, so equal steps in position give equal
+    /// perceived steps in color and lightness changes monotonically between
+    /// the endpoints. Produces `n_stops` evenly spaced stops (at least 2).
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, ColorMap};
+    ///
+    /// let map = ColorMap::generate_sequential("Deep Sea", Color::new(10, 20, 60), Color::new(220, 250, 200), 9);
+    /// assert_eq!(map.stops.len(), 9);
+    /// assert!(map.is_perceptually_monotone(100));
+    /// ```
+    pub fn generate_sequential(
+        name: impl Into<String>,
+        start_color: Color,
+        end_color: Color,
+        n_stops: usize,
+    ) -> Self {
+        let n_stops = n_stops.max(2);
+        let start = rgb_to_oklab(&start_color);
+        let end = rgb_to_oklab(&end_color);
+
+        let stops = (0..n_stops)
+            .map(|i| {
+                let t = i as f64 / (n_stops - 1) as f64;
+                ColorStop::new(t, oklab_lerp(start, end, t))
+            })
+            .collect();
+        Self::with_stops(name, stops)
+    }
+
+    /// Generate a diverging colormap through `mid`, interpolating in Oklab
+    ///
+    /// The two halves run `low` to `mid` and `mid` to `high`. Pick a
+    /// low-chroma `mid` (a near-neutral gray or off-white) so the center
+    /// reads as "no deviation". `n_stops` is raised to the next odd number
+    /// (at least 3) so that `mid` sits exactly at 0.5.
+    pub fn generate_diverging(
+        name: impl Into<String>,
+        low: Color,
+        mid: Color,
+        high: Color,
+        n_stops: usize,
+    ) -> Self {
+        let n_stops = n_stops.max(3) | 1;
+        let half = n_stops / 2;
+        let (low, mid, high) = (rgb_to_oklab(&low), rgb_to_oklab(&mid), rgb_to_oklab(&high));
+
+        let stops = (0..n_stops)
+            .map(|i| {
+                let position = i as f64 / (n_stops - 1) as f64;
+                let color = if i <= half {
+                    oklab_lerp(low, mid, i as f64 / half as f64)
+                } else {
+                    oklab_lerp(mid, high, (i - half) as f64 / half as f64)
+                };
+                ColorStop::new(position, color)
+            })
+            .collect();
+        Self::with_stops(name, stops)
+    }
+
+    /// Generate a blackbody gradient from `start_k` to `end_k` Kelvin
+    ///
+    /// Stops are evenly spaced in temperature and colored with
+    /// [`Color::from_temperature`], so the valid range is 1000 K - 12000 K.
+    /// Produces `n_stops` stops (at least 2).
+    pub fn temperature_gradient(start_k: f64, end_k: f64, n_stops: usize) -> Self {
+        let n_stops = n_stops.max(2);
+        let stops = (0..n_stops)
+            .map(|i| {
+                let t = i as f64 / (n_stops - 1) as f64;
+                ColorStop::new(t, Color::from_temperature(start_k + (end_k - start_k) * t))
+            })
+            .collect();
+        Self::with_stops(format!("Blackbody {:.0}K-{:.0}K", start_k, end_k), stops)
+    }
+
+    /// Two-stop gradient from `color` to its
+    /// [complementary color](Color::complementary) (HSV hue + 180°)
+    ///
+    /// Named after the color, e.g. `"#FF0000 Complementary"`. This is a
+    /// constructor like [`ColorMap::temperature_gradient`] rather than a
+    /// method, since a gradient has no single color to start from.
+    pub fn complementary_gradient(color: Color) -> Self {
+        Self::equidistant(
+            format!("{} Complementary", color.to_hex()),
+            vec![color, color.complementary()],
+        )
+    }
+
+    /// Generate the visible spectrum from 380 nm to 780 nm
+    ///
+    /// Stops are evenly spaced in wavelength and colored with
+    /// [`Color::from_wavelength`], so both ends fade to black. Produces
+    /// `n_stops` stops (at least 2).
+    pub fn spectrum(n_stops: usize) -> Self {
+        let n_stops = n_stops.max(2);
+        let stops = (0..n_stops)
+            .map(|i| {
+                let t = i as f64 / (n_stops - 1) as f64;
+                ColorStop::new(t, Color::from_wavelength(380.0 + 400.0 * t))
+            })
+            .collect();
+        Self::with_stops("Spectrum", stops)
+    }
+}
+
+/// Accept only finite stop positions within 0.0 - 1.0
+fn check_position(position: f64) -> Result<f64> {
+    if position.is_finite() && (0.0..=1.0).contains(&position) {
+        Ok(position)
+    } else {
+        Err(ColorMapError::InvalidPosition(position))
+    }
+}
+
+/// Deserialize a stop position, rejecting values [`check_position`] refuses
+fn deserialize_position<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<f64, D::Error> {
+    check_position(f64::deserialize(deserializer)?).map_err(D::Error::custom)
+}
+
+/// Interpolate two Oklab colors and convert the result back to sRGB
+fn oklab_lerp(from: (f64, f64, f64), to: (f64, f64, f64), t: f64) -> Color {
+    oklab_to_rgb(
+        from.0 + (to.0 - from.0) * t,
+        from.1 + (to.1 - from.1) * t,
+        from.2 + (to.2 - from.2) * t,
+    )
+}
+
+/// Fluent builder for [`ColorMap`]
+///
+/// # Examples
+/// ```
+/// use scala_chromatica::{Color, ColorMap, InterpolationMode};
+///
+/// let map = ColorMap::builder()
+///     .name("Sunrise")
+///     .stop(0.0, Color::new(40, 0, 80))
+///     .named_stop(0.5, Color::new(255, 100, 0), "Orange")
+///     .stop(1.0, Color::new(255, 230, 150))
+///     .interpolation(InterpolationMode::Hsv)
+///     .build()
+///     .unwrap();
+/// assert_eq!(map.stops.len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ColorMapBuilder {
+    name: String,
+    stops: Vec<ColorStop>,
+    interpolation: InterpolationMode,
+    metadata: ColorMapMetadata,
+}
+
+impl ColorMapBuilder {
+    /// Create an empty builder for a colormap named "Unnamed"
+    pub fn new() -> Self {
+        Self {
+            name: "Unnamed".to_string(),
+            stops: Vec::new(),
+            interpolation: InterpolationMode::default(),
+            metadata: ColorMapMetadata::default(),
+        }
+    }
+
+    /// Set the colormap name
+    pub fn name(mut self, n: impl Into<String>) -> Self {
+        self.name = n.into();
+        self
+    }
+
+    /// Add a color stop
+    pub fn stop(mut self, position: f64, color: Color) -> Self {
+        self.stops.push(ColorStop::new(position, color));
+        self
+    }
+
+    /// Add a named color stop
+    pub fn named_stop(mut self, position: f64, color: Color, name: impl Into<String>) -> Self {
+        self.stops.push(ColorStop::with_name(position, color, name));
+        self
+    }
+
+    /// Set the interpolation mode
+    pub fn interpolation(mut self, mode: InterpolationMode) -> Self {
+        self.interpolation = mode;
+        self
+    }
+
+    /// Set the colormap metadata
+    pub fn metadata(mut self, meta: ColorMapMetadata) -> Self {
+        self.metadata = meta;
+        self
+    }
+
+    /// Build the colormap, failing if no stops were added
+    ///
+    /// Stops repeating a position become hard edges as in
+    /// [`ColorMap::add_stop`].
+    pub fn build(self) -> Result<ColorMap> {
+        if self.stops.is_empty() {
+            return Err(ColorMapError::InvalidStops(
+                "colormap has no stops".to_string(),
+            ));
+        }
+
+        let mut colormap = ColorMap::with_stops(self.name, self.stops).with_metadata(self.metadata);
+        colormap.interpolation = self.interpolation;
+        colormap.separate_hard_edges();
+        colormap.validate()?;
+        Ok(colormap)
+    }
+}
+
+impl Default for ColorMapBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A colormap with a linear opacity ramp across the gradient
+///
+/// Created with [`ColorMap::with_alpha_ramp`]. Colors come from the wrapped
+/// map unchanged; the ramp is multiplied with any per-stop
+/// [`ColorStop::alpha`], so a map without stop alphas gets exactly the ramp.
+/// Useful for overlays, masks and vignettes.
+///
+/// # Examples
+/// ```
+/// use scala_chromatica::{Color, ColorMap};
+///
+/// let overlay = ColorMap::fire_scheme().fade_out();
+/// assert_eq!(overlay.get_color_a(0.0), (Color::BLACK, 255));
+/// assert_eq!(overlay.get_color_a(1.0), (Color::WHITE, 0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ColorMapA {
+    /// The colormap supplying colors (and per-stop alpha)
+    pub colormap: ColorMap,
+    /// Opacity of the ramp at position 0.0
+    pub alpha_at_zero: u8,
+    /// Opacity of the ramp at position 1.0
+    pub alpha_at_one: u8,
+}
+
+impl ColorMapA {
+    /// Pair `colormap` with an opacity ramp
+    pub fn new(colormap: ColorMap, alpha_at_zero: u8, alpha_at_one: u8) -> Self {
+        Self {
+            colormap,
+            alpha_at_zero,
+            alpha_at_one,
+        }
+    }
+
+    /// Get the color and opacity at a position (clamped to 0.0 - 1.0)
+    pub fn get_color_a(&self, position: f64) -> (Color, u8) {
+        let rgba = self.get_rgba(position);
+        (rgba.color(), rgba.a)
+    }
+
+    /// [`ColorMapA::get_color_a`] as an [`Rgba`]
+    pub fn get_rgba(&self, position: f64) -> Rgba {
+        let t = position.clamp(0.0, 1.0);
+        let (start, end) = (self.alpha_at_zero as f64, self.alpha_at_one as f64);
+        let ramp = start + (end - start) * t;
+
+        let mut rgba = self.colormap.get_rgba(t);
+        rgba.a = (ramp * rgba.a as f64 / 255.0).round() as u8;
+        rgba
+    }
+}
+
+/// Formats as `position:#RRGGBB`, followed by `:name` for named stops
+///
+/// Only the position, color and name are written; parsing with [`str::parse`]
+/// reads the same form back.
+impl std::fmt::Display for ColorStop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.position, self.color.to_hex())?;
+        if let Some(name) = &self.name {
+            write!(f, ":{}", name)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for ColorStop {
+    type Err = ColorMapError;
+
+    /// Parses `position:color` or `position:color:name`
+    ///
+    /// The color is any hex form [`Color::from_hex`] accepts and the
+    /// position must be a number in 0.0 - 1.0. Everything after the second
+    /// `:` is the name, so names may contain colons.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::{Color, ColorStop};
+    ///
+    /// let stop: ColorStop = "0.25:#FF8800:Orange".parse().unwrap();
+    /// assert_eq!(stop, ColorStop::with_name(0.25, Color::new(255, 136, 0), "Orange"));
+    /// assert_eq!(stop.to_string(), "0.25:#FF8800:Orange");
+    ///
+    /// assert!("abc:#fff".parse::<ColorStop>().is_err());
+    /// assert!("0.5:".parse::<ColorStop>().is_err());
+    /// ```
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let malformed = |reason: &str| {
+            ColorMapError::InvalidStops(format!(
+                "{} in '{}' (expected 'position:#RRGGBB' or 'position:#RRGGBB:name')",
+                reason, s
+            ))
+        };
+
+        let mut parts = s.trim().splitn(3, ':');
+        let position = parts.next().unwrap_or_default().trim();
+        let color = parts
+            .next()
+            .map(str::trim)
+            .ok_or_else(|| malformed("missing ':' separator"))?;
+        if color.is_empty() {
+            return Err(malformed("missing color"));
+        }
+
+        let position: f64 = position
+            .parse()
+            .map_err(|_| malformed(&format!("invalid position '{}'", position)))?;
+        let mut stop = ColorStop::try_new(position, Color::from_hex(color)?)?;
+        stop.name = parts
+            .next()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string);
+        Ok(stop)
+    }
+}
+
+impl std::fmt::Display for ColorMap {
+    /// Formats the colormap as pretty-printed JSON
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let json = crate::io::save_colormap_to_string(self).map_err(|_| std::fmt::Error)?;
+        f.write_str(&json)
+    }
+}
+
+impl From<Vec<Color>> for ColorMap {
+    /// Spreads the colors evenly across 0.0 - 1.0 in a map named "Unnamed"
+    fn from(colors: Vec<Color>) -> Self {
+        ColorMap::equidistant("Unnamed", colors)
+    }
+}
+
+impl<'a> IntoIterator for &'a ColorMap {
+    type Item = &'a ColorStop;
+    type IntoIter = std::slice::Iter<'a, ColorStop>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.stops.iter()
+    }
+}
+
+impl IntoIterator for ColorMap {
+    type Item = ColorStop;
+    type IntoIter = std::vec::IntoIter<ColorStop>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.stops.into_iter()
+    }
+}
+
+impl std::str::FromStr for ColorMap {
+    type Err = ColorMapError;
+
+    /// Parses a colormap from its JSON representation
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        crate::io::load_colormap_from_str(s)
+    }
+}
+
+/// Convert iteration count to color using a colormap
+///
+/// This is a utility function for fractal rendering and similar applications
+/// where you need to map iteration counts to colors.
+///
+/// # Arguments
+/// * `iterations` - Number of iterations performed
+/// * `max_iterations` - Maximum iterations allowed
+/// * `colormap` - The colormap to use for coloring
+/// * `use_period` - Enable periodic color cycling
+/// * `period` - Period for color cycling (if enabled)
+/// * `use_interior_color` - Use custom color for interior points
+/// * `interior_color` - RGB color for interior points
+/// * `use_log_scale` - Apply logarithmic scaling to colors
+#[allow(clippy::too_many_arguments)]
+pub fn color_from_iterations(
+    iterations: u32,
+    max_iterations: u32,
+    colormap: &ColorMap,
+    use_period: bool,
+    period: u32,
+    use_interior_color: bool,
+    interior_color: [u8; 3],
+    use_log_scale: bool,
+) -> Color {
+    // Check if point is inside the set and custom interior color is enabled
+    if iterations >= max_iterations && use_interior_color {
+        return Color {
+            r: interior_color[0],
+            g: interior_color[1],
+            b: interior_color[2],
+        };
+    }
+
+    escape_count_color(
+        iterations as f64,
+        max_iterations,
+        colormap,
+        use_period,
+        period,
+        use_log_scale,
+    )
+}
+
+/// Convert an iteration count to color using continuous (smooth) escape-time coloring
+///
+/// Same as [`color_from_iterations`], but the integer iteration count is
+/// replaced by the fractional count `n + 1 - log2(log2(|z|))`, which removes
+/// the color banding between iteration levels. Pass the magnitude of the
+/// final iterate as `zn_abs`; when it is not greater than 1 (or not finite)
+/// the plain iteration count is used.
+///
+/// # Arguments
+/// * `iterations` - Number of iterations performed
+/// * `max_iterations` - Maximum iterations allowed
+/// * `zn_abs` - Magnitude of the final iterate `|z_n|`
+/// * `colormap` - The colormap to use for coloring
+/// * `use_period` - Enable periodic color cycling
+/// * `period` - Period for color cycling (if enabled)
+/// * `use_interior_color` - Use custom color for interior points
+/// * `interior_color` - RGB color for interior points
+/// * `use_log_scale` - Apply logarithmic scaling to colors
+#[allow(clippy::too_many_arguments)]
+pub fn color_from_iterations_smooth(
+    iterations: u32,
+    max_iterations: u32,
+    zn_abs: f64,
+    colormap: &ColorMap,
+    use_period: bool,
+    period: u32,
+    use_interior_color: bool,
+    interior_color: [u8; 3],
+    use_log_scale: bool,
+) -> Color {
+    // Check if point is inside the set and custom interior color is enabled
+    if iterations >= max_iterations && use_interior_color {
+        return Color {
+            r: interior_color[0],
+            g: interior_color[1],
+            b: interior_color[2],
+        };
+    }
+
+    escape_count_color(
+        smooth_iteration_count(iterations, max_iterations, zn_abs),
+        max_iterations,
+        colormap,
+        use_period,
+        period,
+        use_log_scale,
+    )
+}
+
+/// Fractional escape count `n + 1 - log2(log2(|z|))`, clamped to `[0, max_iterations]`
+fn smooth_iteration_count(iterations: u32, max_iterations: u32, zn_abs: f64) -> f64 {
+    let count = if zn_abs > 1.0 && zn_abs.is_finite() {
+        iterations as f64 + 1.0 - (zn_abs.ln() / 2f64.ln()).ln() / 2f64.ln()
+    } else {
+        iterations as f64
+    };
+
+    count.clamp(0.0, max_iterations as f64)
+}
+
+/// Period, normalization and log-scale logic shared by the escape-time colorings
+fn escape_count_color(
+    count: f64,
+    max_iterations: u32,
+    colormap: &ColorMap,
+    use_period: bool,
+    period: u32,
+    use_log_scale: bool,
+) -> Color {
+    // Apply period modulation if enabled
+    let effective_count = if use_period && period > 0 {
+        count % period as f64
+    } else {
+        count
+    };
+
+    // Normalize iterations to 0.0-1.0 range
+    let divisor = if use_period && period > 0 {
+        period as f64
+    } else {
+        max_iterations as f64
+    };
+    let t = effective_count / divisor;
+
+    // Apply smooth coloring - use log scale if enabled, otherwise linear
+    let smooth_t = if use_log_scale {
+        (t * 10.0).log10() / 1.0 // log10(10) = 1
+    } else {
+        t // Linear scaling
+    };
+
+    colormap.get_color(smooth_t.clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_color_approx_eq;
+
+    #[test]
+    fn test_colorstop_creation() {
+        let stop = ColorStop::new(0.5, Color::new(255, 0, 0));
+        assert_eq!(stop.position, 0.5);
+        assert_eq!(stop.color.r, 255);
+        assert!(stop.name.is_none());
+
+        let named_stop = ColorStop::with_name(0.3, Color::new(0, 255, 0), "Green");
+        assert_eq!(named_stop.name, Some("Green".to_string()));
+    }
+
+    #[test]
+    fn test_hex_stops() {
+        let stop = ColorStop::from_hex(0.25, "f0a").unwrap();
+        assert_eq!(stop, ColorStop::new(0.25, Color::new(255, 0, 170)));
+        let named = ColorStop::named_hex(0.75, "#00FF00", "Green").unwrap();
+        assert_eq!(named, ColorStop::with_name(0.75, Color::GREEN, "Green"));
+        assert!(matches!(
+            ColorStop::from_hex(0.0, "#12"),
+            Err(ColorMapError::InvalidHexColor(_))
+        ));
+
+        let mut map = ColorMap::new("Hex");
+        map.add_hex_stop(1.0, "#FFFFFF").unwrap();
+        map.add_hex_stop(0.0, "#000000").unwrap();
+        assert!(map.add_hex_stop(0.5, "#XYZ").is_err());
+        assert_eq!(map.stops.len(), 2);
+        assert_eq!(map.stops[0].color, Color::BLACK);
+    }
+
+    #[test]
+    fn test_try_new_rejects_bad_positions() {
+        assert_eq!(
+            ColorStop::try_new(0.25, Color::RED).unwrap(),
+            ColorStop::new(0.25, Color::RED)
+        );
+        assert!(ColorStop::try_new(0.0, Color::RED).is_ok());
+        assert!(ColorStop::try_new(1.0, Color::RED).is_ok());
+        for bad in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, -0.1, 1.5] {
+            match ColorStop::try_new(bad, Color::RED) {
+                Err(ColorMapError::InvalidPosition(p)) => {
+                    assert!(p.to_bits() == bad.to_bits())
+                }
+                other => panic!("expected InvalidPosition for {}, got {:?}", bad, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_nan_stop_does_not_panic() {
+        // ColorStop::new passes NaN through clamp, which used to panic the sort
+        let mut map = ColorMap::new("NaN");
+        map.add_stop(ColorStop::new(1.0, Color::WHITE));
+        map.add_stop(ColorStop::new(f64::NAN, Color::RED));
+        map.add_stop(ColorStop::new(0.0, Color::BLACK));
+        assert_eq!(map.stops[0].color, Color::BLACK);
+        assert_eq!(map.stops[1].color, Color::WHITE);
+        assert!(map.stops[2].position.is_nan());
+        assert!(map.validate().is_err());
+
+        // Raw struct construction takes the same path
+        let mut raw = two_stop("Raw", Color::BLACK, Color::WHITE);
+        raw.stops.insert(
+            0,
+            ColorStop {
+                position: f64::NAN,
+                ..ColorStop::new(0.0, Color::RED)
+            },
+        );
+        raw.add_stop(ColorStop::new(0.5, Color::BLUE));
+        assert_eq!(raw.stops[1].color, Color::BLUE);
+    }
+
+    #[test]
+    fn test_deserialize_rejects_bad_positions() {
+        let parse = |position: &str| {
+            serde_json::from_str::<ColorStop>(&format!(
+                r#"{{"position": {}, "color": {{"r": 0, "g": 0, "b": 0}}}}"#,
+                position
+            ))
+        };
+        assert_eq!(parse("0.5").unwrap().position, 0.5);
+        for bad in ["1.5", "-0.25"] {
+            let err = parse(bad).unwrap_err().to_string();
+            assert!(err.contains("Invalid stop position"), "{}", err);
+        }
+        // JSON has no NaN; an overflowing literal is the nearest equivalent
+        assert!(parse("1e400").is_err());
+    }
+
+    #[test]
+    fn test_colormap_gradient() {
+        let mut map = ColorMap::new("Test");
+        map.add_stop(ColorStop::new(0.0, Color::new(0, 0, 0)));
+        map.add_stop(ColorStop::new(1.0, Color::new(255, 255, 255)));
+
+        let start = map.get_color(0.0);
+        assert_eq!(start.r, 0);
+
+        let end = map.get_color(1.0);
+        assert_eq!(end.r, 255);
+
+        let mid = map.get_color(0.5);
+        assert_color_approx_eq!(mid, Color::new(128, 128, 128), 1);
+    }
+
+    #[test]
+    fn test_scientific_schemes() {
+        // Matplotlib's exact values at 0.25 and 0.5
+        let cases = [
+            (
+                ColorMap::viridis_scheme(),
+                Color::new(59, 82, 139),
+                Color::new(33, 145, 140),
+            ),
+            (
+                ColorMap::plasma_scheme(),
+                Color::new(126, 3, 168),
+                Color::new(204, 71, 120),
+            ),
+            (
+                ColorMap::magma_scheme(),
+                Color::new(81, 18, 124),
+                Color::new(183, 55, 121),
+            ),
+            (
+                ColorMap::inferno_scheme(),
+                Color::new(87, 16, 110),
+                Color::new(188, 55, 84),
+            ),
+        ];
+        for (map, quarter, mid) in cases {
+            assert!(map.is_valid());
+            assert!(map.is_perceptually_monotone(11), "{}", map.name);
+            // Between stops only 8-bit rounding can dent the lightness ramp
+            let profile = map.lightness_profile(256);
+            assert!(
+                profile.windows(2).all(|w| w[1] > w[0] - 0.5),
+                "{}",
+                map.name
+            );
+            assert_color_approx_eq!(map.get_color(0.5), mid, 2, "{}", map.name);
+            assert_color_approx_eq!(map.get_color(0.25), quarter, 5, "{}", map.name);
+
+            let builtin = crate::io::load_builtin_colormap(&map.name).unwrap();
+            assert_eq!(builtin.stops, map.stops);
+        }
+    }
+
+    #[test]
+    fn test_temperature_gradient() {
+        let map = ColorMap::temperature_gradient(1500.0, 9000.0, 6);
+        assert_eq!(map.name, "Blackbody 1500K-9000K");
+        assert_eq!(map.stops.len(), 6);
+        assert_eq!(map.stops[0].color, Color::from_temperature(1500.0));
+        assert_eq!(map.stops[1].color, Color::from_temperature(3000.0));
+        assert_eq!(map.stops[5].color, Color::from_temperature(9000.0));
+        assert!(map.is_valid());
+        assert!(map.stops.windows(2).all(|w| w[0].color.b <= w[1].color.b));
+
+        assert_eq!(
+            ColorMap::temperature_gradient(2000.0, 2000.0, 1)
+                .stops
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_equidistant() {
+        let colors = vec![
+            Color::RED,
+            Color::GREEN,
+            Color::BLUE,
+            Color::WHITE,
+            Color::BLACK,
+        ];
+        let map = ColorMap::equidistant("Five", colors.clone());
+        assert_eq!(map.name, "Five");
+        let positions: Vec<f64> = map.stops.iter().map(|s| s.position).collect();
+        assert_eq!(positions, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert!(map.stops.iter().map(|s| s.color).eq(colors.iter().copied()));
+
+        assert!(ColorMap::equidistant("Empty", Vec::new()).stops.is_empty());
+        let single = ColorMap::equidistant("One", vec![Color::RED]);
+        assert_eq!(single.stops.len(), 1);
+        assert_eq!(single.stops[0].position, 0.0);
+
+        let ranged = ColorMap::equidistant_range("Range", colors, 0.2, 0.6);
+        assert_eq!(ranged.stops[0].position, 0.2);
+        assert!((ranged.stops[2].position - 0.4).abs() < 1e-12);
+        assert_eq!(ranged.stops[4].position, 0.6);
+        assert_eq!(
+            ColorMap::equidistant_range("One", vec![Color::RED], 0.3, 0.9).stops[0].position,
+            0.3
+        );
+
+        let from: ColorMap = vec![Color::BLACK, Color::WHITE].into();
+        assert_eq!(from.name, "Unnamed");
+        assert_eq!(from.stops[1].position, 1.0);
+    }
+
+    #[test]
+    fn test_complementary_gradient() {
+        let map = ColorMap::complementary_gradient(Color::new(255, 128, 0));
+        assert_eq!(map.name, "#FF8000 Complementary");
+        assert_eq!(map.stops.len(), 2);
+        assert!(map.is_valid());
+        assert_eq!(map.get_color(0.0), Color::new(255, 128, 0));
+        assert_eq!(map.get_color(1.0), Color::new(0, 127, 255));
+    }
+
+    #[test]
+    fn test_spectrum() {
+        let map = ColorMap::spectrum(41);
+        assert_eq!(map.name, "Spectrum");
+        assert_eq!(map.stops.len(), 41);
+        assert!(map.is_valid());
+        assert_eq!(map.stops[0].color, Color::BLACK);
+        assert_eq!(map.stops[40].color, Color::BLACK);
+        assert_eq!(map.stops[17].color, Color::from_wavelength(550.0));
+        assert_eq!(ColorMap::spectrum(0).stops.len(), 2);
+    }
+
+    #[test]
+    fn test_generate_sequential() {
+        let start = Color::new(20, 10, 70);
+        let end = Color::new(250, 230, 40);
+        let map = ColorMap::generate_sequential("Plasma-ish", start, end, 12);
+        assert_eq!(map.name, "Plasma-ish");
+        assert_eq!(map.stops.len(), 12);
+        assert!(map.is_valid());
+        assert_eq!(map.stops[0].color, start);
+        assert_eq!(map.stops[11].color, end);
+        assert!(map.is_perceptually_monotone(200));
+
+        // Oklab steps are far more even than the same ramp in sRGB
+        let mut rgb = ColorMap::new("RGB");
+        rgb.add_stop(ColorStop::new(0.0, start));
+        rgb.add_stop(ColorStop::new(1.0, end));
+        assert!(map.perceptual_uniformity_score(100) < rgb.perceptual_uniformity_score(100));
+
+        let tiny = ColorMap::generate_sequential("Tiny", start, end, 0);
+        assert_eq!(tiny.stops.len(), 2);
+        assert_eq!(tiny.stops[1].position, 1.0);
+    }
+
+    #[test]
+    fn test_generate_diverging() {
+        let low = Color::new(40, 70, 160);
+        let mid = Color::new(240, 240, 235);
+        let high = Color::new(170, 30, 40);
+        let map = ColorMap::generate_diverging("Diverging", low, mid, high, 10);
+        assert_eq!(map.stops.len(), 11);
+        assert!(map.is_valid());
+        assert_eq!(map.stops[0].color, low);
+        assert_eq!(map.stops[5].position, 0.5);
+        assert_eq!(map.stops[5].color, mid);
+        assert_eq!(map.stops[10].color, high);
+
+        // Lightness peaks in the middle
+        let profile = map.lightness_profile(11);
+        assert!(profile[..6].windows(2).all(|w| w[0] < w[1]));
+        assert!(profile[5..].windows(2).all(|w| w[0] > w[1]));
+
+        assert_eq!(
+            ColorMap::generate_diverging("Min", low, mid, high, 1)
+                .stops
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_builtin_schemes() {
+        let default = ColorMap::default_scheme();
+        assert_eq!(default.name, "Default");
+        assert!(!default.stops.is_empty());
+
+        let fire = ColorMap::fire_scheme();
+        assert_eq!(fire.name, "Fire");
+
+        let ocean = ColorMap::ocean_scheme();
+        assert_eq!(ocean.name, "Ocean");
+
+        let grayscale = ColorMap::grayscale_scheme();
+        assert_eq!(grayscale.name, "Grayscale");
+
+        let rainbow = ColorMap::rainbow_scheme();
+        assert_eq!(rainbow.name, "Rainbow");
+    }
+
+    #[test]
+    fn test_subrange() {
+        let fire = ColorMap::fire_scheme();
+        let whole = fire.subrange(0.0, 1.0).unwrap();
+        assert_eq!(whole.name, fire.name);
+        assert_eq!(whole.stops, fire.stops);
+
+        let upper = fire.subrange(0.5, 1.0).unwrap();
+        assert!(upper.validate().is_ok());
+        assert_eq!(upper.get_color(0.0), fire.get_color(0.5));
+        for i in 0..=50 {
+            let t = i as f64 / 50.0;
+            assert_color_approx_eq!(upper.get_color(t), fire.get_color(0.5 + t * 0.5), 1);
+        }
+        // Fire has stops at 0.75 and 0.9 inside the range, plus its own 0.5 and 1.0
+        let positions: Vec<f64> = upper.stops.iter().map(|s| s.position).collect();
+        assert_eq!(positions.len(), 4);
+        assert!((positions[1] - 0.5).abs() < 1e-12 && (positions[2] - 0.8).abs() < 1e-12);
+
+        // Cut curves are resampled rather than squeezed into each half
+        let eased = ColorMap::with_stops(
+            "Eased",
+            vec![
+                ColorStop::new(0.0, Color::BLACK).with_easing(Easing::EaseIn),
+                ColorStop::new(1.0, Color::WHITE),
+            ],
+        );
+        let skewed = ColorMap::with_stops(
+            "Skewed",
+            vec![
+                ColorStop::new(0.0, Color::BLACK).with_midpoint(0.2),
+                ColorStop::new(1.0, Color::WHITE),
+            ],
+        );
+        for map in [&eased, &skewed] {
+            for (start, end) in [(0.5, 1.0), (0.1, 0.6), (0.3, 0.35)] {
+                let part = map.subrange(start, end).unwrap();
+                assert!(part.validate().is_ok());
+                for i in 0..=50 {
+                    let t = i as f64 / 50.0;
+                    let original = map.get_color(start + t * (end - start));
+                    assert_color_approx_eq!(part.get_color(t), original, 1);
+                }
+            }
+        }
+        assert_eq!(
+            eased.subrange(0.5, 1.0).unwrap().get_color(0.5),
+            eased.get_color(0.75)
+        );
+        assert_eq!(
+            skewed.subrange(0.5, 1.0).unwrap().get_color(0.5),
+            skewed.get_color(0.75)
+        );
+
+        // A step keeps holding its color up to the end of the segment
+        let step = ColorMap::with_stops(
+            "Step",
+            vec![
+                ColorStop::new(0.0, Color::BLACK).with_easing(Easing::Step),
+                ColorStop::new(1.0, Color::WHITE),
+            ],
+        );
+        let upper = step.subrange(0.5, 1.0).unwrap();
+        assert_eq!(upper.stops.len(), 2);
+        assert_eq!(upper.get_color(0.99), Color::BLACK);
+        assert_eq!(upper.get_color(1.0), Color::WHITE);
+
+        // Boundaries between stops are synthesized from the gradient
+        let middle = fire.subrange(0.1, 0.6).unwrap();
+        assert_eq!(middle.stops[0].color, fire.get_color(0.1));
+        assert_eq!(middle.stops.last().unwrap().color, fire.get_color(0.6));
+        assert!(middle.stops[0].name.is_none());
+
+        assert!(matches!(
+            fire.subrange(0.6, 0.2),
+            Err(ColorMapError::InvalidStops(_))
+        ));
+        assert!(matches!(
+            fire.subrange(0.4, 0.4),
+            Err(ColorMapError::InvalidStops(_))
+        ));
+        assert!(matches!(
+            fire.subrange(f64::NAN, 0.5),
+            Err(ColorMapError::InvalidPosition(_))
+        ));
+        assert!(matches!(
+            fire.subrange(0.0, 1.5),
+            Err(ColorMapError::InvalidPosition(_))
+        ));
+        assert!(ColorMap::new("Empty")
+            .subrange(0.2, 0.4)
+            .unwrap()
+            .stops
+            .is_empty());
+    }
+
+    #[test]
+    fn test_resampled() {
+        let linear = two_stop("Linear", Color::new(10, 200, 30), Color::new(250, 20, 130));
+        for n in [2, 3, 7, 16, 100] {
+            let resampled = linear.resampled(n).unwrap();
+            assert!(resampled.is_valid());
+            assert_eq!(resampled.name, "Linear");
+            assert_eq!(resampled.stops.len(), n);
+            assert_eq!(resampled.stops[n - 1].position, 1.0);
+            for i in 0..=200 {
+                let p = i as f64 / 200.0;
+                assert_color_approx_eq!(resampled.get_color(p), linear.get_color(p), 1);
+            }
+        }
+
+        let rainbow = ColorMap::rainbow_scheme();
+        let resampled = rainbow.resampled(256).unwrap();
+        let worst = (0..=2000)
+            .map(|i| i as f64 / 2000.0)
+            .map(|p| resampled.get_color(p).delta_e2000(&rainbow.get_color(p)))
+            .fold(0.0, f64::max);
+        assert!(worst < 1.0, "max Delta E {}", worst);
+    }
+
+    #[test]
+    fn test_resampled_edge_cases() {
+        let fire = ColorMap::fire_scheme();
+        assert!(matches!(
+            fire.resampled(0),
+            Err(ColorMapError::InvalidStops(_))
+        ));
+        assert!(matches!(
+            fire.resampled(1),
+            Err(ColorMapError::InvalidStops(_))
+        ));
+        assert!(ColorMap::new("Empty")
+            .resampled(8)
+            .unwrap()
+            .stops
+            .is_empty());
+
+        let fade = ColorMap::with_stops(
+            "Fade",
+            vec![
+                ColorStop::with_name(0.0, Color::RED, "start"),
+                ColorStop::new(1.0, Color::RED).with_alpha(0),
+            ],
+        );
+        let resampled = fade.resampled(3).unwrap();
+        assert_eq!(resampled.stops[0].alpha, None);
+        assert_eq!(resampled.stops[0].name, None);
+        assert_eq!(resampled.stops[1].alpha, Some(128));
+        assert_eq!(resampled.stops[2].alpha, Some(0));
+    }
+
+    #[test]
+    fn test_level_adjust() {
+        let fire = ColorMap::fire_scheme();
+
+        // The identity levels leave the gradient alone
+        assert_eq!(fire.level_adjust(0.0, 1.0, 1.0, 0.0, 1.0).stops, fire.stops);
+
+        // Input levels clip and stretch, keeping the colors at the clip points
+        let contrast = fire.level_adjust(0.2, 0.8, 1.0, 0.0, 1.0);
+        assert!(contrast.is_valid());
+        for i in 0..=20 {
+            let t = i as f64 / 20.0;
+            assert_color_approx_eq!(contrast.get_color(t), fire.get_color(0.2 + 0.6 * t), 1);
+        }
+
+        // Clipping into a curved segment keeps its shape
+        let smooth = ColorMap::with_stops(
+            "Smooth",
+            vec![
+                ColorStop::new(0.0, Color::BLACK).with_easing(Easing::EaseIn),
+                ColorStop::new(0.6, Color::RED).with_midpoint(0.3),
+                ColorStop::new(1.0, Color::WHITE),
+            ],
+        );
+        let clipped = smooth.level_adjust(0.25, 0.8, 1.0, 0.0, 1.0);
+        assert!(clipped.is_valid());
+        for i in 0..=40 {
+            let t = i as f64 / 40.0;
+            assert_color_approx_eq!(clipped.get_color(t), smooth.get_color(0.25 + 0.55 * t), 1);
+        }
+
+        // Stop positions follow the formula, names included
+        let named = ColorMap::with_stops(
+            "Named",
+            vec![
+                ColorStop::new(0.0, Color::BLACK),
+                ColorStop::with_name(0.5, Color::RED, "mid"),
+                ColorStop::new(1.0, Color::WHITE),
+            ],
+        );
+        let pushed = named.level_adjust(0.0, 1.0, 2.0, 0.0, 1.0);
+        assert_eq!(pushed.stops[1].name.as_deref(), Some("mid"));
+        assert!((pushed.stops[1].position - 0.5f64.sqrt()).abs() < 1e-12);
+        let pulled = named.level_adjust(0.0, 1.0, 0.5, 0.0, 1.0);
+        assert!((pulled.stops[1].position - 0.25).abs() < 1e-12);
+
+        // Output levels squeeze the gradient; get_color clamps outside it
+        let squeezed = named.level_adjust(0.0, 1.0, 1.0, 0.2, 0.6);
+        let positions: Vec<f64> = squeezed.stops.iter().map(|s| s.position).collect();
+        assert_eq!(positions, vec![0.2, 0.4, 0.6]);
+        assert_eq!(squeezed.get_color(0.1), Color::BLACK);
+        assert_eq!(squeezed.get_color(0.9), Color::WHITE);
+    }
+
+    #[test]
+    fn test_level_adjust_degenerate() {
+        let fire = ColorMap::fire_scheme();
+        for (in_black, in_white, gamma) in [
+            (0.5, 0.5, 1.0),
+            (0.8, 0.2, 1.0),
+            (0.0, 1.0, 0.0),
+            (0.0, 1.0, -1.0),
+            (f64::NAN, 1.0, 1.0),
+            (0.0, f64::INFINITY, 1.0),
+        ] {
+            let same = fire.level_adjust(in_black, in_white, gamma, 0.0, 1.0);
+            assert_eq!(same.stops, fire.stops);
+        }
+
+        // Input ranges reaching past the gradient still give valid maps
+        let wide = fire.level_adjust(-0.5, 1.5, 1.0, 0.0, 1.0);
+        assert!(wide.is_valid());
+        assert_eq!(wide.get_color(0.5), fire.get_color(0.5));
+        let outside = fire.level_adjust(1.5, 2.0, 1.0, 0.0, 1.0);
+        assert!(outside.is_valid());
+        assert_eq!(outside.stops.len(), 1);
+        assert!(ColorMap::new("Empty")
+            .level_adjust(0.2, 0.8, 1.0, 0.0, 1.0)
+            .stops
+            .is_empty());
+    }
+
+    #[test]
+    fn test_shifted() {
+        let ring = ColorMap::with_stops(
+            "Ring",
+            vec![
+                ColorStop::new(0.0, Color::RED),
+                ColorStop::with_name(0.3, Color::BLUE, "blue"),
+                ColorStop::new(0.6, Color::GREEN),
+                ColorStop::new(1.0, Color::RED),
+            ],
+        );
+        let same = ring.shifted(0.0);
+        assert_eq!(same.name, ring.name);
+        assert_eq!(same.stops, ring.stops);
+        assert_eq!(ring.shifted(2.0).stops, ring.stops);
+        assert_eq!(ring.shifted(f64::NAN).stops, ring.stops);
+
+        for offset in [0.25, -0.25, 0.7, 1.4] {
+            let shifted = ring.shifted(offset);
+            assert!(shifted.validate().is_ok(), "{:?}", shifted.validate());
+            assert_eq!(shifted.stops.first().unwrap().position, 0.0);
+            assert_eq!(shifted.stops.last().unwrap().position, 1.0);
+            let back = shifted.shifted(-offset);
+            for i in 0..=100 {
+                let t = i as f64 / 100.0;
+                let original = ring.get_color((t - offset).rem_euclid(1.0));
+                assert_color_approx_eq!(shifted.get_color(t), original, 2);
+                assert_color_approx_eq!(back.get_color(t), ring.get_color(t), 3);
+            }
+        }
+
+        // Names move with their stops; a stop landing on the wrap point is at both ends
+        let shifted = ring.shifted(0.7);
+        assert_eq!(shifted.stops[0].name.as_deref(), Some("blue"));
+        assert_eq!(shifted.stops.last().unwrap().name.as_deref(), Some("blue"));
+        let named = shifted.stops.iter().filter(|s| s.name.is_some()).count();
+        assert_eq!(named, 2);
+
+        // Wrapping inside a curved segment keeps its shape on both sides
+        let eased = ColorMap::with_stops(
+            "Eased",
+            vec![
+                ColorStop::new(0.0, Color::BLACK).with_easing(Easing::EaseIn),
+                ColorStop::new(1.0, Color::WHITE),
+            ],
+        );
+        assert_eq!(eased.shifted(0.5).get_color(0.25), eased.get_color(0.75));
+        let curves = ColorMap::with_stops(
+            "Curves",
+            vec![
+                ColorStop::new(0.0, Color::RED).with_easing(Easing::EaseInOut),
+                ColorStop::new(0.4, Color::BLUE).with_midpoint(0.25),
+                ColorStop::new(0.7, Color::GREEN)
+                    .with_easing(Easing::SmoothStep)
+                    .with_midpoint(0.6),
+                ColorStop::new(1.0, Color::RED),
+            ],
+        );
+        for map in [&eased, &curves] {
+            for offset in [0.1, 0.5, 0.85] {
+                let shifted = map.shifted(offset);
+                assert!(shifted.validate().is_ok());
+                for i in 0..=100 {
+                    let t = i as f64 / 100.0;
+                    let original = map.get_color((t - offset).rem_euclid(1.0));
+                    // The wrap point of a non-cyclic map is a hard edge
+                    if (t - offset).abs() > 1e-6 {
+                        assert_color_approx_eq!(shifted.get_color(t), original, 1);
+                    }
+                }
+            }
+        }
+
+        // A non-cyclic map gets a hard edge where its ends meet
+        let ramp = two_stop("Ramp", Color::BLACK, Color::WHITE);
+        let shifted = ramp.shifted(0.5);
+        assert_color_approx_eq!(shifted.get_color(0.5 - 1e-6), Color::WHITE, 1);
+        assert_eq!(shifted.get_color(0.5 + 1e-6), Color::BLACK);
+        assert!(shifted.validate().is_ok());
+    }
+
+    #[test]
+    fn test_reversed() {
+        let mut map = ColorMap::new("RedToBlue");
+        map.add_stop(ColorStop::new(0.0, Color::new(255, 0, 0))); // Red at start
+        map.add_stop(ColorStop::new(0.5, Color::new(128, 128, 0))); // Yellow-ish mid
+        map.add_stop(ColorStop::new(1.0, Color::new(0, 0, 255))); // Blue at end
+
+        let reversed = map.reversed();
+        
+        // Check name
+        assert_eq!(reversed.name, "RedToBlue (Reversed)");
+        
+        // Check number of stops
+        assert_eq!(reversed.stops.len(), 3);
+        
+        // Check that positions are flipped
+        assert_eq!(reversed.stops[0].position, 0.0);
+        assert_eq!(reversed.stops[1].position, 0.5);
+        assert_eq!(reversed.stops[2].position, 1.0);
+        
+        // Check that colors are in reverse order
+        // Original: Red(0.0) -> Yellow(0.5) -> Blue(1.0)
+        // Reversed: Blue(0.0) -> Yellow(0.5) -> Red(1.0)
+        assert_eq!(reversed.stops[0].color.b, 255); // Blue at start
+        assert_eq!(reversed.stops[2].color.r, 255); // Red at end
+        
+        // Check that getting color works correctly
+        let reversed_start = reversed.get_color(0.0);
+        let original_end = map.get_color(1.0);
+        assert_eq!(reversed_start.r, original_end.r);
+        assert_eq!(reversed_start.g, original_end.g);
+        assert_eq!(reversed_start.b, original_end.b);
+    }
+
+    #[test]
+    fn test_display_fromstr_roundtrip() {
+        let map = ColorMap::fire_scheme();
+        let parsed: ColorMap = map.to_string().parse().unwrap();
+
+        assert_eq!(parsed.name, map.name);
+        assert_eq!(parsed.stops, map.stops);
+        assert!("not a colormap".parse::<ColorMap>().is_err());
+    }
+
+    #[test]
+    fn test_stop_display_fromstr_roundtrip() {
+        let stops = [
+            ColorStop::new(0.0, Color::BLACK),
+            ColorStop::new(1.0 / 3.0, Color::new(255, 136, 0)),
+            ColorStop::with_name(1.0, Color::WHITE, "Snow: peak"),
+        ];
+        for stop in &stops {
+            assert_eq!(stop.to_string().parse::<ColorStop>().unwrap(), *stop);
+        }
+        assert_eq!(stops[0].to_string(), "0:#000000");
+        assert_eq!(stops[2].to_string(), "1:#FFFFFF:Snow: peak");
+
+        let parsed: ColorStop = " 0.5 : f80 : Orange ".parse().unwrap();
+        assert_eq!(
+            parsed,
+            ColorStop::with_name(0.5, Color::new(255, 136, 0), "Orange")
+        );
+        assert_eq!("0.5:#fff:".parse::<ColorStop>().unwrap().name, None);
+    }
+
+    #[test]
+    fn test_stop_fromstr_errors() {
+        let err = |s: &str| s.parse::<ColorStop>().unwrap_err();
+        assert!(err("abc:#fff")
+            .to_string()
+            .contains("invalid position 'abc'"));
+        assert!(err("0.5:").to_string().contains("missing color"));
+        assert!(err("0.5").to_string().contains("missing ':' separator"));
+        assert!(err("").to_string().contains("missing ':' separator"));
+        assert!(matches!(err("0.5:#GGG"), ColorMapError::InvalidHexColor(_)));
+        assert!(matches!(err("0.5:é1"), ColorMapError::InvalidHexColor(_)));
+        assert!(err("é:#fff").to_string().contains("invalid position 'é'"));
+        assert!(matches!(err("1.5:#fff"), ColorMapError::InvalidPosition(_)));
+        assert!(matches!(err("NaN:#fff"), ColorMapError::InvalidPosition(_)));
+    }
+
+    fn two_stop(name: &str, from: Color, to: Color) -> ColorMap {
+        ColorMap::equidistant(name, vec![from, to])
+    }
+
+    #[test]
+    fn test_append_gradient_hard_join() {
+        let a = two_stop("A", Color::BLACK, Color::WHITE);
+        let b = two_stop("B", Color::RED, Color::BLUE);
+        let joined = a.append_gradient(&b, 0.0);
+
+        assert_eq!(joined.name, "A + B");
+        assert!(joined.is_valid());
+        assert_eq!(joined.get_color(0.0), Color::BLACK);
+        assert_eq!(joined.get_color(0.25), a.get_color(0.5));
+        assert_eq!(joined.get_color(0.5), Color::WHITE);
+        assert_color_approx_eq!(joined.get_color(0.5 + 1e-6), Color::RED, 1);
+        assert_eq!(joined.get_color(0.75), b.get_color(0.5));
+        assert_eq!(joined.get_color(1.0), Color::BLUE);
+
+        assert_eq!(a.prepend_gradient(&b, 0.0).name, "B + A");
+        assert_eq!(a.prepend_gradient(&b, 0.0).get_color(0.0), Color::RED);
+    }
+
+    #[test]
+    fn test_append_gradient_blend_zone() {
+        let a = two_stop("A", Color::BLACK, Color::WHITE);
+        let b = two_stop("B", Color::RED, Color::BLUE);
+        let joined = a.append_gradient(&b, 0.2);
+        assert!(joined.is_valid());
+
+        // Outside the zone each map is untouched; the center is an even mix
+        assert_eq!(joined.get_color(0.2), a.get_color(0.4));
+        assert_color_approx_eq!(joined.get_color(0.4), a.get_color(0.8), 1);
+        assert_color_approx_eq!(joined.get_color(0.6), b.get_color(0.2), 1);
+        assert_color_approx_eq!(joined.get_color(0.5), Color::new(255, 128, 128), 1);
+
+        // No seams across the crossfade
+        for i in 0..200 {
+            let p = 0.3 + i as f64 * 0.002;
+            assert_color_approx_eq!(joined.get_color(p), joined.get_color(p + 0.002), 8);
+        }
+
+        // Oversized zones are clamped to 0.5
+        assert_eq!(
+            a.append_gradient(&b, 3.0).stops,
+            a.append_gradient(&b, 0.5).stops
+        );
+    }
+
+    #[test]
+    fn test_concat() {
+        let ocean = ColorMap::ocean_scheme();
+        let fire = ColorMap::fire_scheme();
+        for split in [0.1, 0.3, 0.75] {
+            let joined = ocean.concat(&fire, split, Junction::Hard);
+            assert!(joined.is_valid());
+            assert_eq!(joined.name, "Ocean + Fire");
+            assert_eq!(joined.stops.len(), ocean.stops.len() + fire.stops.len());
+            assert!(joined
+                .stops
+                .windows(2)
+                .all(|w| w[0].position < w[1].position));
+            for i in 0..=20 {
+                let t = i as f64 / 20.0;
+                assert_color_approx_eq!(joined.get_color(t * split), ocean.get_color(t), 1);
+                if t > 0.0 {
+                    let p = split + t * (1.0 - split);
+                    assert_color_approx_eq!(joined.get_color(p), fire.get_color(t), 1);
+                }
+            }
+        }
+
+        // Stop names survive the rescaling
+        let named = ColorMap::with_stops(
+            "Named",
+            vec![
+                ColorStop::with_name(0.0, Color::BLACK, "night"),
+                ColorStop::with_name(1.0, Color::WHITE, "day"),
+            ],
+        );
+        let joined = named.concat(&ocean, 0.4, Junction::Hard);
+        assert_eq!(joined.stops[0].name.as_deref(), Some("night"));
+        assert_eq!(joined.stops[1].name.as_deref(), Some("day"));
+        assert_eq!(joined.stops[1].position, 0.4);
+    }
+
+    #[test]
+    fn test_concat_junctions() {
+        let a = two_stop("A", Color::BLACK, Color::WHITE);
+        let b = two_stop("B", Color::RED, Color::BLUE);
+
+        let hard = a.concat(&b, 0.5, Junction::Hard);
+        assert_eq!(hard.stops.len(), 4);
+        assert_eq!(hard.get_color(0.5), Color::WHITE);
+        assert_color_approx_eq!(hard.get_color(0.5 + 1e-6), Color::RED, 1);
+
+        let blend = a.concat(&b, 0.5, Junction::Blend);
+        assert!(blend.is_valid());
+        assert_eq!(blend.stops.len(), 3);
+        assert_eq!(blend.stops[1].position, 0.5);
+        assert_color_approx_eq!(blend.get_color(0.5), Color::new(255, 127, 127), 1);
+        assert_eq!(blend.get_color(1.0), Color::BLUE);
+
+        // Matching end colors never duplicate a stop
+        let back = two_stop("Back", Color::WHITE, Color::BLACK);
+        assert_eq!(a.concat(&back, 0.5, Junction::Hard).stops.len(), 3);
+    }
+
+    #[test]
+    fn test_concat_degenerate_split() {
+        let a = ColorMap::ocean_scheme();
+        let b = ColorMap::fire_scheme();
+        for junction in [Junction::Hard, Junction::Blend] {
+            assert_eq!(a.concat(&b, 0.0, junction).stops, b.stops);
+            assert_eq!(a.concat(&b, -2.0, junction).stops, b.stops);
+            assert_eq!(a.concat(&b, 1.0, junction).stops, a.stops);
+            assert!(a.concat(&b, 1.0 - 1e-12, junction).is_valid());
+            assert_eq!(
+                a.concat(&b, f64::NAN, junction).stops,
+                a.concat(&b, 0.5, junction).stops
+            );
+        }
+        let empty = ColorMap::new("Empty");
+        let joined = empty.concat(&b, 0.5, Junction::Hard);
+        assert!(joined.is_valid());
+        assert_eq!(joined.get_color(0.75), b.get_color(0.5));
+    }
+
+    #[test]
+    fn test_blend() {
+        let fire = ColorMap::fire_scheme();
+        let ocean = ColorMap::ocean_scheme();
+        let mut eased = ColorMap::viridis_scheme();
+        eased.interpolation = InterpolationMode::Oklab;
+        eased.stops[1].easing = Easing::SmoothStep;
+
+        // A hard step and a strongly skewed midpoint
+        let mut step = two_stop("Step", Color::BLACK, Color::WHITE);
+        step.stops[0].easing = Easing::Step;
+        let mut skewed = ColorMap::fire_scheme();
+        skewed.stops[1].midpoint = Some(0.02);
+        skewed.stops[2].midpoint = Some(0.97);
+        skewed.stops[2].easing = Easing::EaseIn;
+        skewed.stops[3].easing = Easing::Step;
+        let gray = ColorMap::grayscale_scheme();
+
+        for (a, b) in [
+            (&fire, &ocean),
+            (&ocean, &eased),
+            (&step, &gray),
+            (&gray, &step),
+            (&skewed, &ocean),
+            (&step, &skewed),
+        ] {
+            for t in [0.25, 0.5, 0.9] {
+                let blended = a.blend(b, t);
+                assert!(blended.is_valid());
+                for i in 0..=1000 {
+                    let p = i as f64 / 1000.0;
+                    // Against the unrounded mix; get_color itself truncates
+                    let expected = a.get_color_f(p).lerp(&b.get_color_f(p), t).to_color();
+                    assert_color_approx_eq!(blended.get_color(p), expected, 1);
+                }
+            }
+        }
+
+        // Exact at every original stop
+        let blended = fire.blend(&ocean, 0.5);
+        for stop in fire.stops.iter().chain(&ocean.stops) {
+            let expected = fire
+                .get_color_f(stop.position)
+                .lerp(&ocean.get_color_f(stop.position), 0.5)
+                .to_color();
+            assert_eq!(blended.get_color(stop.position), expected);
+        }
+        assert_eq!(blended.name, "Fire");
+
+        let stepped = step.blend(&gray, 0.5);
+        assert_color_approx_eq!(stepped.get_color(0.99), Color::new(126, 126, 126), 1);
+        assert_eq!(stepped.get_color(1.0), Color::WHITE);
+    }
+
+    #[test]
+    fn test_blend_clamps_factor() {
+        let fire = ColorMap::fire_scheme();
+        let ocean = ColorMap::ocean_scheme();
+        assert_eq!(fire.blend(&ocean, 0.0).stops, fire.stops);
+        assert_eq!(fire.blend(&ocean, -1.0).stops, fire.stops);
+        assert_eq!(fire.blend(&ocean, f64::NAN).stops, fire.stops);
+        assert_eq!(fire.blend(&ocean, 5.0).stops, fire.blend(&ocean, 1.0).stops);
+        for i in 0..=100 {
+            let p = i as f64 / 100.0;
+            assert_color_approx_eq!(fire.blend(&ocean, 1.0).get_color(p), ocean.get_color(p), 1);
+        }
+
+        let fade = ColorMap::with_stops(
+            "Fade",
+            vec![
+                ColorStop::new(0.0, Color::RED),
+                ColorStop::new(1.0, Color::RED).with_alpha(0),
+            ],
+        );
+        assert_eq!(fire.blend(&fade, 0.5).get_rgba(1.0).a, 128);
+    }
+
+    #[test]
+    fn test_concat_gradients() {
+        let maps = [
+            two_stop("A", Color::BLACK, Color::WHITE),
+            ColorMap::fire_scheme(),
+            ColorMap::with_stops("C", vec![ColorStop::new(0.5, Color::GREEN)]),
+        ];
+        let joined = ColorMap::concat_gradients(&maps);
+        assert_eq!(joined.name, "A + Fire + C");
+        assert!(joined.is_valid());
+        assert_eq!(joined.get_color(1.0 / 6.0), maps[0].get_color(0.5));
+        assert_eq!(joined.get_color(0.5), maps[1].get_color(0.5));
+        assert_eq!(joined.get_color(0.9), Color::GREEN);
+
+        // Seams with matching colors don't duplicate stops
+        let gray = two_stop("Gray", Color::BLACK, Color::WHITE);
+        let back = two_stop("Back", Color::WHITE, Color::BLACK);
+        assert_eq!(
+            ColorMap::concat_gradients(&[gray.clone(), back])
+                .stops
+                .len(),
+            3
+        );
+
+        assert_eq!(
+            ColorMap::concat_gradients(std::slice::from_ref(&gray)).stops,
+            gray.stops
+        );
+        let empty = ColorMap::concat_gradients(&[]);
+        assert!(empty.name.is_empty() && empty.stops.is_empty());
+    }
+
+    #[test]
+    fn test_stop_midpoint() {
+        let linear = two_stop("Linear", Color::BLACK, Color::WHITE);
+        let mut early = linear.clone();
+        early.stops[0].midpoint = Some(0.25);
+
+        // The 50% gray moves from the middle of the segment toward its start
+        assert_eq!(linear.get_color(0.5), Color::new(127, 127, 127));
+        assert_eq!(early.get_color(0.25), Color::new(127, 127, 127));
+        assert!(early.get_color(0.5).r > linear.get_color(0.5).r);
+        assert_eq!(early.get_color(0.0), Color::BLACK);
+        assert_eq!(early.get_color(1.0), Color::WHITE);
+        assert!((early.get_color_f(0.625).r - 0.75).abs() < 1e-12);
+
+        // A midpoint of 0.5 is a no-op, and extremes are clamped instead of dividing by zero
+        let mut half = linear.clone();
+        half.stops[0].midpoint = Some(0.5);
+        assert_eq!(half.get_color(0.3), linear.get_color(0.3));
+        let mut extreme = linear.clone();
+        extreme.stops[0].midpoint = Some(0.0);
+        let mut clamped = linear.clone();
+        clamped.stops[0].midpoint = Some(ColorStop::MIN_MIDPOINT);
+        assert_eq!(extreme.get_color(0.005), clamped.get_color(0.005));
+        assert_eq!(extreme.get_color(0.5), clamped.get_color(0.5));
+
+        // Reversing mirrors the midpoint onto the segment's new starting stop
+        let reversed = early.reversed();
+        assert_eq!(reversed.stops[0].midpoint, Some(0.75));
+        assert_eq!(reversed.stops[1].midpoint, None);
+        assert_eq!(reversed.get_color(0.75), early.get_color(0.25));
+
+        // Unset midpoints are not serialized and old JSON loads unchanged
+        let json = serde_json::to_string(&linear).unwrap();
+        assert!(!json.contains("midpoint"));
+        let json = serde_json::to_string(&early).unwrap();
+        assert!(json.contains(r#""midpoint":0.25"#));
+        let loaded: ColorMap = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.stops, early.stops);
+    }
+
+    #[test]
+    fn test_easing_curves() {
+        let cases = [
+            (Easing::Linear, 0.5),
+            (Easing::EaseIn, 0.25),
+            (Easing::EaseOut, 0.75),
+            (Easing::EaseInOut, 0.5),
+            (Easing::SmoothStep, 0.5),
+            (Easing::Step, 0.0),
+        ];
+        for (easing, half) in cases {
+            assert_eq!(easing.apply(0.0), 0.0, "{:?}", easing);
+            assert_eq!(easing.apply(0.5), half, "{:?}", easing);
+            assert_eq!(easing.apply(1.0), 1.0, "{:?}", easing);
+        }
+        assert_eq!(Easing::EaseInOut.apply(0.25), 0.125);
+        assert_eq!(Easing::Step.apply(0.999), 0.0);
+    }
+
+    #[test]
+    fn test_stop_easing() {
+        let eased = |easing: Easing| {
+            ColorMap::with_stops(
+                "Eased",
+                vec![
+                    ColorStop::new(0.0, Color::BLACK).with_easing(easing),
+                    ColorStop::new(0.5, Color::WHITE),
+                    ColorStop::new(1.0, Color::RED),
+                ],
+            )
+        };
+
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+            Easing::SmoothStep,
+            Easing::Step,
+        ] {
+            let map = eased(easing);
+            // Continuous at the stops, and the next segment is unaffected
+            assert_eq!(map.get_color(0.0), Color::BLACK);
+            assert_eq!(map.get_color(0.5), Color::WHITE);
+            assert_eq!(map.get_color(0.75), Color::new(255, 127, 127));
+            let v = (easing.apply(0.5) * 255.0) as u8;
+            assert_eq!(map.get_color(0.25), Color::new(v, v, v), "{:?}", easing);
+        }
+
+        // Step makes hard bands without doubled stops
+        let step = eased(Easing::Step);
+        assert_eq!(step.get_color(0.4999), Color::BLACK);
+        assert_eq!(step.get_color(0.5), Color::WHITE);
+
+        // Reversing mirrors the curve onto the segment's new starting stop
+        let reversed = eased(Easing::EaseIn).reversed();
+        assert_eq!(reversed.stops[1].easing, Easing::EaseOut);
+        assert_eq!(reversed.stops[2].easing, Easing::Linear);
+        assert_eq!(
+            reversed.get_color(0.8),
+            eased(Easing::EaseIn).get_color(0.2)
+        );
+
+        // A reversed step jumps at the start of its segment instead
+        for map in [
+            eased(Easing::Step),
+            eased(Easing::Step).reversed().reversed(),
+        ] {
+            let reversed = map.reversed();
+            assert!(reversed.is_valid());
+            for i in 0..=1000 {
+                let p = i as f64 / 1000.0;
+                // Linear segments may truncate differently in either direction
+                assert_color_approx_eq!(reversed.get_color(p), map.get_color(1.0 - p), 1);
+            }
+        }
+        let reversed = eased(Easing::Step).reversed();
+        assert_eq!(reversed.get_color(0.5), Color::WHITE);
+        assert_eq!(reversed.get_color(0.5 + 1e-6), Color::BLACK);
+
+        let json = serde_json::to_string(&eased(Easing::Linear)).unwrap();
+        assert!(!json.contains("easing"));
+        let json = serde_json::to_string(&eased(Easing::SmoothStep)).unwrap();
+        assert!(json.contains(r#""easing":"SmoothStep""#));
+        let loaded: ColorMap = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.stops, eased(Easing::SmoothStep).stops);
+    }
+
+    #[test]
+    fn test_to_palette() {
+        let map = ColorMap::fire_scheme();
+        assert!(map.to_palette(0).is_empty());
+        assert_eq!(map.to_palette(1), vec![map.get_color(0.0)]);
+        let palette = map.to_palette(5);
+        assert_eq!(palette.len(), 5);
+        assert_eq!(palette[2], map.get_color(0.5));
+        assert_eq!(palette[4], map.get_color(1.0));
+
+        let distinct = map.to_palette_distinct(32, 10.0);
+        assert!(distinct.len() < 32);
+        assert_eq!(distinct[0], palette[0]);
+        for (i, a) in distinct.iter().enumerate() {
+            for b in &distinct[i + 1..] {
+                assert!(a.distance_delta_e_76(b) > 10.0);
+            }
+        }
+        assert_eq!(distinct, map.to_palette_distinct(32, 10.0));
+
+        // A negative threshold keeps everything, even repeats
+        let flat = two_stop("Flat", Color::RED, Color::RED);
+        assert_eq!(flat.to_palette_distinct(4, -1.0).len(), 4);
+        assert_eq!(flat.to_palette_distinct(4, 0.0), vec![Color::RED]);
+    }
+
+    #[test]
+    fn test_stop_metadata() {
+        let plain = ColorMap::fire_scheme();
+        let json = serde_json::to_string(&plain).unwrap();
+        assert!(!json.contains("metadata"));
+        let loaded: ColorMap = serde_json::from_str(&json).unwrap();
+        assert!(loaded.stops.iter().all(|s| s.metadata.is_empty()));
+
+        let mut map = two_stop("Annotated", Color::BLACK, Color::WHITE);
+        map.stops[1] = map.stops[1]
+            .clone()
+            .with_metadata("threshold", "0.75")
+            .with_metadata("picked_by", "ana");
+        let loaded: ColorMap = serde_json::from_str(&serde_json::to_string(&map).unwrap()).unwrap();
+        assert_eq!(loaded.stops, map.stops);
+
+        // Preserved wherever the stop itself survives
+        assert_eq!(map.reversed().stops[0].metadata, map.stops[1].metadata);
+        assert_eq!(map.inverted().stops[1].metadata, map.stops[1].metadata);
+        let joined = ColorMap::concat_gradients(&[map.clone(), map.clone()]);
+        assert_eq!(
+            joined
+                .stops
+                .iter()
+                .filter(|s| !s.metadata.is_empty())
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_get_rgba() {
+        let fade = ColorMap::with_stops(
+            "Fade",
+            vec![
+                ColorStop::new(0.0, Color::BLACK).with_alpha(255),
+                ColorStop::new(1.0, Color::WHITE).with_alpha(0),
+            ],
+        );
+        assert_eq!(fade.get_rgba(0.0), Rgba::new(0, 0, 0, 255));
+        assert_eq!(fade.get_rgba(0.5).a, 128);
+        assert_eq!(fade.get_rgba(0.5).color(), fade.get_color(0.5));
+        assert_eq!(fade.get_rgba(1.0).a, 0);
+
+        // Unset alpha is opaque, and empty maps are transparent
+        let opaque = two_stop("Opaque", Color::RED, Color::BLUE);
+        assert_eq!(opaque.get_rgba(0.3).a, 255);
+        assert_eq!(ColorMap::new("Empty").get_rgba(0.5), Rgba::new(0, 0, 0, 0));
+
+        let json = serde_json::to_string(&opaque).unwrap();
+        assert!(!json.contains("alpha"));
+        let json = serde_json::to_string(&fade).unwrap();
+        let loaded: ColorMap = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.stops, fade.stops);
+        assert_eq!(loaded.get_rgba(0.5), fade.get_rgba(0.5));
+
+        // Both save formats carry alpha through the io functions
+        use crate::io::{self, ColorFormat};
+        for format in [ColorFormat::Rgb, ColorFormat::Hex] {
+            let saved = if format == ColorFormat::Rgb {
+                io::save_colormap_to_string(&fade).unwrap()
+            } else {
+                io::save_colormap_to_string_with_format(&fade, format).unwrap()
+            };
+            assert_eq!(saved.contains("#FFFFFF"), format == ColorFormat::Hex);
+            let loaded = io::load_colormap_from_str(&saved).unwrap();
+            assert_eq!(loaded.stops, fade.stops, "{:?}", format);
+            assert_eq!(loaded.get_rgba(0.5), fade.get_rgba(0.5));
+        }
+    }
+
+    #[test]
+    fn test_alpha_ramp() {
+        let map = ColorMap::fire_scheme();
+        let fade_in = map.fade_in();
+        assert_eq!(fade_in.get_color_a(0.0), (map.get_color(0.0), 0));
+        assert_eq!(fade_in.get_color_a(0.5), (map.get_color(0.5), 128));
+        assert_eq!(fade_in.get_color_a(1.0), (map.get_color(1.0), 255));
+        assert_eq!(fade_in.get_color_a(2.0).1, 255);
+        assert_eq!(fade_in.get_color_a(-1.0).1, 0);
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            // Ramps round half up, so mirrored pairs can overshoot by one
+            let sum = map.fade_out().get_color_a(t).1 as u16 + fade_in.get_color_a(t).1 as u16;
+            assert!((255..=256).contains(&sum), "{} at {}", sum, t);
+        }
+
+        let ramp = map.with_alpha_ramp(100, 200);
+        assert_eq!(
+            ramp.get_rgba(0.25),
+            Rgba::from_color(map.get_color(0.25), 125)
+        );
+
+        // Stop alpha and the ramp multiply
+        let mut translucent = two_stop("Half", Color::RED, Color::BLUE);
+        for stop in &mut translucent.stops {
+            stop.alpha = Some(128);
+        }
+        assert_eq!(translucent.fade_in().get_color_a(1.0).1, 128);
+        assert_eq!(translucent.with_alpha_ramp(128, 128).get_color_a(0.5).1, 64);
+    }
+
+    #[test]
+    fn test_invert_colors() {
+        let fire = ColorMap::fire_scheme();
+        let negative = fire.inverted();
+        assert_eq!(negative.name, fire.name);
+        for (a, b) in negative.stops.iter().zip(&fire.stops) {
+            assert_eq!(a.position, b.position);
+            assert_eq!(a.color, b.color.inverted());
+        }
+        assert_eq!(fire.inverted().inverted().stops, fire.stops);
+
+        let mut in_place = fire.clone();
+        in_place.invert_colors();
+        assert_eq!(in_place.stops, negative.stops);
+        in_place.invert_colors();
+        assert_eq!(in_place.stops, fire.stops);
+
+        let gray = ColorMap::grayscale_scheme();
+        let negative = gray.inverted();
+        assert_eq!(negative.stops.first().unwrap().color, Color::WHITE);
+        assert_eq!(negative.stops.last().unwrap().color, Color::BLACK);
+        assert_eq!(negative.stops[1].color, gray.stops[1].color.inverted());
+
+        // Inverting and reversing are different operations that compose
+        let both = fire.inverted().reversed();
+        assert_ne!(both.stops, fire.inverted().stops);
+        assert_ne!(both.stops, fire.reversed().stops);
+        assert_eq!(both.get_color(0.0), fire.get_color(1.0).inverted());
+    }
+
+    #[test]
+    fn test_map_colors() {
+        let fire = ColorMap::fire_scheme();
+        let lighter = fire.map_colors(|c| c.lighten(0.1));
+
+        assert_eq!(lighter.name, fire.name);
+        assert_eq!(lighter.stops.len(), fire.stops.len());
+        for (a, b) in lighter.stops.iter().zip(&fire.stops) {
+            assert_eq!(a.position, b.position);
+            assert_eq!(a.color, b.color.lighten(0.1));
+        }
+    }
+
+    #[test]
+    fn test_hsv_adjustments() {
+        let ocean = ColorMap::ocean_scheme();
+        let rotated = ocean.rotate_hue(120.0);
+        assert_eq!(rotated.name, ocean.name);
+        for (a, b) in rotated.stops.iter().zip(&ocean.stops) {
+            assert_eq!(a.position, b.position);
+            assert_eq!(a.color, b.color.rotate_hue(120.0));
+        }
+        // A full turn is a no-op up to rounding
+        for (a, b) in ocean.rotate_hue(360.0).stops.iter().zip(&ocean.stops) {
+            assert_color_approx_eq!(a.color, b.color, 1);
+        }
+
+        let map = ColorMap::equidistant("Test", vec![Color::new(200, 100, 100), Color::RED]);
+        let muted = map.adjust_saturation(0.5);
+        assert_eq!(muted.stops[1].color, Color::new(255, 128, 128));
+        assert_eq!(map.adjust_saturation(0.0).stops[1].color, Color::WHITE);
+        assert_eq!(
+            map.adjust_saturation(10.0).stops[0].color,
+            Color::new(200, 0, 0)
+        );
+
+        let dimmed = map.adjust_value(0.5);
+        assert_eq!(dimmed.stops[1].color, Color::new(128, 0, 0));
+        assert_eq!(map.adjust_value(0.0).stops[0].color, Color::BLACK);
+        assert_eq!(
+            map.adjust_value(2.0).stops[0].color,
+            Color::new(255, 128, 128)
+        );
+    }
+
+    #[test]
+    fn test_desaturate_and_tint() {
+        let rainbow = ColorMap::rainbow_scheme();
+        assert_eq!(rainbow.desaturate(0.0).stops, rainbow.stops);
+        assert_eq!(
+            rainbow.desaturate(1.0).stops,
+            rainbow.to_grayscale(GrayscaleMethod::Rec709).stops
+        );
+
+        // Green is far brighter than blue, so its gray target is too
+        let half = rainbow.desaturate(0.5);
+        let green = half.get_color(0.33);
+        assert_eq!(green, Color::new(91, 218, 91));
+        let blue = half.get_color(0.67);
+        assert!(blue.r < green.r && blue.b > blue.r);
+
+        let tinted = rainbow.tint(Color::WHITE, 0.5);
+        assert_eq!(tinted.name, rainbow.name);
+        assert_eq!(tinted.stops[0].color, Color::new(255, 127, 127));
+        assert_eq!(rainbow.tint(Color::BLUE, 1.0).get_color(0.4), Color::BLUE);
+        assert_eq!(rainbow.tint(Color::BLUE, 0.0).stops, rainbow.stops);
+    }
+
+    #[test]
+    fn test_metadata_serialization() {
+        // Files without metadata still load, and empty metadata is not written out
+        let json = r#"{"name": "Plain", "stops": []}"#;
+        let map: ColorMap = serde_json::from_str(json).unwrap();
+        assert!(map.metadata.is_empty());
+        assert!(!serde_json::to_string(&map).unwrap().contains("metadata"));
+
+        let map = map.with_metadata(ColorMapMetadata {
+            description: Some("A test map".to_string()),
+            tags: vec!["test".to_string()],
+            ..Default::default()
+        });
+        let roundtrip: ColorMap =
+            serde_json::from_str(&serde_json::to_string(&map).unwrap()).unwrap();
+        assert_eq!(roundtrip.metadata, map.metadata);
     }
 
-    /// Fire color scheme (black -> red -> orange -> yellow -> white)
-    pub fn fire_scheme() -> Self {
-        Self::with_stops(
-            "Fire",
-            vec![
-                ColorStop::new(0.0, Color::black()),
-                ColorStop::new(0.25, Color::new(128, 0, 0)), // Dark red
-                ColorStop::new(0.5, Color::new(255, 0, 0)),  // Red
-                ColorStop::new(0.75, Color::new(255, 128, 0)), // Orange
-                ColorStop::new(0.9, Color::new(255, 255, 0)), // Yellow
-                ColorStop::new(1.0, Color::white()),
-            ],
-        )
+    #[test]
+    fn test_colormap_to_grayscale() {
+        let gray = ColorMap::rainbow_scheme().to_grayscale(GrayscaleMethod::Rec709);
+        for stop in &gray.stops {
+            assert_eq!(stop.color.r, stop.color.g);
+            assert_eq!(stop.color.g, stop.color.b);
+        }
     }
 
-    /// Ocean color scheme (black -> deep blue -> cyan -> white)
-    pub fn ocean_scheme() -> Self {
-        Self::with_stops(
-            "Ocean",
-            vec![
-                ColorStop::new(0.0, Color::black()),
-                ColorStop::new(0.3, Color::new(0, 0, 128)), // Deep blue
-                ColorStop::new(0.6, Color::new(0, 128, 255)), // Sky blue
-                ColorStop::new(0.85, Color::new(0, 255, 255)), // Cyan
-                ColorStop::new(1.0, Color::white()),
-            ],
-        )
+    #[test]
+    fn test_add_stop_repeated_position() {
+        let mut map = two_stop("Edge", Color::BLACK, Color::WHITE);
+        map.add_stop(ColorStop::new(0.5, Color::RED));
+        map.add_stop(ColorStop::new(0.5, Color::BLUE));
+        map.add_stop(ColorStop::new(1.0, Color::GREEN));
+        assert!(map.is_valid());
+        assert_eq!(map.stops.len(), 5);
+        assert_eq!(map.get_color(0.5), Color::RED);
+        assert_eq!(map.get_color(0.5 + 2e-9), Color::BLUE);
+        assert_eq!(map.get_color(1.0), Color::GREEN);
+        assert_eq!(map.stops[3].position, 1.0 - ColorMap::JOIN_GAP);
+        assert_eq!(map.stops[3].color, Color::WHITE);
     }
 
-    /// Grayscale color scheme (black -> gray -> white)
-    pub fn grayscale_scheme() -> Self {
-        Self::with_stops(
-            "Grayscale",
+    #[test]
+    fn test_validate() {
+        assert!(ColorMap::fire_scheme().is_valid());
+        assert!(
+            ColorMap::with_stops("Single", vec![ColorStop::new(0.5, Color::white())]).is_valid()
+        );
+
+        let empty = ColorMap::new("Empty");
+        assert!(matches!(
+            empty.validate(),
+            Err(ColorMapError::InvalidStops(_))
+        ));
+
+        let mut duplicate = ColorMap::new("Duplicate");
+        duplicate.stops.push(ColorStop::new(0.5, Color::black()));
+        duplicate.stops.push(ColorStop::new(0.5, Color::white()));
+        assert!(!duplicate.is_valid());
+
+        // Direct field access can bypass the sorting and clamping invariants
+        let mut unsorted = ColorMap::fire_scheme();
+        unsorted.stops.swap(0, 1);
+        assert!(!unsorted.is_valid());
+
+        let mut out_of_range = ColorMap::fire_scheme();
+        out_of_range.stops[0].position = -0.1;
+        assert!(!out_of_range.is_valid());
+
+        let mut non_finite = ColorMap::fire_scheme();
+        non_finite.stops[5].position = f64::INFINITY;
+        assert!(!non_finite.is_valid());
+    }
+
+    #[test]
+    fn test_builder() {
+        let map = ColorMap::builder()
+            .name("Built")
+            .stop(1.0, Color::white())
+            .named_stop(0.0, Color::black(), "Start")
+            .interpolation(InterpolationMode::Hsv)
+            .metadata(ColorMapMetadata {
+                author: Some("Tester".to_string()),
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+
+        assert_eq!(map.name, "Built");
+        assert_eq!(map.interpolation, InterpolationMode::Hsv);
+        assert_eq!(map.metadata.author.as_deref(), Some("Tester"));
+        // Stops are sorted on build
+        assert_eq!(map.stops[0].name.as_deref(), Some("Start"));
+        assert_eq!(map.stops[1].color, Color::white());
+
+        assert!(ColorMap::builder().name("Empty").build().is_err());
+        assert!(ColorMap::builder()
+            .stop(f64::NAN, Color::RED)
+            .build()
+            .is_err());
+
+        // Repeated positions make a hard edge
+        let edge = ColorMap::builder()
+            .stop(0.0, Color::black())
+            .stop(0.5, Color::black())
+            .stop(0.5, Color::white())
+            .build()
+            .unwrap();
+        assert!(edge.is_valid());
+        assert_eq!(edge.get_color(0.75), Color::white());
+        assert_eq!(
+            ColorMapBuilder::default()
+                .stop(0.0, Color::black())
+                .build()
+                .unwrap()
+                .name,
+            "Unnamed"
+        );
+    }
+
+    #[test]
+    fn test_hsv_interpolation() {
+        let mut map = ColorMap::new("RedToBlue");
+        map.add_stop(ColorStop::new(0.0, Color::new(255, 0, 0)));
+        map.add_stop(ColorStop::new(1.0, Color::new(0, 0, 255)));
+
+        // RGB goes through a dark purple, HSV through full-brightness magenta
+        assert_eq!(map.get_color(0.5), Color::new(127, 0, 127));
+        map.interpolation = InterpolationMode::Hsv;
+        assert_eq!(map.get_color(0.5), Color::new(255, 0, 255));
+
+        // The mode survives serialization, and is omitted when default
+        let json = serde_json::to_string(&map).unwrap();
+        let parsed: ColorMap = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.interpolation, InterpolationMode::Hsv);
+        assert!(!serde_json::to_string(&ColorMap::fire_scheme())
+            .unwrap()
+            .contains("interpolation"));
+    }
+
+    #[test]
+    fn test_stop_iteration() {
+        let mut map = ColorMap::fire_scheme();
+        let positions: Vec<f64> = (&map).into_iter().map(|stop| stop.position).collect();
+        assert_eq!(positions.len(), map.stops().len());
+        assert!(positions.windows(2).all(|w| w[0] < w[1]));
+
+        let mut count = 0;
+        for stop in &map {
+            assert!((0.0..=1.0).contains(&stop.position));
+            count += 1;
+        }
+        assert_eq!(count, map.stops().len());
+
+        map.stops_mut()[0].color = Color::white();
+        assert_eq!(map.get_color(0.0), Color::white());
+
+        let owned: Vec<ColorStop> = map.clone().into_iter().collect();
+        assert_eq!(owned, map.stops);
+    }
+
+    #[test]
+    fn test_optimize_stops() {
+        // A straight ramp sampled densely collapses to its endpoints
+        let stops = (0..=10)
+            .map(|i| ColorStop::new(i as f64 / 10.0, Color::new(i * 20, 0, 200 - i * 20)))
+            .collect();
+        let mut ramp = ColorMap::with_stops("Ramp", stops);
+        ramp.optimize_stops(ColorMap::DEFAULT_OPTIMIZE_TOLERANCE);
+        assert_eq!(ramp.stops.len(), 2);
+
+        // Fire's dark red stop sits on the black -> red line; the corners survive
+        let mut fire = ColorMap::fire_scheme();
+        fire.optimize_stops(ColorMap::DEFAULT_OPTIMIZE_TOLERANCE);
+        let positions: Vec<f64> = fire.stops.iter().map(|s| s.position).collect();
+        assert_eq!(positions, vec![0.0, 0.5, 0.75, 0.9, 1.0]);
+
+        // Everything stays within tolerance (plus rounding slack) after optimizing
+        let tolerance = 20.0;
+        let original = crate::io::load_builtin_colormap("Twilight Garden").unwrap();
+        let mut optimized = original.clone();
+        optimized.optimize_stops(tolerance);
+        assert!(optimized.stops.len() <= original.stops.len());
+        for i in 0..=200 {
+            let t = i as f64 / 200.0;
+            let error = optimized.get_color(t).distance_rgb(&original.get_color(t));
+            assert!(error <= tolerance + 2.0, "error {} at {}", error, t);
+        }
+
+        // Short maps and NaN tolerances are left alone
+        let mut two = ColorMap::with_stops(
+            "Two",
             vec![
                 ColorStop::new(0.0, Color::black()),
-                ColorStop::new(0.5, Color::new(128, 128, 128)),
                 ColorStop::new(1.0, Color::white()),
             ],
-        )
+        );
+        two.optimize_stops(1000.0);
+        assert_eq!(two.stops.len(), 2);
+        let mut fire = ColorMap::fire_scheme();
+        fire.optimize_stops(f64::NAN);
+        assert_eq!(fire.stops.len(), ColorMap::fire_scheme().stops.len());
     }
 
-    /// Rainbow color scheme (full spectrum)
-    pub fn rainbow_scheme() -> Self {
-        Self::with_stops(
-            "Rainbow",
-            vec![
-                ColorStop::new(0.0, Color::from_hsv(0.0, 1.0, 1.0)), // Red
-                ColorStop::new(0.17, Color::from_hsv(60.0, 1.0, 1.0)), // Yellow
-                ColorStop::new(0.33, Color::from_hsv(120.0, 1.0, 1.0)), // Green
-                ColorStop::new(0.5, Color::from_hsv(180.0, 1.0, 1.0)), // Cyan
-                ColorStop::new(0.67, Color::from_hsv(240.0, 1.0, 1.0)), // Blue
-                ColorStop::new(0.83, Color::from_hsv(300.0, 1.0, 1.0)), // Magenta
-                ColorStop::new(1.0, Color::from_hsv(360.0, 1.0, 1.0)), // Red
-            ],
-        )
+    #[test]
+    fn test_smooth_iteration_count_range() {
+        for iterations in [0, 1, 5, 99, 100] {
+            for zn_abs in [0.0, 0.5, 1.0, 1.0001, 2.0, 4.0, 1e3, 1e100, f64::MAX] {
+                let count = smooth_iteration_count(iterations, 100, zn_abs);
+                assert!(
+                    (0.0..=100.0).contains(&count),
+                    "{} for n = {}, |z| = {}",
+                    count,
+                    iterations,
+                    zn_abs
+                );
+            }
+        }
+
+        // |z| = 2 gives n + 1, and a larger |z| escapes "earlier"
+        assert_eq!(smooth_iteration_count(10, 100, 2.0), 11.0);
+        assert!(smooth_iteration_count(10, 100, 16.0) < smooth_iteration_count(10, 100, 4.0));
+        assert_eq!(smooth_iteration_count(10, 100, f64::NAN), 10.0);
     }
-}
 
-/// Convert iteration count to color using a colormap
-///
-/// This is a utility function for fractal rendering and similar applications
-/// where you need to map iteration counts to colors.
-///
-/// # Arguments
-/// * `iterations` - Number of iterations performed
-/// * `max_iterations` - Maximum iterations allowed
-/// * `colormap` - The colormap to use for coloring
-/// * `use_period` - Enable periodic color cycling
-/// * `period` - Period for color cycling (if enabled)
-/// * `use_interior_color` - Use custom color for interior points
-/// * `interior_color` - RGB color for interior points
-/// * `use_log_scale` - Apply logarithmic scaling to colors
-#[allow(clippy::too_many_arguments)]
-pub fn color_from_iterations(
-    iterations: u32,
-    max_iterations: u32,
-    colormap: &ColorMap,
-    use_period: bool,
-    period: u32,
-    use_interior_color: bool,
-    interior_color: [u8; 3],
-    use_log_scale: bool,
-) -> Color {
-    // Check if point is inside the set and custom interior color is enabled
-    if iterations >= max_iterations && use_interior_color {
-        return Color {
-            r: interior_color[0],
-            g: interior_color[1],
-            b: interior_color[2],
-        };
+    #[test]
+    fn test_color_from_iterations_smooth() {
+        let map = ColorMap::grayscale_scheme();
+
+        // Interior points behave exactly like the integer version
+        assert_eq!(
+            color_from_iterations_smooth(100, 100, 1.5, &map, false, 0, true, [1, 2, 3], false),
+            Color::new(1, 2, 3)
+        );
+
+        // With |z| = 2 the smooth count is n + 1
+        assert_eq!(
+            color_from_iterations_smooth(49, 100, 2.0, &map, false, 0, false, [0, 0, 0], false),
+            color_from_iterations(50, 100, &map, false, 0, false, [0, 0, 0], false)
+        );
+
+        // Neighboring escape radii give neighboring colors instead of bands
+        let a = color_from_iterations_smooth(50, 100, 3.0, &map, false, 0, false, [0, 0, 0], false);
+        let b = color_from_iterations_smooth(50, 100, 3.5, &map, false, 0, false, [0, 0, 0], false);
+        assert!(a.r >= b.r && a.r - b.r <= 1);
     }
 
-    // Apply period modulation if enabled
-    let effective_iterations = if use_period && period > 0 {
-        iterations % period
-    } else {
-        iterations
-    };
+    #[test]
+    fn test_colormap_simulate_cvd() {
+        let map = ColorMap::rainbow_scheme();
+        let simulated = map.simulate_cvd(ColorVisionDeficiency::Protanopia, 1.0);
 
-    // Normalize iterations to 0.0-1.0 range
-    let divisor = if use_period && period > 0 {
-        period as f64
-    } else {
-        max_iterations as f64
-    };
-    let t = effective_iterations as f64 / divisor;
+        assert_eq!(simulated.name, map.name);
+        for (original, stop) in map.stops.iter().zip(&simulated.stops) {
+            assert_eq!(stop.position, original.position);
+            assert_eq!(
+                stop.color,
+                original
+                    .color
+                    .simulate_cvd(ColorVisionDeficiency::Protanopia, 1.0)
+            );
+        }
+        assert_eq!(
+            map.simulate_cvd(ColorVisionDeficiency::Tritanopia, 0.0)
+                .stops,
+            map.stops
+        );
+    }
 
-    // Apply smooth coloring - use log scale if enabled, otherwise linear
-    let smooth_t = if use_log_scale {
-        (t * 10.0).log10() / 1.0 // log10(10) = 1
-    } else {
-        t // Linear scaling
-    };
+    #[test]
+    fn test_ascii_preview() {
+        let map = ColorMap::fire_scheme();
+        for width in [0, 1, 7, 80] {
+            let preview = map.to_ascii_preview(width);
+            assert_eq!(preview.chars().count(), width);
+            assert!(preview.chars().all(|c| ColorMap::ASCII_RAMP.contains(c)));
+        }
 
-    colormap.get_color(smooth_t.clamp(0.0, 1.0))
-}
+        let preview = map.to_ascii_preview(10);
+        assert!(
+            preview.starts_with(' '),
+            "black should be the darkest symbol"
+        );
+        assert!(
+            preview.ends_with('@'),
+            "white should be the lightest symbol"
+        );
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let legend = ColorMap::grayscale_scheme().to_ascii_preview_with_legend(3);
+        assert_eq!(legend, " :@\n#000000 #808080 #FFFFFF");
+        let rows: Vec<&str> = legend.lines().collect();
+        assert_eq!(rows[0].len(), 3);
+        assert_eq!(rows[1].split(' ').count(), 3);
+    }
 
     #[test]
-    fn test_colorstop_creation() {
-        let stop = ColorStop::new(0.5, Color::new(255, 0, 0));
-        assert_eq!(stop.position, 0.5);
-        assert_eq!(stop.color.r, 255);
-        assert!(stop.name.is_none());
+    fn test_lightness_profile() {
+        let profile = ColorMap::grayscale_scheme().lightness_profile(3);
+        assert_eq!(profile.len(), 3);
+        assert_eq!(profile[0], 0.0);
+        assert!((profile[1] - 53.6).abs() < 0.1);
+        assert!((profile[2] - 100.0).abs() < 1e-3);
+        assert!(ColorMap::grayscale_scheme().lightness_profile(0).is_empty());
 
-        let named_stop = ColorStop::with_name(0.3, Color::new(0, 255, 0), "Green");
-        assert_eq!(named_stop.name, Some("Green".to_string()));
+        assert!(ColorMap::grayscale_scheme().is_perceptually_monotone(100));
+        assert!(ColorMap::grayscale_scheme()
+            .reversed()
+            .is_perceptually_monotone(100));
+        assert!(!ColorMap::rainbow_scheme().is_perceptually_monotone(100));
     }
 
     #[test]
-    fn test_colormap_gradient() {
-        let mut map = ColorMap::new("Test");
-        map.add_stop(ColorStop::new(0.0, Color::new(0, 0, 0)));
-        map.add_stop(ColorStop::new(1.0, Color::new(255, 255, 255)));
+    fn test_perceptual_uniformity_score() {
+        // Stops evenly spaced in L* give near-constant steps
+        let stops = (0..=10)
+            .map(|i| ColorStop::new(i as f64 / 10.0, Color::from_lab(i as f64 * 10.0, 0.0, 0.0)))
+            .collect();
+        let uniform = ColorMap::with_stops("Uniform L*", stops);
+        let uniform_score = uniform.perceptual_uniformity_score(11);
+        assert!(uniform_score < 0.05, "score {}", uniform_score);
 
-        let start = map.get_color(0.0);
-        assert_eq!(start.r, 0);
+        let gray_score = ColorMap::grayscale_scheme().perceptual_uniformity_score(50);
+        let rainbow_score = ColorMap::rainbow_scheme().perceptual_uniformity_score(50);
+        assert!(uniform_score < gray_score && gray_score < rainbow_score);
 
-        let end = map.get_color(1.0);
-        assert_eq!(end.r, 255);
+        let flat = ColorMap::with_stops("Flat", vec![ColorStop::new(0.0, Color::RED)]);
+        assert_eq!(flat.perceptual_uniformity_score(10), f64::INFINITY);
+        assert_eq!(flat.perceptual_uniformity_score(1), 0.0);
+    }
 
-        let mid = map.get_color(0.5);
-        assert!(mid.r > 100 && mid.r < 200);
+    #[test]
+    fn test_average_warmth() {
+        assert!(ColorMap::fire_scheme().average_warmth() > Color::WARMTH_NEUTRAL_BAND);
+        assert!(ColorMap::ocean_scheme().average_warmth() < -Color::WARMTH_NEUTRAL_BAND);
+        assert_eq!(ColorMap::grayscale_scheme().average_warmth(), 0.0);
+        assert_eq!(ColorMap::new("Empty").average_warmth(), 0.0);
     }
 
     #[test]
-    fn test_builtin_schemes() {
-        let default = ColorMap::default_scheme();
-        assert_eq!(default.name, "Default");
-        assert!(!default.stops.is_empty());
+    fn test_sample_image_row() {
+        let map = ColorMap::fire_scheme();
+        let row = map.sample_image_row(5, 200);
+        assert_eq!(row.len(), 20);
+        for (i, pixel) in row.chunks(4).enumerate() {
+            let color = map.get_color(i as f64 / 4.0);
+            assert_eq!(pixel, [color.r, color.g, color.b, 200]);
+        }
+
+        let rgb = map.sample_image_row_rgb(5);
+        assert_eq!(rgb.len(), 15);
+        assert_eq!(&rgb[12..], &[255, 255, 255]);
+        assert!(map.sample_image_row(0, 255).is_empty());
+        assert_eq!(map.sample_image_row_rgb(1), vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn test_sample_2d_gradient_rgba() {
+        let gray = ColorMap::grayscale_scheme();
+        let to_red = ColorMap::with_stops(
+            "ToRed",
+            vec![
+                ColorStop::new(0.0, Color::WHITE),
+                ColorStop::new(1.0, Color::RED),
+            ],
+        );
+        let data = gray.sample_2d_gradient_rgba(&to_red, 3, 2);
+        assert_eq!(data.len(), 3 * 2 * 4);
+
+        // Top row multiplies by white, so it matches the x gradient
+        assert_eq!(&data[..12], &gray.sample_image_row(3, 255)[..]);
+        // Bottom row multiplies by red, keeping only the red channel
+        assert_eq!(&data[12..], &[0, 0, 0, 255, 128, 0, 0, 255, 255, 0, 0, 255]);
+        assert!(gray.sample_2d_gradient_rgba(&to_red, 3, 0).is_empty());
+    }
+
+    #[test]
+    fn test_shift_positions() {
+        let mut map = ColorMap::fire_scheme();
+        map.shift_positions(0.1);
+        let positions: Vec<f64> = map.stops.iter().map(|s| s.position).collect();
+        assert_eq!(positions.len(), 5);
+        assert!((positions[0] - 0.1).abs() < 1e-12);
+        assert!((positions[4] - 1.0).abs() < 1e-12);
+        // 0.9 and 1.0 both land on 1.0; the original end stop wins
+        assert_eq!(map.stops.last().unwrap().color, Color::white());
+        assert!(map.is_valid());
+
+        // Everything piles up at 1.0: boring, but still valid
+        let mut piled = ColorMap::fire_scheme();
+        piled.shift_positions(5.0);
+        assert_eq!(piled.stops.len(), 1);
+        assert!(piled.is_valid());
+        assert_eq!(piled.get_color(0.3), Color::white());
+
+        let mut unchanged = ColorMap::fire_scheme();
+        unchanged.shift_positions(f64::NAN);
+        assert_eq!(unchanged.stops, ColorMap::fire_scheme().stops);
+    }
+
+    #[test]
+    fn test_transform_keeps_hard_edges() {
+        let mut edge = two_stop("Edge", Color::BLACK, Color::BLUE);
+        edge.add_stop(ColorStop::new(0.0, Color::WHITE));
+        edge.add_stop(ColorStop::new(0.5, Color::RED));
+        edge.add_stop(ColorStop::new(0.5, Color::GREEN));
+        edge.add_stop(ColorStop::new(1.0, Color::YELLOW));
+        let colors = |map: &ColorMap| map.stops.iter().map(|s| s.color).collect::<Vec<_>>();
+
+        // A strong gamma squeezes the edge at 0.0 below f64::EPSILON
+        let pushed = edge.level_adjust(0.0, 1.0, 0.02, 0.0, 1.0);
+        assert!(pushed.is_valid());
+        assert_eq!(colors(&pushed), colors(&edge));
+        assert_eq!(pushed.get_color(0.0), Color::BLACK);
+        assert_eq!(pushed.get_color(ColorMap::JOIN_GAP), Color::WHITE);
+
+        // Collapsing everything keeps only the furthest edge, still as an edge
+        let mut collapsed = edge.clone();
+        collapsed.scale_positions(0.0, 0.3);
+        assert!(collapsed.is_valid());
+        assert_eq!(colors(&collapsed), vec![Color::BLUE, Color::YELLOW]);
+        assert_eq!(collapsed.get_color(0.0), Color::BLUE);
+        assert_eq!(collapsed.get_color(1.0), Color::YELLOW);
+
+        // Repeated positions written directly are kept as an edge too
+        let mut raw = two_stop("Raw", Color::BLACK, Color::WHITE);
+        raw.stops.insert(1, ColorStop::new(0.5, Color::RED));
+        raw.stops.insert(2, ColorStop::new(0.5, Color::BLUE));
+        raw.shift_positions(0.1);
+        assert!(raw.is_valid());
+        assert_eq!(raw.stops.len(), 4);
+        assert_eq!(raw.get_color(0.6), Color::RED);
+        assert_eq!(raw.get_color(0.6 + ColorMap::JOIN_GAP), Color::BLUE);
+
+        // Stops pushed into an end still pile up and merge
+        let mut piled = edge.clone();
+        piled.shift_positions(0.6);
+        assert!(piled.is_valid());
+        assert_eq!(
+            colors(&piled),
+            vec![Color::BLACK, Color::WHITE, Color::BLUE, Color::YELLOW]
+        );
+    }
 
+    #[test]
+    fn test_scale_positions() {
+        let mut map = ColorMap::grayscale_scheme();
+        map.scale_positions(0.5, 0.0);
+        let positions: Vec<f64> = map.stops.iter().map(|s| s.position).collect();
+        assert_eq!(positions, vec![0.0, 0.25, 0.5]);
+
+        // Mirroring around the middle reverses the gradient
+        let mut mirrored = ColorMap::grayscale_scheme();
+        mirrored.scale_positions(-1.0, 0.5);
+        assert_eq!(mirrored.stops[0].color, Color::white());
+        assert_eq!(mirrored.stops[2].color, Color::black());
+        assert!(mirrored.is_valid());
+
+        // Collapsing onto the origin still leaves a valid map
+        let mut collapsed = ColorMap::rainbow_scheme();
+        collapsed.scale_positions(0.0, 0.3);
+        assert_eq!(collapsed.stops.len(), 1);
+        assert!(collapsed.is_valid());
+    }
+
+    #[test]
+    fn test_get_color_f() {
+        let map = ColorMap::grayscale_scheme();
+        // 128/255 halfway to black: 64/255 exactly, where get_color truncates
+        assert!((map.get_color_f(0.25).r - 64.0 / 255.0).abs() < 1e-12);
+        assert_eq!(map.get_color(0.25), Color::new(64, 64, 64));
+        assert_eq!(map.get_color_f(0.0).to_color(), Color::black());
+        assert_eq!(map.get_color_f(1.0).to_color(), Color::white());
+        assert_eq!(ColorMap::new("Empty").get_color_f(0.5), ColorF::default());
+
+        // Rounding once instead of truncating removes the off-by-one drift
         let fire = ColorMap::fire_scheme();
-        assert_eq!(fire.name, "Fire");
+        for i in 0..=100 {
+            let t = i as f64 / 100.0;
+            let exact = fire.get_color_f(t).to_color();
+            assert!(exact.distance_rgb(&fire.get_color(t)) <= 3.0_f64.sqrt());
+        }
 
-        let ocean = ColorMap::ocean_scheme();
-        assert_eq!(ocean.name, "Ocean");
+        let mut hsv = ColorMap::with_stops(
+            "Hsv",
+            vec![
+                ColorStop::new(0.0, Color::new(255, 0, 0)),
+                ColorStop::new(1.0, Color::new(0, 0, 255)),
+            ],
+        );
+        hsv.interpolation = InterpolationMode::Hsv;
+        assert_eq!(hsv.get_color_f(0.5).to_color(), hsv.get_color(0.5));
+    }
 
-        let grayscale = ColorMap::grayscale_scheme();
-        assert_eq!(grayscale.name, "Grayscale");
+    #[test]
+    fn test_oklab_interpolation() {
+        let rgb = ColorMap::rainbow_scheme();
+        let mut oklab = rgb.clone();
+        oklab.interpolation = InterpolationMode::Oklab;
 
-        let rainbow = ColorMap::rainbow_scheme();
-        assert_eq!(rainbow.name, "Rainbow");
+        // Stops are unchanged; only the blends between them differ
+        for stop in &rgb.stops {
+            assert_eq!(oklab.get_color(stop.position), stop.color);
+        }
+
+        // Oklab spends the gradient evenly; RGB bunches changes together
+        let step_spread = |map: &ColorMap| {
+            let samples = map.to_palette(241);
+            let steps: Vec<f64> = samples
+                .windows(2)
+                .map(|w| {
+                    let (l1, a1, b1) = w[0].to_oklab();
+                    let (l2, a2, b2) = w[1].to_oklab();
+                    ((l2 - l1).powi(2) + (a2 - a1).powi(2) + (b2 - b1).powi(2)).sqrt()
+                })
+                .collect();
+            let mean = steps.iter().sum::<f64>() / steps.len() as f64;
+            (steps.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / steps.len() as f64).sqrt()
+                / mean
+        };
+        assert!(step_spread(&oklab) < step_spread(&rgb));
+
+        // Complementary stops meet in a muddy mid gray in RGB
+        let blue_yellow = ColorMap::with_stops(
+            "BlueYellow",
+            vec![
+                ColorStop::new(0.0, Color::BLUE),
+                ColorStop::new(1.0, Color::new(255, 255, 0)),
+            ],
+        );
+        let mid_rgb = blue_yellow.get_color(0.5);
+        let mut perceptual = blue_yellow.clone();
+        perceptual.interpolation = InterpolationMode::Oklab;
+        let mid_oklab = perceptual.get_color(0.5);
+        assert_eq!(mid_rgb.to_hsv().1, 0.0);
+        assert!(mid_oklab.to_oklab().0 > mid_rgb.to_oklab().0);
+        assert_eq!(perceptual.get_color_f(0.5).to_color(), mid_oklab);
+
+        let json = serde_json::to_string(&perceptual).unwrap();
+        assert!(json.contains(r#""interpolation":"Oklab""#));
     }
 
     #[test]
-    fn test_reversed() {
-        let mut map = ColorMap::new("RedToBlue");
-        map.add_stop(ColorStop::new(0.0, Color::new(255, 0, 0))); // Red at start
-        map.add_stop(ColorStop::new(0.5, Color::new(128, 128, 0))); // Yellow-ish mid
-        map.add_stop(ColorStop::new(1.0, Color::new(0, 0, 255))); // Blue at end
+    fn test_oklch_interpolation() {
+        let mut map = two_stop("RedBlue", Color::RED, Color::BLUE);
+        map.interpolation = InterpolationMode::Oklch;
+        let mid = map.get_color(0.5);
+        assert!(mid.g < mid.r.min(mid.b), "{:?}", mid);
+        assert_eq!(map.get_color_f(0.5).to_color(), mid);
+        assert_eq!(map.get_color(1.0), Color::BLUE);
 
-        let reversed = map.reversed();
-        
-        // Check name
-        assert_eq!(reversed.name, "RedToBlue (Reversed)");
-        
-        // Check number of stops
-        assert_eq!(reversed.stops.len(), 3);
-        
-        // Check that positions are flipped
-        assert_eq!(reversed.stops[0].position, 0.0);
-        assert_eq!(reversed.stops[1].position, 0.5);
-        assert_eq!(reversed.stops[2].position, 1.0);
-        
-        // Check that colors are in reverse order
-        // Original: Red(0.0) -> Yellow(0.5) -> Blue(1.0)
-        // Reversed: Blue(0.0) -> Yellow(0.5) -> Red(1.0)
-        assert_eq!(reversed.stops[0].color.b, 255); // Blue at start
-        assert_eq!(reversed.stops[2].color.r, 255); // Red at end
-        
-        // Check that getting color works correctly
-        let reversed_start = reversed.get_color(0.0);
-        let original_end = map.get_color(1.0);
-        assert_eq!(reversed_start.r, original_end.r);
-        assert_eq!(reversed_start.g, original_end.g);
-        assert_eq!(reversed_start.b, original_end.b);
+        map.interpolation = InterpolationMode::OklchLongArc;
+        let mid = map.get_color(0.5);
+        assert!(mid.g > mid.r.max(mid.b), "{:?}", mid);
+
+        let loaded: ColorMap = serde_json::from_str(&serde_json::to_string(&map).unwrap()).unwrap();
+        assert_eq!(loaded.interpolation, InterpolationMode::OklchLongArc);
+    }
+
+    #[test]
+    fn test_get_color16_ramp() {
+        let map = ColorMap::grayscale_scheme();
+        assert_eq!(map.get_color16(0.0), Color16::new(0, 0, 0));
+        assert_eq!(map.get_color16(1.0), Color16::new(65535, 65535, 65535));
+
+        let samples: Vec<u16> = (0..1000)
+            .map(|i| map.get_color16(i as f64 / 999.0).r)
+            .collect();
+        assert!(samples.windows(2).all(|w| w[0] < w[1]));
+
+        // The same ramp at 8 bits necessarily repeats values
+        let bytes: Vec<u8> = (0..1000)
+            .map(|i| map.get_color(i as f64 / 999.0).r)
+            .collect();
+        assert!(bytes.windows(2).any(|w| w[0] == w[1]));
     }
 }
@@ -15,6 +15,18 @@ pub enum ColorMapError {
     NoConfigDirectory,
     /// Invalid hex color string
     InvalidHexColor(String),
+    /// Color string that could not be parsed in any supported format
+    InvalidColor(String),
+    /// A component of a functional color string (e.g. `rgb(255, x, 0)`) that failed to parse
+    InvalidColorComponent { input: String, component: String },
+    /// Stop position that is NaN, infinite or outside `[0.0, 1.0]`
+    InvalidPosition(f64),
+    /// Structural problem with a colormap's stops
+    InvalidStops(String),
+    /// Unsupported colormap format version
+    VersionMismatch { found: u32, expected: u32 },
+    /// A colormap with this name already exists
+    DuplicateName(String),
 }
 
 impl std::fmt::Display for ColorMapError {
@@ -25,11 +37,37 @@ impl std::fmt::Display for ColorMapError {
             ColorMapError::NotFound(name) => write!(f, "ColorMap '{}' not found", name),
             ColorMapError::NoConfigDirectory => write!(f, "Could not find config directory"),
             ColorMapError::InvalidHexColor(hex) => write!(f, "Invalid hex color: '{}'", hex),
+            ColorMapError::InvalidColor(color) => write!(f, "Invalid color: '{}'", color),
+            ColorMapError::InvalidColorComponent { input, component } => {
+                write!(f, "Invalid component '{}' in color '{}'", component, input)
+            }
+            ColorMapError::InvalidPosition(position) => write!(
+                f,
+                "Invalid stop position {} (expected a finite number in [0.0, 1.0])",
+                position
+            ),
+            ColorMapError::InvalidStops(reason) => write!(f, "Invalid color stops: {}", reason),
+            ColorMapError::VersionMismatch { found, expected } => write!(
+                f,
+                "Unsupported colormap format version {} (expected {})",
+                found, expected
+            ),
+            ColorMapError::DuplicateName(name) => {
+                write!(f, "ColorMap '{}' already exists", name)
+            }
         }
     }
 }
 
-impl std::error::Error for ColorMapError {}
+impl std::error::Error for ColorMapError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ColorMapError::IoError(e) => Some(e),
+            ColorMapError::JsonError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 impl From<io::Error> for ColorMapError {
     fn from(err: io::Error) -> Self {
@@ -0,0 +1,640 @@
+//! Export of colormaps to other tools and formats
+//!
+//! - Gradient PNG images for textures, previews and documentation
+//!   (requires the `png` cargo feature)
+//! - Matplotlib colormap snippets for Python notebooks
+//! - `.cube` 1D and 3D LUTs for video color grading (requires the `cube`
+//!   cargo feature)
+//!
+//! # Usage
+//! ```
+//! let fire = scala_chromatica::io::load_builtin_colormap("Fire").unwrap();
+//! let python = fire.to_matplotlib_python();
+//! assert!(python.contains("LinearSegmentedColormap.from_list(\"Fire\""));
+//! ```
+//!
+//! ```
+//! # #[cfg(feature = "png")]
+//! # {
+//! use scala_chromatica::export::Orientation;
+//!
+//! let fire = scala_chromatica::io::load_builtin_colormap("Fire").unwrap();
+//! let bytes = fire.to_png_bytes(256, 16, Orientation::Horizontal).unwrap();
+//! assert!(bytes.starts_with(b"\x89PNG"));
+//! # }
+//! ```
+
+use crate::colormap::ColorMap;
+#[cfg(any(feature = "png", feature = "cube"))]
+use crate::error::Result;
+#[cfg(feature = "cube")]
+use crate::io::write_atomic;
+use std::fmt::Write as _;
+#[cfg(feature = "png")]
+use std::fs::File;
+#[cfg(feature = "png")]
+use std::io::BufWriter;
+#[cfg(any(feature = "png", feature = "cube"))]
+use std::io::{self, Write};
+#[cfg(any(feature = "png", feature = "cube"))]
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Direction along which a gradient image runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// Position 0.0 on the left, 1.0 on the right
+    Horizontal,
+    /// Position 0.0 at the top, 1.0 at the bottom
+    Vertical,
+}
+
+#[cfg(feature = "png")]
+impl ColorMap {
+    /// Write the colormap as an 8-bit RGB PNG gradient image
+    pub fn export_png(
+        &self,
+        width: usize,
+        height: usize,
+        orientation: Orientation,
+        path: &Path,
+    ) -> Result<()> {
+        let file = File::create(path)?;
+        self.write_png(width, height, orientation, BufWriter::new(file))
+    }
+
+    /// Encode the colormap as an 8-bit RGB PNG gradient image in memory
+    pub fn to_png_bytes(
+        &self,
+        width: usize,
+        height: usize,
+        orientation: Orientation,
+    ) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.write_png(width, height, orientation, &mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Render the gradient and encode it into `writer`
+    fn write_png<W: Write>(
+        &self,
+        width: usize,
+        height: usize,
+        orientation: Orientation,
+        mut writer: W,
+    ) -> Result<()> {
+        if width == 0 || height == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "image dimensions must be non-zero (got {}x{})",
+                    width, height
+                ),
+            )
+            .into());
+        }
+
+        let (png_width, png_height) = match (u32::try_from(width), u32::try_from(height)) {
+            (Ok(w), Ok(h)) => (w, h),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("image dimensions {}x{} are too large", width, height),
+                )
+                .into())
+            }
+        };
+
+        // One color per column or row, computed once
+        let steps = match orientation {
+            Orientation::Horizontal => width,
+            Orientation::Vertical => height,
+        };
+        let colors: Vec<_> = (0..steps)
+            .map(|i| {
+                let t = if steps > 1 {
+                    i as f64 / (steps - 1) as f64
+                } else {
+                    0.0
+                };
+                self.get_color(t)
+            })
+            .collect();
+
+        let mut data = Vec::with_capacity(width * height * 3);
+        for y in 0..height {
+            for x in 0..width {
+                let color = match orientation {
+                    Orientation::Horizontal => colors[x],
+                    Orientation::Vertical => colors[y],
+                };
+                data.extend_from_slice(&[color.r, color.g, color.b]);
+            }
+        }
+
+        let mut encoder = png::Encoder::new(&mut writer, png_width, png_height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut png_writer = encoder.write_header().map_err(io::Error::from)?;
+        png_writer
+            .write_image_data(&data)
+            .map_err(io::Error::from)?;
+        png_writer.finish().map_err(io::Error::from)?;
+
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Largest `LUT_1D_SIZE` allowed by the `.cube` specification
+#[cfg(feature = "cube")]
+const CUBE_MAX_1D_SIZE: u32 = 65_536;
+
+/// Largest `LUT_3D_SIZE` allowed by the `.cube` specification
+#[cfg(feature = "cube")]
+const CUBE_MAX_3D_SIZE: u32 = 256;
+
+#[cfg(feature = "cube")]
+impl ColorMap {
+    /// Write the colormap as a `.cube` 1D LUT, replacing `path` atomically
+    ///
+    /// Entry `i` holds the gradient color at `i / (lut_size - 1)`, so
+    /// grading software maps each input level through the colormap. See
+    /// [`ColorMap::to_cube_lut`] for the format details.
+    pub fn export_cube_lut(&self, path: &Path, lut_size: u32) -> Result<()> {
+        let cube = self.to_cube_lut(lut_size)?;
+        write_text(path, &cube)
+    }
+
+    /// Generate a `.cube` 1D LUT with `lut_size` entries as text
+    ///
+    /// The file has a `TITLE` with the colormap name, `LUT_1D_SIZE`, and one
+    /// row of normalized `R G B` floats per entry. `lut_size` must be
+    /// between 2 and 65536.
+    ///
+    /// # Examples
+    /// ```
+    /// let gray = scala_chromatica::ColorMap::grayscale_scheme();
+    /// let cube = gray.to_cube_lut(3).unwrap();
+    /// assert!(cube.contains("LUT_1D_SIZE 3\n"));
+    /// assert!(cube.ends_with("0.000000 0.000000 0.000000\n0.501961 0.501961 0.501961\n1.000000 1.000000 1.000000\n"));
+    /// ```
+    pub fn to_cube_lut(&self, lut_size: u32) -> Result<String> {
+        check_cube_size("LUT_1D_SIZE", lut_size, CUBE_MAX_1D_SIZE)?;
+
+        let mut out = cube_header(&self.name);
+        let _ = writeln!(out, "LUT_1D_SIZE {}", lut_size);
+        let _ = writeln!(out);
+        for i in 0..lut_size {
+            let color = self.get_color(cube_level(i, lut_size));
+            let _ = writeln!(
+                out,
+                "{:.6} {:.6} {:.6}",
+                color.r as f64 / 255.0,
+                color.g as f64 / 255.0,
+                color.b as f64 / 255.0
+            );
+        }
+        Ok(out)
+    }
+
+    /// Write a `.cube` 3D LUT where three gradients grade the channels,
+    /// replacing `path` atomically
+    ///
+    /// See [`ColorMap::to_cube_lut_3d`] for how the channels are built.
+    pub fn export_cube_lut_3d(
+        &self,
+        other_y: &ColorMap,
+        other_z: &ColorMap,
+        path: &Path,
+        size: u32,
+    ) -> Result<()> {
+        let cube = self.to_cube_lut_3d(other_y, other_z, size)?;
+        write_text(path, &cube)
+    }
+
+    /// Generate a `.cube` 3D LUT of `size`³ entries as text
+    ///
+    /// Each channel is processed independently: the output red comes from
+    /// this map's red channel at the input red level, green from
+    /// `other_y`'s green at the input green, and blue from `other_z`'s blue
+    /// at the input blue. Rows follow the `.cube` order, with red changing
+    /// fastest and blue slowest. `size` must be between 2 and 256.
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::ColorMap;
+    ///
+    /// let gray = ColorMap::grayscale_scheme();
+    /// let cube = gray.to_cube_lut_3d(&gray, &gray, 2).unwrap();
+    /// assert!(cube.contains("LUT_3D_SIZE 2\n"));
+    /// assert!(cube.contains("\n1.000000 0.000000 0.000000\n"));
+    /// ```
+    pub fn to_cube_lut_3d(
+        &self,
+        other_y: &ColorMap,
+        other_z: &ColorMap,
+        size: u32,
+    ) -> Result<String> {
+        check_cube_size("LUT_3D_SIZE", size, CUBE_MAX_3D_SIZE)?;
+
+        // Each channel only depends on its own input level
+        let curve = |map: &ColorMap, channel: fn(crate::color::Color) -> u8| -> Vec<f64> {
+            (0..size)
+                .map(|i| channel(map.get_color(cube_level(i, size))) as f64 / 255.0)
+                .collect()
+        };
+        let red = curve(self, |c| c.r);
+        let green = curve(other_y, |c| c.g);
+        let blue = curve(other_z, |c| c.b);
+
+        let title = format!("{} / {} / {}", self.name, other_y.name, other_z.name);
+        let mut out = cube_header(&title);
+        let _ = writeln!(out, "LUT_3D_SIZE {}", size);
+        let _ = writeln!(out);
+        for b in &blue {
+            for g in &green {
+                for r in &red {
+                    let _ = writeln!(out, "{:.6} {:.6} {:.6}", r, g, b);
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// Input level of LUT entry `i` out of `size`, from 0.0 to 1.0
+#[cfg(feature = "cube")]
+fn cube_level(i: u32, size: u32) -> f64 {
+    i as f64 / (size - 1) as f64
+}
+
+/// `TITLE` line shared by 1D and 3D LUTs
+///
+/// `.cube` titles can't contain double quotes, so they become single quotes.
+/// Control characters and Unicode line separators are dropped so a name
+/// can't end the line and start a keyword of its own.
+#[cfg(feature = "cube")]
+fn cube_header(title: &str) -> String {
+    let title: String = title
+        .chars()
+        .filter(|&c| !c.is_control() && c != '\u{2028}' && c != '\u{2029}')
+        .map(|c| if c == '"' { '\'' } else { c })
+        .collect();
+    format!("# Exported from scala-chromatica\nTITLE \"{}\"\n", title)
+}
+
+#[cfg(feature = "cube")]
+fn check_cube_size(keyword: &str, size: u32, max: u32) -> Result<()> {
+    if !(2..=max).contains(&size) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} must be between 2 and {} (got {})", keyword, max, size),
+        )
+        .into());
+    }
+    Ok(())
+}
+
+#[cfg(feature = "cube")]
+fn write_text(path: &Path, text: &str) -> Result<()> {
+    write_atomic(path, |file| Ok(file.write_all(text.as_bytes())?))
+}
+
+impl ColorMap {
+    /// Generate a Python snippet defining this colormap for Matplotlib
+    ///
+    /// The snippet imports `LinearSegmentedColormap` and ends by assigning
+    /// the colormap to `cmap`, ready to paste into a notebook. Matplotlib
+    /// requires anchors at 0.0 and 1.0, so the end colors are added when
+    /// the first or last stop sits inside the range.
+    pub fn to_matplotlib_python(&self) -> String {
+        let mut stops: Vec<(f64, crate::color::Color)> = self
+            .stops
+            .iter()
+            .map(|stop| (stop.position, stop.color))
+            .collect();
+        if stops.first().is_none_or(|(position, _)| *position > 0.0) {
+            stops.insert(0, (0.0, self.get_color(0.0)));
+        }
+        if stops.last().is_none_or(|(position, _)| *position < 1.0) {
+            stops.push((1.0, self.get_color(1.0)));
+        }
+
+        let name = python_string(&self.name);
+        let mut out = String::new();
+        // Writing to a String can't fail
+        let _ = writeln!(out, "# Colormap {} exported from scala-chromatica", name);
+        let _ = writeln!(out, "# Generated {}", utc_timestamp(SystemTime::now()));
+        let _ = writeln!(out, "from matplotlib.colors import LinearSegmentedColormap");
+        let _ = writeln!(out);
+        let _ = writeln!(out, "stops = [");
+        for (position, color) in &stops {
+            let _ = writeln!(
+                out,
+                "    ({:?}, ({}, {}, {})),",
+                position, color.r, color.g, color.b
+            );
+        }
+        let _ = writeln!(out, "]");
+        let _ = writeln!(
+            out,
+            "cmap = LinearSegmentedColormap.from_list({}, [(pos, (r / 255, g / 255, b / 255)) for pos, (r, g, b) in stops])",
+            name
+        );
+        out
+    }
+}
+
+/// Quote `s` as a double-quoted Python string literal
+///
+/// Control characters and Unicode line separators are escaped as well, so
+/// the result stays on one line when it's also used in a `#` comment.
+fn python_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() || c == '\u{2028}' || c == '\u{2029}' => {
+                let _ = write!(quoted, "\\u{:04x}", c as u32);
+            }
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Format a time as an ISO 8601 UTC timestamp, e.g. `2026-02-27T14:05:09Z`
+fn utc_timestamp(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        rem / 3_600,
+        rem % 3_600 / 60,
+        rem % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::Color;
+    use crate::colormap::ColorStop;
+    #[cfg(any(feature = "png", feature = "cube"))]
+    use crate::io::tests::temp_path;
+    use std::time::Duration;
+
+    #[cfg(feature = "png")]
+    fn decode(bytes: &[u8]) -> (u32, u32, Vec<u8>) {
+        let decoder = png::Decoder::new(io::Cursor::new(bytes));
+        let mut reader = decoder.read_info().unwrap();
+        let mut buf = vec![0; reader.output_buffer_size().unwrap()];
+        let info = reader.next_frame(&mut buf).unwrap();
+        buf.truncate(info.buffer_size());
+        (info.width, info.height, buf)
+    }
+
+    #[cfg(feature = "png")]
+    fn pixel(data: &[u8], width: u32, x: u32, y: u32) -> Color {
+        let i = ((y * width + x) * 3) as usize;
+        Color::new(data[i], data[i + 1], data[i + 2])
+    }
+
+    #[cfg(feature = "png")]
+    fn red_to_blue() -> ColorMap {
+        ColorMap::with_stops(
+            "RedToBlue",
+            vec![
+                ColorStop::new(0.0, Color::new(255, 0, 0)),
+                ColorStop::new(1.0, Color::new(0, 0, 255)),
+            ],
+        )
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn test_horizontal_png() {
+        let map = red_to_blue();
+        let bytes = map.to_png_bytes(5, 2, Orientation::Horizontal).unwrap();
+        let (width, height, data) = decode(&bytes);
+
+        assert_eq!((width, height), (5, 2));
+        for y in 0..2 {
+            assert_eq!(pixel(&data, width, 0, y), Color::new(255, 0, 0));
+            assert_eq!(pixel(&data, width, 2, y), map.get_color(0.5));
+            assert_eq!(pixel(&data, width, 4, y), Color::new(0, 0, 255));
+        }
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn test_vertical_png() {
+        let map = red_to_blue();
+        let bytes = map.to_png_bytes(3, 5, Orientation::Vertical).unwrap();
+        let (width, height, data) = decode(&bytes);
+
+        assert_eq!((width, height), (3, 5));
+        for x in 0..3 {
+            assert_eq!(pixel(&data, width, x, 0), Color::new(255, 0, 0));
+            assert_eq!(pixel(&data, width, x, 1), map.get_color(0.25));
+            assert_eq!(pixel(&data, width, x, 4), Color::new(0, 0, 255));
+        }
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn test_export_png_file() {
+        let map = red_to_blue();
+        let path = temp_path("scala_chromatica_export_test").with_extension("png");
+        map.export_png(8, 1, Orientation::Horizontal, &path)
+            .unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(
+            bytes,
+            map.to_png_bytes(8, 1, Orientation::Horizontal).unwrap()
+        );
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn test_empty_png_rejected() {
+        let map = red_to_blue();
+        assert!(map.to_png_bytes(0, 10, Orientation::Horizontal).is_err());
+        assert!(map.to_png_bytes(10, 0, Orientation::Vertical).is_err());
+    }
+
+    #[cfg(feature = "cube")]
+    #[test]
+    fn test_cube_lut_1d() {
+        let fire = ColorMap::fire_scheme();
+        let cube = fire.to_cube_lut(5).unwrap();
+        let lines: Vec<&str> = cube.lines().collect();
+
+        assert!(lines.contains(&"TITLE \"Fire\""));
+        assert!(lines.contains(&"LUT_1D_SIZE 5"));
+        let rows: Vec<&str> = lines
+            .iter()
+            .copied()
+            .filter(|line| line.starts_with(|c: char| c.is_ascii_digit()))
+            .collect();
+        assert_eq!(rows.len(), 5);
+        assert_eq!(rows[0], "0.000000 0.000000 0.000000");
+        assert_eq!(rows[4], "1.000000 1.000000 1.000000");
+        let mid = fire.get_color(0.5);
+        assert_eq!(
+            rows[2],
+            format!(
+                "{:.6} {:.6} {:.6}",
+                mid.r as f64 / 255.0,
+                mid.g as f64 / 255.0,
+                mid.b as f64 / 255.0
+            )
+        );
+
+        let path = temp_path("scala_chromatica_export_test").with_extension("cube");
+        fire.export_cube_lut(&path, 5).unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(written, cube);
+    }
+
+    #[cfg(feature = "cube")]
+    #[test]
+    fn test_cube_lut_3d() {
+        let fire = ColorMap::fire_scheme();
+        let ocean = ColorMap::ocean_scheme();
+        let gray = ColorMap::grayscale_scheme();
+        let cube = fire.to_cube_lut_3d(&ocean, &gray, 4).unwrap();
+        assert!(cube.contains("TITLE \"Fire / Ocean / Grayscale\"\n"));
+        assert!(cube.contains("LUT_3D_SIZE 4\n"));
+
+        let rows: Vec<Vec<f64>> = cube
+            .lines()
+            .filter(|line| line.starts_with(|c: char| c.is_ascii_digit()))
+            .map(|line| line.split(' ').map(|v| v.parse().unwrap()).collect())
+            .collect();
+        assert_eq!(rows.len(), 64);
+
+        // Red varies fastest, blue slowest, each through its own gradient
+        let level = |map: &ColorMap, i: usize| map.get_color(i as f64 / 3.0);
+        for (index, row) in rows.iter().enumerate() {
+            let (r, g, b) = (index % 4, index / 4 % 4, index / 16);
+            assert!((row[0] - level(&fire, r).r as f64 / 255.0).abs() < 1e-6);
+            assert!((row[1] - level(&ocean, g).g as f64 / 255.0).abs() < 1e-6);
+            assert!((row[2] - level(&gray, b).b as f64 / 255.0).abs() < 1e-6);
+        }
+    }
+
+    #[cfg(feature = "cube")]
+    #[test]
+    fn test_cube_lut_size_limits() {
+        let map = ColorMap::with_stops("Say \"hi\"", vec![ColorStop::new(0.0, Color::RED)]);
+        assert!(map.to_cube_lut(1).is_err());
+        assert!(map.to_cube_lut(65_537).is_err());
+        assert!(map.to_cube_lut(2).unwrap().contains("TITLE \"Say 'hi'\"\n"));
+
+        // A name can't inject extra keyword lines
+        let sneaky = ColorMap::with_stops(
+            "x\"\nLUT_1D_SIZE 2\r\u{2028}",
+            vec![ColorStop::new(0.0, Color::RED)],
+        );
+        let cube = sneaky.to_cube_lut(3).unwrap();
+        assert!(cube.contains("TITLE \"x'LUT_1D_SIZE 2\"\n"));
+        assert!(cube
+            .lines()
+            .filter(|l| l.starts_with("LUT_1D_SIZE"))
+            .eq(["LUT_1D_SIZE 3"]));
+        assert!(!cube.contains(['\r', '\u{2028}']));
+        assert!(map.to_cube_lut_3d(&map, &map, 0).is_err());
+        assert!(map.to_cube_lut_3d(&map, &map, 257).is_err());
+    }
+
+    #[test]
+    fn test_matplotlib_python() {
+        let python = ColorMap::fire_scheme().to_matplotlib_python();
+        let lines: Vec<&str> = python.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "# Colormap \"Fire\" exported from scala-chromatica"
+        );
+        assert!(lines[1].starts_with("# Generated ") && lines[1].ends_with('Z'));
+        assert!(python.contains("from matplotlib.colors import LinearSegmentedColormap\n"));
+        assert!(python.contains("    (0.0, (0, 0, 0)),\n"));
+        assert!(python.contains("    (0.25, (128, 0, 0)),\n"));
+        assert!(python.contains("    (1.0, (255, 255, 255)),\n]\n"));
+        assert_eq!(python.matches("),\n").count(), 6);
+        assert!(lines
+            .last()
+            .unwrap()
+            .starts_with("cmap = LinearSegmentedColormap.from_list(\"Fire\", [(pos, (r / 255"));
+    }
+
+    #[test]
+    fn test_matplotlib_python_anchors_and_quoting() {
+        let map = ColorMap::with_stops(
+            "Say \"hi\"",
+            vec![
+                ColorStop::new(0.2, Color::new(10, 20, 30)),
+                ColorStop::new(0.8, Color::new(40, 50, 60)),
+            ],
+        );
+        let python = map.to_matplotlib_python();
+
+        assert!(python.contains("from_list(\"Say \\\"hi\\\"\", "));
+        assert!(python.contains("stops = [\n    (0.0, (10, 20, 30)),\n    (0.2, (10, 20, 30)),\n"));
+        assert!(python.contains("    (0.8, (40, 50, 60)),\n    (1.0, (40, 50, 60)),\n]"));
+
+        // Line breaks in the name can't end the header comment early
+        let map = ColorMap::with_stops(
+            "a\nb\rc\u{2028}d\te\u{7}",
+            vec![ColorStop::new(0.0, Color::RED)],
+        );
+        let python = map.to_matplotlib_python();
+        let quoted = "\"a\\nb\\rc\\u2028d\\te\\u0007\"";
+        assert_eq!(
+            python.lines().next().unwrap(),
+            format!("# Colormap {} exported from scala-chromatica", quoted)
+        );
+        assert!(python.contains(&format!("from_list({}, ", quoted)));
+        assert!(!python.contains(['\r', '\u{2028}', '\t', '\u{7}']));
+    }
+
+    #[test]
+    fn test_utc_timestamp() {
+        assert_eq!(utc_timestamp(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+        let leap_day = UNIX_EPOCH + Duration::from_secs(1_709_210_096);
+        assert_eq!(utc_timestamp(leap_day), "2024-02-29T12:34:56Z");
+        let new_year = UNIX_EPOCH + Duration::from_secs(1_767_225_599);
+        assert_eq!(utc_timestamp(new_year), "2025-12-31T23:59:59Z");
+    }
+}
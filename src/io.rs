@@ -6,8 +6,11 @@
 //!
 //! # Architecture
 //! - Built-in colormaps are embedded in the binary using `include_str!`
-//! - Custom colormaps are stored in OS-appropriate config directories
+//! - Custom colormaps are stored in OS-appropriate config directories, or in
+//!   the directory named by `SCALA_CHROMATICA_COLORMAPS_DIR` when it is set
 //! - Automatic directory creation and error handling
+//! - Change notifications for custom colormaps with the `watch` feature
+//! - Compact binary `.scbin` files with the `bincode` feature
 //!
 //! # Usage
 //! ```
@@ -19,7 +22,7 @@
 //!
 //! // Create and save a custom colormap
 //! let custom = ColorMap::new("MyCustom");
-//! // io::save_colormap(&custom).unwrap(); // Commented - would write to disk
+//! // io::save_colormap_atomic(&custom).unwrap(); // Commented - would write to disk
 //!
 //! // Load a custom colormap
 //! // let custom = io::load_custom_colormap("MyCustom").unwrap();
@@ -28,12 +31,28 @@
 //! let all = io::list_available_colormaps().unwrap();
 //! ```
 
-use crate::colormap::ColorMap;
+use crate::color::Color;
+use crate::colormap::{ColorMap, ColorMapMetadata, ColorStop, Easing, InterpolationMode};
 use crate::error::{ColorMapError, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "bincode")]
+mod binary;
+#[cfg(feature = "watch")]
+mod watch;
+
+#[cfg(feature = "bincode")]
+pub use binary::{
+    load_colormap_binary, load_colormap_binary_from_reader, save_colormap_binary,
+    save_colormap_binary_to_writer, BINARY_FORMAT_VERSION, BINARY_MAGIC, BINARY_SIZE_LIMIT,
+};
+
+#[cfg(feature = "watch")]
+pub use watch::{watch_colormap_directory, WatchEvent, WatchHandle};
 
 /// Macro to define builtin colormaps with automatic list generation
 macro_rules! define_builtin_colormaps {
@@ -81,18 +100,66 @@ define_builtin_colormaps! {
     "Spring Meadow" => SPRING_MEADOW_COLORMAP_JSON => "colormaps/spring_meadow.json",
     "Egyptian Echo" => EGYPTIAN_ECHO_COLORMAP_JSON => "colormaps/egyptian_echo.json",
     "Copper Sheen" => COPPER_SHEEN_COLORMAP_JSON => "colormaps/copper_sheen.json",
+    "Viridis" => VIRIDIS_COLORMAP_JSON => "colormaps/viridis.json",
+    "Plasma" => PLASMA_COLORMAP_JSON => "colormaps/plasma.json",
+    "Magma" => MAGMA_COLORMAP_JSON => "colormaps/magma.json",
+    "Inferno" => INFERNO_COLORMAP_JSON => "colormaps/inferno.json",
 }
 
+/// Environment variable that overrides the custom colormaps directory
+///
+/// Useful in containers and CI, where colormaps are supplied from a mounted
+/// or checked-out directory instead of the user's config directory.
+pub const COLORMAPS_DIR_ENV: &str = "SCALA_CHROMATICA_COLORMAPS_DIR";
+
 /// Get the directory where custom colormaps are stored
 /// Returns platform-specific config directory:
 /// - Windows: %APPDATA%\scala-chromatica\colormaps\
 /// - Linux: ~/.config/scala-chromatica/colormaps/
 /// - macOS: ~/Library/Application Support/scala-chromatica/colormaps/
+///
+/// `SCALA_CHROMATICA_COLORMAPS_DIR` takes precedence when set; see
+/// [`get_effective_colormaps_directory`].
 pub fn get_colormaps_directory() -> Result<PathBuf> {
-    let base_dir = directories::ProjectDirs::from("", "", "scala-chromatica")
-        .ok_or(ColorMapError::NoConfigDirectory)?;
+    get_effective_colormaps_directory()
+}
+
+/// Resolve the custom colormaps directory, creating it if needed
+///
+/// Resolution order:
+/// 1. [`COLORMAPS_DIR_ENV`] (`SCALA_CHROMATICA_COLORMAPS_DIR`), if set and non-empty
+/// 2. The platform config directory from `directories::ProjectDirs`
+///
+/// Every function in this module that reads or writes custom colormaps
+/// uses this directory. [`list_available_colormaps`] also lists the
+/// platform directory when the variable points somewhere else.
+///
+/// A value that isn't valid UTF-8 is an [`io::ErrorKind::InvalidInput`]
+/// error rather than being ignored.
+pub fn get_effective_colormaps_directory() -> Result<PathBuf> {
+    let env_val = std::env::var_os(COLORMAPS_DIR_ENV);
+    get_colormaps_directory_with_env(colormaps_dir_env_str(env_val.as_deref())?)
+}
 
-    let colormaps_dir = base_dir.config_dir().join("colormaps");
+/// Check that the [`COLORMAPS_DIR_ENV`] value is valid UTF-8
+fn colormaps_dir_env_str(env_val: Option<&std::ffi::OsStr>) -> Result<Option<&str>> {
+    env_val
+        .map(|v| {
+            v.to_str().ok_or_else(|| {
+                ColorMapError::IoError(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{} is not valid UTF-8: {:?}", COLORMAPS_DIR_ENV, v),
+                ))
+            })
+        })
+        .transpose()
+}
+
+fn get_colormaps_directory_with_env(env_val: Option<&str>) -> Result<PathBuf> {
+    let colormaps_dir = match env_val.filter(|v| !v.is_empty()) {
+        Some(dir) => PathBuf::from(dir),
+        None => platform_colormaps_directory().ok_or(ColorMapError::NoConfigDirectory)?,
+    };
 
     // Create directory if it doesn't exist
     if !colormaps_dir.exists() {
@@ -102,6 +169,25 @@ pub fn get_colormaps_directory() -> Result<PathBuf> {
     Ok(colormaps_dir)
 }
 
+/// The platform config directory for custom colormaps, without creating it
+fn platform_colormaps_directory() -> Option<PathBuf> {
+    directories::ProjectDirs::from("", "", "scala-chromatica")
+        .map(|dirs| dirs.config_dir().join("colormaps"))
+}
+
+/// Directories [`list_available_colormaps`] scans, in order of precedence
+///
+/// The effective directory comes first. When [`COLORMAPS_DIR_ENV`] points
+/// elsewhere, an existing platform directory follows it.
+fn custom_colormap_directories_with_env(env_val: Option<&str>) -> Result<Vec<PathBuf>> {
+    let effective = get_colormaps_directory_with_env(env_val)?;
+    let platform = env_val
+        .filter(|v| !v.is_empty())
+        .and_then(|_| platform_colormaps_directory())
+        .filter(|dir| dir.is_dir() && *dir != effective);
+    Ok(std::iter::once(effective).chain(platform).collect())
+}
+
 /// Load a built-in colormap by name
 ///
 /// Available built-in colormaps:
@@ -109,6 +195,8 @@ pub fn get_colormaps_directory() -> Result<PathBuf> {
 /// - Academic, Twilight Garden, Coral Sunset
 /// - Olive Symmetry, Orchid Garden, Frozen Amaranth
 /// - Electric Neon, Cosmic Dawn, Vintage Lavender
+/// - Spring Meadow, Egyptian Echo, Copper Sheen
+/// - Viridis, Plasma, Magma, Inferno
 pub fn load_builtin_colormap(name: &str) -> Result<ColorMap> {
     let json_str =
         load_builtin_impl(name).ok_or_else(|| ColorMapError::NotFound(name.to_string()))?;
@@ -126,17 +214,93 @@ pub fn is_builtin_colormap(name: &str) -> bool {
 /// This will create a JSON file named "{colormap.name}.json"
 ///
 /// Returns the path to the saved file
+#[deprecated(
+    since = "0.1.4",
+    note = "use `save_colormap_atomic`, which it now calls"
+)]
 pub fn save_colormap(colormap: &ColorMap) -> Result<PathBuf> {
-    let dir = get_colormaps_directory()?;
-    let filename = format!("{}.json", colormap.name);
-    let filepath = dir.join(&filename);
+    save_colormap_atomic(colormap)
+}
 
-    let json = serde_json::to_string_pretty(colormap)?;
-    fs::write(&filepath, json)?;
+/// Save a colormap to the custom colormaps directory without risking a
+/// half-written file
+///
+/// The JSON is written to `{colormap.name}.json.tmp` next to the target,
+/// synced to disk, then renamed over `{colormap.name}.json`. A crash
+/// leaves either the old file or the new one, never a mix. The temporary
+/// file is removed if any step fails.
+///
+/// Returns the path to the saved file
+pub fn save_colormap_atomic(colormap: &ColorMap) -> Result<PathBuf> {
+    save_colormap_atomic_with_format(colormap, ColorFormat::Rgb)
+}
 
+/// Like [`save_colormap_atomic`], writing stop colors in the given [`ColorFormat`]
+pub fn save_colormap_atomic_with_format(
+    colormap: &ColorMap,
+    format: ColorFormat,
+) -> Result<PathBuf> {
+    let filepath = get_colormaps_directory()?.join(format!("{}.json", colormap.name));
+    write_atomic(&filepath, |file| {
+        save_colormap_to_writer_with_format(colormap, io::BufWriter::new(file), format)
+    })?;
     Ok(filepath)
 }
 
+/// Run `write` against a temporary sibling of `path`, then sync and rename
+/// it into place; the temporary file is removed on failure
+pub(crate) fn write_atomic(
+    path: &Path,
+    write: impl FnOnce(&mut fs::File) -> Result<()>,
+) -> Result<()> {
+    let tmp_path = temp_sibling(path);
+    let result = write_temp(&tmp_path, write).and_then(|()| Ok(fs::rename(&tmp_path, path)?));
+
+    if result.is_err() {
+        fs::remove_file(&tmp_path).ok();
+    }
+    result
+}
+
+/// Like [`write_atomic`], but fails with an `AlreadyExists` I/O error
+/// instead of replacing an existing `path`
+///
+/// The finished temporary file is hard-linked into place, which fails
+/// atomically if `path` appeared in the meantime, then removed.
+pub(crate) fn write_new_atomic(
+    path: &Path,
+    write: impl FnOnce(&mut fs::File) -> Result<()>,
+) -> Result<()> {
+    let tmp_path = temp_sibling(path);
+    let result = write_temp(&tmp_path, write).and_then(|()| {
+        fs::hard_link(&tmp_path, path).map_err(|e| match e.kind() {
+            io::ErrorKind::AlreadyExists => ColorMapError::IoError(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("'{}' already exists", path.display()),
+            )),
+            _ => e.into(),
+        })
+    });
+
+    fs::remove_file(&tmp_path).ok();
+    result
+}
+
+/// `{path}.tmp`, the temporary file the atomic writers fill first
+fn temp_sibling(path: &Path) -> PathBuf {
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".tmp");
+    path.with_file_name(tmp_name)
+}
+
+/// Create `tmp_path`, run `write` against it and sync it to disk
+fn write_temp(tmp_path: &Path, write: impl FnOnce(&mut fs::File) -> Result<()>) -> Result<()> {
+    let mut file = fs::File::create(tmp_path)?;
+    write(&mut file)?;
+    file.sync_all()?;
+    Ok(())
+}
+
 /// Load a custom colormap from the colormaps directory
 pub fn load_custom_colormap(name: &str) -> Result<ColorMap> {
     let dir = get_colormaps_directory()?;
@@ -147,12 +311,180 @@ pub fn load_custom_colormap(name: &str) -> Result<ColorMap> {
         return Err(ColorMapError::NotFound(name.to_string()));
     }
 
-    let json = fs::read_to_string(&filepath)?;
-    let colormap: ColorMap = serde_json::from_str(&json)?;
+    let file = fs::File::open(&filepath)?;
+    load_colormap_from_reader(io::BufReader::new(file))
+}
+
+/// Load a colormap from any JSON source (file, network stream, in-memory buffer)
+///
+/// Stops are sorted, and stops repeating a position (a common way to write
+/// hard edges) are moved [`ColorMap::JOIN_GAP`] apart. Files that still
+/// don't pass [`ColorMap::validate`] afterwards, other than ones without
+/// any stops, fail with [`ColorMapError::InvalidStops`].
+pub fn load_colormap_from_reader<R: io::Read>(reader: R) -> Result<ColorMap> {
+    let colormap: ColorMap = serde_json::from_reader(reader)?;
+    checked_colormap(colormap)
+}
+
+/// Parse a colormap from a JSON string; see [`load_colormap_from_reader`]
+pub fn load_colormap_from_str(s: &str) -> Result<ColorMap> {
+    let colormap: ColorMap = serde_json::from_str(s)?;
+    checked_colormap(colormap)
+}
 
+/// Normalize a freshly loaded colormap so it is safe to sample
+pub(crate) fn checked_colormap(mut colormap: ColorMap) -> Result<ColorMap> {
+    colormap.separate_hard_edges();
+    if !colormap.stops.is_empty() {
+        colormap.validate()?;
+    }
     Ok(colormap)
 }
 
+/// Load a colormap file, choosing the format from its extension
+///
+/// - `.json` is read with [`load_colormap_from_reader`]
+/// - `.ggr` is read with [`import_ggr`]
+/// - `.scbin` is read with `load_colormap_binary` (requires the `bincode`
+///   feature; without it these files fail with an
+///   [`io::ErrorKind::Unsupported`] I/O error)
+///
+/// Extensions are matched case-insensitively. Any other extension fails
+/// with an [`io::ErrorKind::InvalidInput`] I/O error.
+pub fn load_colormap_auto(path: &Path) -> Result<ColorMap> {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase);
+
+    match extension.as_deref() {
+        Some("json") => load_colormap_from_reader(io::BufReader::new(fs::File::open(path)?)),
+        Some("ggr") => import_ggr(path),
+        #[cfg(feature = "bincode")]
+        Some("scbin") => load_colormap_binary(path),
+        #[cfg(not(feature = "bincode"))]
+        Some("scbin") => Err(ColorMapError::IoError(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "binary colormaps require the `bincode` feature",
+        ))),
+        _ => Err(ColorMapError::IoError(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("unrecognized colormap file extension: {}", path.display()),
+        ))),
+    }
+}
+
+/// How stop colors are written when saving a colormap
+///
+/// Loading always accepts both forms, even mixed within one file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorFormat {
+    /// `{"r": 255, "g": 87, "b": 51}` objects, as the built-in colormaps use
+    #[default]
+    Rgb,
+    /// `"#FF5733"` strings, easier to read and edit by hand
+    Hex,
+}
+
+/// Serialization view of a [`ColorMap`] with hex stop colors
+#[derive(Serialize)]
+struct HexColorMap<'a> {
+    name: &'a str,
+    stops: Vec<HexColorStop<'a>>,
+    #[serde(skip_serializing_if = "ColorMapMetadata::is_empty")]
+    metadata: ColorMapMetadata,
+    #[serde(skip_serializing_if = "InterpolationMode::is_default")]
+    interpolation: InterpolationMode,
+}
+
+#[derive(Serialize)]
+struct HexColorStop<'a> {
+    position: f64,
+    #[serde(with = "crate::color::serde_hex")]
+    color: Color,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    midpoint: Option<f64>,
+    #[serde(skip_serializing_if = "Easing::is_linear")]
+    easing: Easing,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    alpha: Option<u8>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    metadata: &'a BTreeMap<String, String>,
+}
+
+impl<'a> HexColorMap<'a> {
+    fn new(colormap: &'a ColorMap) -> Self {
+        Self {
+            name: &colormap.name,
+            stops: colormap
+                .stops
+                .iter()
+                .map(|stop| HexColorStop {
+                    position: stop.position,
+                    color: stop.color,
+                    name: stop.name.as_deref(),
+                    midpoint: stop.midpoint,
+                    easing: stop.easing,
+                    alpha: stop.alpha,
+                    metadata: &stop.metadata,
+                })
+                .collect(),
+            metadata: colormap.metadata.clone(),
+            interpolation: colormap.interpolation,
+        }
+    }
+}
+
+/// Serialize a colormap to a pretty-printed JSON string
+pub fn save_colormap_to_string(colormap: &ColorMap) -> Result<String> {
+    save_colormap_to_string_with_format(colormap, ColorFormat::Rgb)
+}
+
+/// Serialize a colormap to a pretty-printed JSON string, writing stop
+/// colors in the given [`ColorFormat`]
+///
+/// # Examples
+/// ```
+/// use scala_chromatica::io::{self, ColorFormat};
+/// use scala_chromatica::ColorMap;
+///
+/// let json = io::save_colormap_to_string_with_format(&ColorMap::fire_scheme(), ColorFormat::Hex).unwrap();
+/// assert!(json.contains(r##""color": "#000000""##));
+/// assert_eq!(io::load_colormap_from_str(&json).unwrap().stops, ColorMap::fire_scheme().stops);
+/// ```
+pub fn save_colormap_to_string_with_format(
+    colormap: &ColorMap,
+    format: ColorFormat,
+) -> Result<String> {
+    let json = match format {
+        ColorFormat::Rgb => serde_json::to_string_pretty(colormap)?,
+        ColorFormat::Hex => serde_json::to_string_pretty(&HexColorMap::new(colormap))?,
+    };
+    Ok(json)
+}
+
+/// Save a colormap as pretty-printed JSON to any writer
+pub fn save_colormap_to_writer<W: io::Write>(colormap: &ColorMap, writer: W) -> Result<()> {
+    save_colormap_to_writer_with_format(colormap, writer, ColorFormat::Rgb)
+}
+
+/// Save a colormap as pretty-printed JSON to any writer, writing stop
+/// colors in the given [`ColorFormat`]
+pub fn save_colormap_to_writer_with_format<W: io::Write>(
+    colormap: &ColorMap,
+    mut writer: W,
+    format: ColorFormat,
+) -> Result<()> {
+    match format {
+        ColorFormat::Rgb => serde_json::to_writer_pretty(&mut writer, colormap)?,
+        ColorFormat::Hex => serde_json::to_writer_pretty(&mut writer, &HexColorMap::new(colormap))?,
+    }
+    writer.flush()?;
+    Ok(())
+}
+
 /// Load a colormap by name, checking built-ins first, then custom colormaps
 pub fn load_colormap(name: &str) -> Result<ColorMap> {
     // Try built-in first
@@ -186,42 +518,151 @@ pub fn delete_custom_colormap(name: &str) -> Result<()> {
     Ok(())
 }
 
+/// Rename a custom colormap, updating both the file name and the stored name
+///
+/// Fails with [`ColorMapError::NotFound`] if `old_name` doesn't exist and with
+/// [`ColorMapError::DuplicateName`] if another colormap already uses `new_name`.
+/// Built-in colormaps can neither be renamed nor replaced.
+pub fn rename_colormap(old_name: &str, new_name: &str) -> Result<()> {
+    rename_colormap_in(&get_colormaps_directory()?, old_name, new_name)
+}
+
+/// Copy a colormap (built-in or custom) to a new custom colormap named `new_name`
+///
+/// Returns the path of the new file. Fails with [`ColorMapError::NotFound`]
+/// if the source doesn't exist and with [`ColorMapError::DuplicateName`] if
+/// a custom colormap named `new_name` already exists. Built-in names cannot
+/// be used as the target.
+pub fn copy_colormap(source_name: &str, new_name: &str) -> Result<PathBuf> {
+    copy_colormap_in(&get_colormaps_directory()?, source_name, new_name)
+}
+
+fn rename_colormap_in(dir: &Path, old_name: &str, new_name: &str) -> Result<()> {
+    if is_builtin_colormap(old_name) {
+        return Err(ColorMapError::IoError(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            "Cannot rename built-in colormaps",
+        )));
+    }
+    check_copy_target(dir, new_name)?;
+
+    let old_path = dir.join(format!("{}.json", old_name));
+    if !old_path.exists() {
+        return Err(ColorMapError::NotFound(old_name.to_string()));
+    }
+
+    let mut colormap = load_colormap_from_reader(io::BufReader::new(fs::File::open(&old_path)?))?;
+    colormap.name = new_name.to_string();
+
+    let new_path = dir.join(format!("{}.json", new_name));
+    save_colormap_to_writer(&colormap, io::BufWriter::new(fs::File::create(&new_path)?))?;
+    fs::remove_file(&old_path)?;
+    Ok(())
+}
+
+fn copy_colormap_in(dir: &Path, source_name: &str, new_name: &str) -> Result<PathBuf> {
+    check_copy_target(dir, new_name)?;
+
+    let mut colormap = if is_builtin_colormap(source_name) {
+        load_builtin_colormap(source_name)?
+    } else {
+        let source_path = dir.join(format!("{}.json", source_name));
+        if !source_path.exists() {
+            return Err(ColorMapError::NotFound(source_name.to_string()));
+        }
+        load_colormap_from_reader(io::BufReader::new(fs::File::open(&source_path)?))?
+    };
+    colormap.name = new_name.to_string();
+
+    let new_path = dir.join(format!("{}.json", new_name));
+    save_colormap_to_writer(&colormap, io::BufWriter::new(fs::File::create(&new_path)?))?;
+    Ok(new_path)
+}
+
+/// Refuse targets that would shadow a built-in or overwrite an existing file
+fn check_copy_target(dir: &Path, new_name: &str) -> Result<()> {
+    if is_builtin_colormap(new_name) {
+        return Err(ColorMapError::IoError(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            "Cannot overwrite built-in colormaps",
+        )));
+    }
+    if dir.join(format!("{}.json", new_name)).exists() {
+        return Err(ColorMapError::DuplicateName(new_name.to_string()));
+    }
+    Ok(())
+}
+
 /// Information about an available colormap
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ColorMapInfo {
     pub name: String,
     pub is_builtin: bool,
     pub filepath: Option<PathBuf>,
+    pub metadata: ColorMapMetadata,
 }
 
 /// List all available colormaps (built-in + custom)
+///
+/// Custom colormaps come from the effective directory (see
+/// [`get_effective_colormaps_directory`]). When [`COLORMAPS_DIR_ENV`] is
+/// set, the platform config directory is scanned too, after it, so a name
+/// found in both is listed once with the environment directory's file.
+/// Entries from the platform directory can be loaded through their
+/// `filepath`, since [`load_custom_colormap`] only looks in the effective
+/// directory.
 pub fn list_available_colormaps() -> Result<Vec<ColorMapInfo>> {
+    let env_val = std::env::var_os(COLORMAPS_DIR_ENV);
+    let dirs = custom_colormap_directories_with_env(colormaps_dir_env_str(env_val.as_deref())?)?;
+    list_colormaps_in(&dirs)
+}
+
+/// List the built-ins followed by the custom colormaps in `dirs`
+///
+/// Names already listed, from a built-in or an earlier directory, are skipped.
+fn list_colormaps_in(dirs: &[PathBuf]) -> Result<Vec<ColorMapInfo>> {
     let mut colormaps = Vec::new();
 
     // Add built-in colormaps
     for name in get_builtin_colormap_names() {
+        let metadata = load_builtin_colormap(name)
+            .map(|colormap| colormap.metadata)
+            .unwrap_or_default();
         colormaps.push(ColorMapInfo {
             name: name.to_string(),
             is_builtin: true,
             filepath: None,
+            metadata,
         });
     }
 
     // Add custom colormaps
-    let dir = get_colormaps_directory()?;
-    if dir.exists() {
-        for entry in fs::read_dir(&dir)? {
+    for dir in dirs.iter().filter(|dir| dir.exists()) {
+        for entry in fs::read_dir(dir)? {
             let entry = entry?;
             let path = entry.path();
 
             if path.extension().and_then(|s| s.to_str()) == Some("json") {
                 if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
                     // Skip if it has the same name as a built-in (built-ins take precedence)
-                    if !is_builtin_colormap(stem) {
+                    // or was already found in an earlier directory
+                    let listed = colormaps
+                        .iter()
+                        .any(|info| !info.is_builtin && info.name == stem);
+                    if !is_builtin_colormap(stem) && !listed {
+                        // Unreadable files are still listed, just without metadata
+                        let metadata = fs::File::open(&path)
+                            .ok()
+                            .and_then(|file| {
+                                load_colormap_from_reader(io::BufReader::new(file)).ok()
+                            })
+                            .map(|colormap| colormap.metadata)
+                            .unwrap_or_default();
                         colormaps.push(ColorMapInfo {
                             name: stem.to_string(),
                             is_builtin: false,
                             filepath: Some(path),
+                            metadata,
                         });
                     }
                 }
@@ -236,13 +677,266 @@ pub fn list_available_colormaps() -> Result<Vec<ColorMapInfo>> {
 /// This allows users to create modified versions of built-in colormaps
 pub fn export_builtin_colormap(name: &str) -> Result<PathBuf> {
     let colormap = load_builtin_colormap(name)?;
-    save_colormap(&colormap)
+    save_colormap_atomic(&colormap)
+}
+
+/// Export a built-in colormap as JSON to an explicit location
+///
+/// `path` is the file to write; if it names an existing directory the
+/// colormap is written to `{name}.json` inside it. Refuses to replace an
+/// existing file, including one created while the export runs, failing
+/// with an `AlreadyExists` I/O error; use
+/// [`export_builtin_colormap_to_path_overwrite`] for that. Returns the path
+/// written.
+///
+/// # Examples
+/// ```no_run
+/// use scala_chromatica::io;
+/// use std::path::Path;
+///
+/// io::export_builtin_colormap_to_path("Fire", Path::new("./assets/fire.json")).unwrap();
+/// ```
+pub fn export_builtin_colormap_to_path(name: &str, path: &Path) -> Result<PathBuf> {
+    export_builtin_colormap_to(name, path, false)
+}
+
+/// Like [`export_builtin_colormap_to_path`], but replaces an existing file
+pub fn export_builtin_colormap_to_path_overwrite(name: &str, path: &Path) -> Result<PathBuf> {
+    export_builtin_colormap_to(name, path, true)
+}
+
+fn export_builtin_colormap_to(name: &str, path: &Path, overwrite: bool) -> Result<PathBuf> {
+    let colormap = load_builtin_colormap(name)?;
+    let filepath = if path.is_dir() {
+        path.join(format!("{}.json", colormap.name))
+    } else {
+        path.to_path_buf()
+    };
+
+    let write = |file: &mut fs::File| save_colormap_to_writer(&colormap, io::BufWriter::new(file));
+    if overwrite {
+        write_atomic(&filepath, write)?;
+    } else {
+        write_new_atomic(&filepath, write)?;
+    }
+    Ok(filepath)
+}
+
+/// Outcome of [`import_colormaps_from_directory`]
+#[derive(Debug)]
+pub struct DirectoryImport {
+    /// Colormaps that loaded successfully
+    pub colormaps: Vec<ColorMap>,
+    /// Files that could not be read or parsed, with the reason
+    pub failures: Vec<(PathBuf, ColorMapError)>,
+}
+
+/// Load every `*.json` colormap file in `path` (non-recursive)
+///
+/// Files are visited in path order. A file that fails to load doesn't stop
+/// the import; it is reported in [`DirectoryImport::failures`] instead. Only
+/// problems reading the directory itself are returned as an error.
+pub fn import_colormaps_from_directory(path: &Path) -> Result<DirectoryImport> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(path)? {
+        let file_path = entry?.path();
+        if file_path.is_file() && file_path.extension().and_then(|s| s.to_str()) == Some("json") {
+            files.push(file_path);
+        }
+    }
+    files.sort();
+
+    let mut import = DirectoryImport {
+        colormaps: Vec::new(),
+        failures: Vec::new(),
+    };
+    for file_path in files {
+        let loaded = fs::File::open(&file_path)
+            .map_err(ColorMapError::from)
+            .and_then(|file| load_colormap_from_reader(io::BufReader::new(file)));
+        match loaded {
+            Ok(colormap) => import.colormaps.push(colormap),
+            Err(err) => import.failures.push((file_path, err)),
+        }
+    }
+
+    Ok(import)
+}
+
+/// Save each colormap as `<name>.json` in `path`, creating the directory if needed
+///
+/// Returns the written file paths in the same order as `maps`. Fails with
+/// [`ColorMapError::DuplicateName`] before writing anything if two colormaps
+/// share a name.
+pub fn export_colormaps_to_directory(maps: &[ColorMap], path: &Path) -> Result<Vec<PathBuf>> {
+    for (i, colormap) in maps.iter().enumerate() {
+        if maps[..i].iter().any(|other| other.name == colormap.name) {
+            return Err(ColorMapError::DuplicateName(colormap.name.clone()));
+        }
+    }
+
+    fs::create_dir_all(path)?;
+
+    let mut written = Vec::with_capacity(maps.len());
+    for colormap in maps {
+        let filepath = path.join(format!("{}.json", colormap.name));
+        let file = fs::File::create(&filepath)?;
+        save_colormap_to_writer(colormap, io::BufWriter::new(file))?;
+        written.push(filepath);
+    }
+
+    Ok(written)
+}
+
+/// Export a colormap to a GIMP gradient (`.ggr`) file
+///
+/// Each pair of adjacent stops becomes one linear RGB segment whose
+/// midpoint comes from the first stop's [`ColorStop::midpoint`] (halfway
+/// between the two stop positions if unset). Colors are written as
+/// normalized RGBA floats with full opacity.
+pub fn export_ggr(colormap: &ColorMap, path: &Path) -> Result<()> {
+    let mut segments = Vec::new();
+
+    match colormap.stops.len() {
+        0 | 1 => {
+            // A gradient needs at least one segment, so stretch the single color across it
+            let color = colormap.get_color(0.0);
+            segments.push((0.0, 0.5, 1.0, color, color));
+        }
+        _ => {
+            for pair in colormap.stops.windows(2) {
+                let (left, right) = (pair[0].position, pair[1].position);
+                let midpoint = pair[0]
+                    .midpoint
+                    .unwrap_or(0.5)
+                    .clamp(ColorStop::MIN_MIDPOINT, ColorStop::MAX_MIDPOINT);
+                segments.push((
+                    left,
+                    left + (right - left) * midpoint,
+                    right,
+                    pair[0].color,
+                    pair[1].color,
+                ));
+            }
+        }
+    }
+
+    let mut ggr = String::from("GIMP Gradient\n");
+    ggr.push_str(&format!("Name: {}\n", colormap.name));
+    ggr.push_str(&format!("{}\n", segments.len()));
+
+    for (left, middle, right, left_color, right_color) in segments {
+        ggr.push_str(&format!(
+            "{:.6} {:.6} {:.6} {:.6} {:.6} {:.6} 1.000000 {:.6} {:.6} {:.6} 1.000000 0 0\n",
+            left,
+            middle,
+            right,
+            left_color.r as f64 / 255.0,
+            left_color.g as f64 / 255.0,
+            left_color.b as f64 / 255.0,
+            right_color.r as f64 / 255.0,
+            right_color.g as f64 / 255.0,
+            right_color.b as f64 / 255.0,
+        ));
+    }
+
+    fs::write(path, ggr)?;
+    Ok(())
+}
+
+/// Import a colormap from a GIMP gradient (`.ggr`) file
+///
+/// Segment endpoints become color stops. Where two segments meet with
+/// different colors, both colors are kept as a hard edge, with the right
+/// stop [`ColorMap::JOIN_GAP`] after the left one. Off-center segment
+/// midpoints are kept as [`ColorStop::midpoint`]; blending functions and
+/// alpha are ignored.
+pub fn import_ggr(path: &Path) -> Result<ColorMap> {
+    let contents = fs::read_to_string(path)?;
+    let mut lines = contents.lines();
+
+    if lines.next().map(str::trim) != Some("GIMP Gradient") {
+        return Err(invalid_ggr("missing 'GIMP Gradient' header"));
+    }
+
+    let mut line = lines
+        .next()
+        .ok_or_else(|| invalid_ggr("missing segment count"))?;
+    let mut name = String::from("Imported Gradient");
+    if let Some(stripped) = line.strip_prefix("Name:") {
+        name = stripped.trim().to_string();
+        line = lines
+            .next()
+            .ok_or_else(|| invalid_ggr("missing segment count"))?;
+    }
+
+    let count: usize = line
+        .trim()
+        .parse()
+        .map_err(|_| invalid_ggr("invalid segment count"))?;
+
+    let mut colormap = ColorMap::new(name);
+    for _ in 0..count {
+        let line = lines.next().ok_or_else(|| invalid_ggr("missing segment"))?;
+        let values = line
+            .split_whitespace()
+            .take(11)
+            .map(|v| v.parse::<f64>())
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|_| invalid_ggr("invalid segment value"))?;
+
+        if values.len() < 11 {
+            return Err(invalid_ggr("segment has too few values"));
+        }
+
+        let to_color = |r: f64, g: f64, b: f64| {
+            Color::new(
+                (r.clamp(0.0, 1.0) * 255.0).round() as u8,
+                (g.clamp(0.0, 1.0) * 255.0).round() as u8,
+                (b.clamp(0.0, 1.0) * 255.0).round() as u8,
+            )
+        };
+        let left = ColorStop::new(values[0], to_color(values[3], values[4], values[5]));
+        let right = ColorStop::new(values[2], to_color(values[7], values[8], values[9]));
+        let width = values[2] - values[0];
+        let midpoint = if width > 0.0 {
+            (values[1] - values[0]) / width
+        } else {
+            0.5
+        };
+
+        if colormap.stops.last() != Some(&left) {
+            colormap.stops.push(left);
+        }
+        if (midpoint - 0.5).abs() > 1e-6 {
+            if let Some(start) = colormap.stops.last_mut() {
+                start.midpoint = Some(midpoint);
+            }
+        }
+        colormap.stops.push(right);
+    }
+
+    checked_colormap(colormap)
+}
+
+/// Build the error returned for malformed `.ggr` files
+fn invalid_ggr(message: &str) -> ColorMapError {
+    ColorMapError::IoError(io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("Invalid GIMP gradient: {}", message),
+    ))
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
 
+    /// Path in the temp directory, suffixed with the process id so
+    /// concurrent test runs don't share fixtures
+    pub(crate) fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("{}_{}", name, std::process::id()))
+    }
+
     #[test]
     fn test_load_builtin_colormaps() {
         // Test loading each built-in colormap
@@ -257,6 +951,10 @@ mod tests {
             "Coral Sunset",
             "Olive Symmetry",
             "Orchid Garden",
+            "Viridis",
+            "Plasma",
+            "Magma",
+            "Inferno",
         ] {
             let result = load_builtin_colormap(name);
             assert!(result.is_ok(), "Failed to load {}: {:?}", name, result);
@@ -267,6 +965,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_builtin_json_matches_hsv_constructors() {
+        // The HSV-built schemes must agree with their embedded JSON,
+        // including Rainbow's closing stop at a hue of 360
+        for expected in [ColorMap::default_scheme(), ColorMap::rainbow_scheme()] {
+            let loaded = load_builtin_colormap(&expected.name).unwrap();
+            let colors = |map: &ColorMap| -> Vec<_> {
+                map.stops.iter().map(|s| (s.position, s.color)).collect()
+            };
+            assert_eq!(colors(&loaded), colors(&expected), "{}", expected.name);
+        }
+    }
+
     #[test]
     fn test_load_nonexistent_builtin() {
         let result = load_builtin_colormap("NonExistent");
@@ -282,4 +993,561 @@ mod tests {
         assert!(!is_builtin_colormap("MyCustom"));
         assert!(!is_builtin_colormap(""));
     }
+
+    #[test]
+    fn test_load_colormap_auto() {
+        let dir = fixture_directory("scala_chromatica_auto_load_test");
+        let fire = ColorMap::fire_scheme();
+
+        let json = dir.join("Fire.JSON");
+        fs::write(&json, save_colormap_to_string(&fire).unwrap()).unwrap();
+        assert_eq!(load_colormap_auto(&json).unwrap().stops, fire.stops);
+
+        let ggr = dir.join("Fire.ggr");
+        export_ggr(&fire, &ggr).unwrap();
+        assert_eq!(load_colormap_auto(&ggr).unwrap().name, "Fire");
+
+        let binary = dir.join("Fire.scbin");
+        #[cfg(feature = "bincode")]
+        {
+            save_colormap_binary(&fire, &binary).unwrap();
+            assert_eq!(load_colormap_auto(&binary).unwrap().stops, fire.stops);
+        }
+        #[cfg(not(feature = "bincode"))]
+        {
+            fs::write(&binary, b"SCBN").unwrap();
+            assert!(matches!(
+                load_colormap_auto(&binary),
+                Err(ColorMapError::IoError(e)) if e.kind() == io::ErrorKind::Unsupported
+            ));
+        }
+
+        for name in ["Fire.txt", "Fire"] {
+            let path = dir.join(name);
+            fs::write(&path, "").unwrap();
+            assert!(matches!(
+                load_colormap_auto(&path),
+                Err(ColorMapError::IoError(e)) if e.kind() == io::ErrorKind::InvalidInput
+            ));
+        }
+        assert!(load_colormap_auto(&dir.join("Missing.json")).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_ggr_roundtrip() {
+        let original = load_builtin_colormap("Fire").unwrap();
+        let path = temp_path("scala_chromatica_test_roundtrip").with_extension("ggr");
+
+        export_ggr(&original, &path).unwrap();
+        let imported = import_ggr(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(imported.name, original.name);
+        assert_eq!(imported.stops.len(), original.stops.len());
+        for (a, b) in imported.stops.iter().zip(&original.stops) {
+            assert!((a.position - b.position).abs() < 1e-6);
+            assert_eq!(a.color, b.color);
+        }
+    }
+
+    #[test]
+    fn test_ggr_hard_edge_roundtrip() {
+        let dir = fixture_directory("scala_chromatica_ggr_hard_edge_test");
+        let path = dir.join("edge.ggr");
+        fs::write(
+            &path,
+            "GIMP Gradient\nName: Edge\n3\n\
+             0.000000 0.250000 0.500000 0.000000 0.000000 0.000000 1.000000 1.000000 1.000000 1.000000 1.000000 0 0\n\
+             0.500000 0.500000 0.500000 1.000000 1.000000 1.000000 1.000000 1.000000 0.000000 0.000000 1.000000 0 0\n\
+             0.500000 0.750000 1.000000 1.000000 0.000000 0.000000 1.000000 0.000000 0.000000 1.000000 1.000000 0 0\n",
+        )
+        .unwrap();
+
+        let imported = import_ggr(&path).unwrap();
+        assert!(imported.is_valid());
+        assert_eq!(imported.stops.len(), 4);
+        assert_eq!(imported.stops[1].position, 0.5);
+        assert_eq!(imported.stops[2].position, 0.5 + ColorMap::JOIN_GAP);
+        assert_eq!(imported.get_color(0.5), Color::WHITE);
+        assert_eq!(imported.get_color(0.5 + ColorMap::JOIN_GAP), Color::RED);
+        assert_eq!(imported.get_color(1.0), Color::BLUE);
+
+        // Exporting writes the edge as a zero-width segment, which imports again
+        export_ggr(&imported, &path).unwrap();
+        let again = import_ggr(&path).unwrap();
+        fs::remove_dir_all(&dir).ok();
+        assert!(again.is_valid());
+        for (a, b) in again.stops.iter().zip(&imported.stops) {
+            assert!((a.position - b.position).abs() < 1e-6);
+            assert_eq!(a.color, b.color);
+        }
+        assert_eq!(again.stops.len(), imported.stops.len());
+    }
+
+    #[test]
+    fn test_ggr_midpoints() {
+        let mut original = ColorMap::equidistant(
+            "Skewed",
+            vec![Color::BLACK, Color::WHITE, Color::new(255, 0, 0)],
+        );
+        original.stops[0].midpoint = Some(0.25);
+        let path = temp_path("scala_chromatica_test_midpoints").with_extension("ggr");
+
+        export_ggr(&original, &path).unwrap();
+        let ggr = fs::read_to_string(&path).unwrap();
+        assert!(ggr.contains("0.000000 0.125000 0.500000"));
+        assert!(ggr.contains("0.500000 0.750000 1.000000"));
+
+        let imported = import_ggr(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert!((imported.stops[0].midpoint.unwrap() - 0.25).abs() < 1e-6);
+        assert_eq!(imported.stops[1].midpoint, None);
+        assert_eq!(imported.get_color(0.125), original.get_color(0.125));
+    }
+
+    #[test]
+    fn test_reader_writer_roundtrip() {
+        let original = load_builtin_colormap("Ocean").unwrap();
+
+        let mut buffer = io::Cursor::new(Vec::new());
+        save_colormap_to_writer(&original, &mut buffer).unwrap();
+
+        buffer.set_position(0);
+        let loaded = load_colormap_from_reader(buffer).unwrap();
+
+        assert_eq!(loaded.name, original.name);
+        assert_eq!(loaded.stops, original.stops);
+    }
+
+    #[test]
+    fn test_load_from_reader_invalid_json() {
+        let result = load_colormap_from_reader(io::Cursor::new(b"not json".to_vec()));
+        assert!(matches!(result, Err(ColorMapError::JsonError(_))));
+    }
+
+    #[test]
+    fn test_string_roundtrip() {
+        let original = load_builtin_colormap("Academic").unwrap();
+
+        let json = save_colormap_to_string(&original).unwrap();
+        let loaded = load_colormap_from_str(&json).unwrap();
+
+        assert_eq!(loaded.name, original.name);
+        assert_eq!(loaded.stops, original.stops);
+        assert!(load_colormap_from_str("{").is_err());
+    }
+
+    #[test]
+    fn test_directory_round_trip() {
+        let dir = temp_path("scala_chromatica_directory_test");
+        fs::remove_dir_all(&dir).ok();
+
+        let maps = vec![ColorMap::fire_scheme(), ColorMap::ocean_scheme()];
+        let written = export_colormaps_to_directory(&maps, &dir).unwrap();
+        assert_eq!(written, vec![dir.join("Fire.json"), dir.join("Ocean.json")]);
+
+        // Broken and non-JSON files are reported or ignored, not fatal
+        fs::write(dir.join("Broken.json"), "{ not json").unwrap();
+        fs::write(dir.join("notes.txt"), "not a colormap").unwrap();
+
+        let import = import_colormaps_from_directory(&dir).unwrap();
+        fs::remove_dir_all(&dir).ok();
+
+        let names: Vec<&str> = import.colormaps.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Fire", "Ocean"]);
+        assert_eq!(import.colormaps[0].stops, maps[0].stops);
+        assert_eq!(import.failures.len(), 1);
+        assert_eq!(import.failures[0].0, dir.join("Broken.json"));
+        assert!(matches!(import.failures[0].1, ColorMapError::JsonError(_)));
+    }
+
+    #[test]
+    fn test_export_directory_duplicate_names() {
+        let dir = temp_path("scala_chromatica_duplicate_test");
+        fs::remove_dir_all(&dir).ok();
+
+        let maps = vec![ColorMap::fire_scheme(), ColorMap::fire_scheme()];
+        let result = export_colormaps_to_directory(&maps, &dir);
+        assert!(matches!(result, Err(ColorMapError::DuplicateName(name)) if name == "Fire"));
+        assert!(!dir.exists());
+
+        assert!(import_colormaps_from_directory(&dir).is_err());
+    }
+
+    /// Writer that accepts `remaining` bytes, then fails like a full disk
+    struct FailAfter<W> {
+        inner: W,
+        remaining: usize,
+    }
+
+    impl<W: io::Write> io::Write for FailAfter<W> {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.remaining == 0 {
+                return Err(io::Error::new(
+                    io::ErrorKind::StorageFull,
+                    "simulated full disk",
+                ));
+            }
+            let n = buf.len().min(self.remaining);
+            self.remaining -= n;
+            self.inner.write(&buf[..n])
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.inner.flush()
+        }
+    }
+
+    #[test]
+    fn test_hex_color_format() {
+        // Old (object), new (hex) and mixed stop colors all load
+        let old = r#"{"name": "Old", "stops": [
+            {"position": 0.0, "color": {"r": 255, "g": 87, "b": 51}}
+        ]}"#;
+        let new = r##"{"name": "New", "stops": [
+            {"position": 0.0, "color": "#FF5733"}
+        ]}"##;
+        let mixed = r##"{"name": "Mixed", "stops": [
+            {"position": 0.0, "color": "#ff5733"},
+            {"position": 1.0, "color": {"r": 0, "g": 0, "b": 255}, "name": "blue"}
+        ]}"##;
+        let coral = Color::new(255, 87, 51);
+        assert_eq!(load_colormap_from_str(old).unwrap().stops[0].color, coral);
+        assert_eq!(load_colormap_from_str(new).unwrap().stops[0].color, coral);
+        let mixed = load_colormap_from_str(mixed).unwrap();
+        assert_eq!(mixed.stops[0].color, coral);
+        assert_eq!(mixed.stops[1].color, Color::new(0, 0, 255));
+        assert!(load_colormap_from_str(
+            r##"{"name": "Bad", "stops": [{"position": 0.0, "color": "#12"}]}"##
+        )
+        .is_err());
+
+        // Hex output keeps every other field and is the only color form written
+        let mut map = ColorMap::fire_scheme();
+        map.stops[1].name = Some("ember".to_string());
+        map.stops[1].midpoint = Some(0.3);
+        map.stops[2].easing = Easing::EaseOut;
+        map.stops[2].alpha = Some(64);
+        map.stops[2] = map.stops[2].clone().with_metadata("source", "lava");
+        map.interpolation = InterpolationMode::Hsv;
+        map.metadata.author = Some("Tester".to_string());
+        let json = save_colormap_to_string_with_format(&map, ColorFormat::Hex).unwrap();
+        assert!(json.contains(r##""color": "#000000""##));
+        assert!(!json.contains(r#""r":"#));
+        let loaded = load_colormap_from_str(&json).unwrap();
+        assert_eq!(loaded.stops, map.stops);
+        assert_eq!(loaded.interpolation, map.interpolation);
+        assert_eq!(loaded.metadata, map.metadata);
+        assert_eq!(
+            save_colormap_to_string_with_format(&map, ColorFormat::Rgb).unwrap(),
+            save_colormap_to_string(&map).unwrap()
+        );
+
+        // Round trip through an atomic save on disk
+        let dir = fixture_directory("scala_chromatica_hex_format_test");
+        let path = dir.join("Fire.json");
+        write_atomic(&path, |file| {
+            save_colormap_to_writer_with_format(&map, file, ColorFormat::Hex)
+        })
+        .unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), json);
+        let loaded = load_colormap_from_reader(fs::File::open(&path).unwrap()).unwrap();
+        assert_eq!(loaded.stops, map.stops);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_export_builtin_colormap_to_path() {
+        let dir = fixture_directory("scala_chromatica_export_path_test");
+        let path = dir.join("fire.json");
+
+        assert_eq!(
+            export_builtin_colormap_to_path("Fire", &path).unwrap(),
+            path
+        );
+        let exported = load_colormap_from_reader(fs::File::open(&path).unwrap()).unwrap();
+        assert_eq!(exported.stops, ColorMap::fire_scheme().stops);
+
+        // Existing files are only replaced on request
+        let result = export_builtin_colormap_to_path("Ocean", &path);
+        assert!(
+            matches!(&result, Err(ColorMapError::IoError(e)) if e.kind() == io::ErrorKind::AlreadyExists)
+        );
+        assert_eq!(
+            load_colormap_from_reader(fs::File::open(&path).unwrap())
+                .unwrap()
+                .name,
+            "Fire"
+        );
+        export_builtin_colormap_to_path_overwrite("Ocean", &path).unwrap();
+        assert_eq!(
+            load_colormap_from_reader(fs::File::open(&path).unwrap())
+                .unwrap()
+                .name,
+            "Ocean"
+        );
+
+        // A directory gets a file named after the colormap
+        assert_eq!(
+            export_builtin_colormap_to_path("Fire", &dir).unwrap(),
+            dir.join("Fire.json")
+        );
+        assert!(matches!(
+            export_builtin_colormap_to_path("Nope", &dir),
+            Err(ColorMapError::NotFound(_))
+        ));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_atomic() {
+        let dir = fixture_directory("scala_chromatica_atomic_test");
+        let path = dir.join("Fire.json");
+        let fire = ColorMap::fire_scheme();
+
+        write_atomic(&path, |file| save_colormap_to_writer(&fire, file)).unwrap();
+        let saved = load_colormap_from_reader(fs::File::open(&path).unwrap()).unwrap();
+        assert_eq!(saved.stops, fire.stops);
+        assert!(!dir.join("Fire.json.tmp").exists());
+
+        // A write that dies partway leaves the previous file untouched
+        let before = fs::read_to_string(&path).unwrap();
+        let mut changed = fire.clone();
+        changed.name = "Changed".to_string();
+        let result = write_atomic(&path, |file| {
+            save_colormap_to_writer(
+                &changed,
+                FailAfter {
+                    inner: file,
+                    remaining: 40,
+                },
+            )
+        });
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), before);
+        assert!(!dir.join("Fire.json.tmp").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_new_atomic() {
+        let dir = fixture_directory("scala_chromatica_new_atomic_test");
+        let path = dir.join("Fire.json");
+        let fire = ColorMap::fire_scheme();
+        let already_exists = |result: &Result<()>| matches!(result, Err(ColorMapError::IoError(e)) if e.kind() == io::ErrorKind::AlreadyExists);
+
+        write_new_atomic(&path, |file| save_colormap_to_writer(&fire, file)).unwrap();
+        let saved = load_colormap_from_reader(fs::File::open(&path).unwrap()).unwrap();
+        assert_eq!(saved.stops, fire.stops);
+        assert!(!dir.join("Fire.json.tmp").exists());
+
+        let result = write_new_atomic(&path, |file| save_colormap_to_writer(&fire, file));
+        assert!(already_exists(&result));
+
+        // A file that appears while the temporary one is written is kept
+        let racing = dir.join("Racing.json");
+        let result = write_new_atomic(&racing, |file| {
+            fs::write(&racing, "theirs")?;
+            save_colormap_to_writer(&fire, file)
+        });
+        assert!(already_exists(&result));
+        assert_eq!(fs::read_to_string(&racing).unwrap(), "theirs");
+        assert!(!dir.join("Racing.json.tmp").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_colormaps_directory_env_override() {
+        let dir = temp_path("scala_chromatica_env_dir_test");
+        fs::remove_dir_all(&dir).ok();
+
+        let resolved = get_colormaps_directory_with_env(dir.to_str()).unwrap();
+        assert_eq!(resolved, dir);
+        assert!(dir.is_dir());
+
+        // Unset or empty falls back to the platform directory
+        let fallback = get_colormaps_directory_with_env(Some(""));
+        assert_eq!(
+            fallback.is_ok(),
+            get_colormaps_directory_with_env(None).is_ok()
+        );
+        if let Ok(fallback) = fallback {
+            assert_ne!(fallback, dir);
+            assert!(fallback.ends_with("colormaps"));
+        }
+
+        // The override is listed first, followed by at most the platform directory
+        let dirs = custom_colormap_directories_with_env(dir.to_str()).unwrap();
+        assert_eq!(dirs[0], dir);
+        assert!(dirs.len() <= 2);
+        if let Ok(dirs) = custom_colormap_directories_with_env(None) {
+            assert_eq!(dirs.len(), 1);
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_colormaps_directory_env_not_utf8() {
+        use std::ffi::OsStr;
+
+        assert_eq!(colormaps_dir_env_str(None).unwrap(), None);
+        assert_eq!(
+            colormaps_dir_env_str(Some(OsStr::new("maps"))).unwrap(),
+            Some("maps")
+        );
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt;
+            let invalid = OsStr::from_bytes(b"maps\xff");
+            assert!(matches!(
+                colormaps_dir_env_str(Some(invalid)),
+                Err(ColorMapError::IoError(e)) if e.kind() == io::ErrorKind::InvalidInput
+            ));
+        }
+    }
+
+    #[test]
+    fn test_list_colormaps_in_several_directories() {
+        let first = fixture_directory("scala_chromatica_list_first_test");
+        let second = fixture_directory("scala_chromatica_list_second_test");
+        let write = |dir: &Path, name: &str| {
+            let map = ColorMap::with_stops(name, vec![ColorStop::new(0.0, Color::new(1, 2, 3))]);
+            fs::write(
+                dir.join(format!("{}.json", name)),
+                save_colormap_to_string(&map).unwrap(),
+            )
+            .unwrap();
+        };
+        write(&first, "Shared");
+        write(&first, "First Only");
+        write(&second, "Shared");
+        write(&second, "Second Only");
+        write(&second, "Fire");
+
+        let listed = list_colormaps_in(&[first.clone(), second.clone()]).unwrap();
+        fs::remove_dir_all(&first).ok();
+        fs::remove_dir_all(&second).ok();
+
+        let mut custom: Vec<(&str, &Path)> = listed
+            .iter()
+            .filter(|info| !info.is_builtin)
+            .map(|info| (info.name.as_str(), info.filepath.as_deref().unwrap()))
+            .collect();
+        custom.sort();
+        assert_eq!(
+            custom,
+            vec![
+                ("First Only", first.join("First Only.json").as_path()),
+                ("Second Only", second.join("Second Only.json").as_path()),
+                ("Shared", first.join("Shared.json").as_path()),
+            ]
+        );
+        assert_eq!(listed.iter().filter(|info| info.name == "Fire").count(), 1);
+    }
+
+    fn fixture_directory(name: &str) -> PathBuf {
+        let dir = temp_path(name);
+        fs::remove_dir_all(&dir).ok();
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_load_non_ascii_color() {
+        let json = r#"{"name": "Bad", "stops": [{"position": 0.0, "color": "é1"}]}"#;
+        assert!(matches!(
+            load_colormap_from_str(json),
+            Err(ColorMapError::JsonError(_))
+        ));
+        assert!(load_colormap_from_reader(json.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_load_hard_edge_json() {
+        let dir = fixture_directory("scala_chromatica_hard_edge_test");
+        let path = dir.join("edge.json");
+        fs::write(
+            &path,
+            r##"{"name": "Edge", "stops": [
+                {"position": 1.0, "color": "#0000FF"},
+                {"position": 0.0, "color": "#000000"},
+                {"position": 0.5, "color": "#FFFFFF"},
+                {"position": 0.5, "color": "#FF0000"},
+                {"position": 1.0, "color": "#00FF00"}
+            ]}"##,
+        )
+        .unwrap();
+
+        let map = load_colormap_auto(&path).unwrap();
+        fs::remove_dir_all(&dir).ok();
+        assert!(map.is_valid());
+        assert_eq!(map.get_color(0.5), Color::WHITE);
+        assert_eq!(map.get_color(0.75), Color::RED.lerp(&Color::BLUE, 0.5));
+        assert_eq!(map.get_color(1.0), Color::GREEN);
+
+        // Empty stop lists still load, as before
+        let empty = load_colormap_from_str(r#"{"name": "Empty", "stops": []}"#).unwrap();
+        assert!(empty.stops.is_empty());
+    }
+
+    #[test]
+    fn test_rename_colormap() {
+        let dir = fixture_directory("scala_chromatica_rename_test");
+        let mut original = ColorMap::fire_scheme();
+        original.name = "Old".to_string();
+        export_colormaps_to_directory(&[original.clone()], &dir).unwrap();
+
+        rename_colormap_in(&dir, "Old", "New").unwrap();
+        assert!(!dir.join("Old.json").exists());
+        let file = fs::File::open(dir.join("New.json")).unwrap();
+        let renamed = load_colormap_from_reader(file).unwrap();
+        assert_eq!(renamed.name, "New");
+        assert_eq!(renamed.stops, original.stops);
+
+        assert!(matches!(
+            rename_colormap_in(&dir, "Old", "Other"),
+            Err(ColorMapError::NotFound(name)) if name == "Old"
+        ));
+        assert!(rename_colormap_in(&dir, "New", "Fire").is_err());
+        assert!(rename_colormap_in(&dir, "Fire", "Hot").is_err());
+        assert!(dir.join("New.json").exists());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_copy_colormap() {
+        let dir = fixture_directory("scala_chromatica_copy_test");
+
+        // Built-ins can be the source of a copy
+        let path = copy_colormap_in(&dir, "Fire", "My Fire").unwrap();
+        assert_eq!(path, dir.join("My Fire.json"));
+        let copied = load_colormap_from_reader(fs::File::open(&path).unwrap()).unwrap();
+        assert_eq!(copied.name, "My Fire");
+        assert_eq!(copied.stops, load_builtin_colormap("Fire").unwrap().stops);
+
+        // Custom sources stay in place
+        copy_colormap_in(&dir, "My Fire", "My Fire 2").unwrap();
+        assert!(dir.join("My Fire.json").exists());
+        assert!(dir.join("My Fire 2.json").exists());
+
+        assert!(matches!(
+            copy_colormap_in(&dir, "Missing", "Anything"),
+            Err(ColorMapError::NotFound(name)) if name == "Missing"
+        ));
+        assert!(matches!(
+            copy_colormap_in(&dir, "Fire", "My Fire"),
+            Err(ColorMapError::DuplicateName(_))
+        ));
+        assert!(copy_colormap_in(&dir, "My Fire", "Ocean").is_err());
+
+        fs::remove_dir_all(&dir).ok();
+    }
 }
@@ -0,0 +1,293 @@
+//! Compact binary colormap files (`bincode` feature)
+//!
+//! A binary colormap file starts with the four magic bytes `SCBN` and a
+//! little-endian `u32` format version, followed by the colormap encoded
+//! with `bincode`. Loading checks both, so files from a newer format
+//! version fail with [`ColorMapError::VersionMismatch`] instead of
+//! decoding garbage. The conventional extension is `.scbin`; see
+//! [`load_colormap_auto`](super::load_colormap_auto).
+
+use super::{checked_colormap, write_atomic};
+use crate::color::Color;
+use crate::colormap::{ColorMap, ColorMapMetadata, ColorStop, Easing, InterpolationMode};
+use crate::error::{ColorMapError, Result};
+use bincode::Options;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+/// Magic bytes at the start of every binary colormap file
+pub const BINARY_MAGIC: [u8; 4] = *b"SCBN";
+
+/// Current binary format version, written after [`BINARY_MAGIC`]
+pub const BINARY_FORMAT_VERSION: u32 = 1;
+
+/// Largest encoded colormap accepted when loading, in bytes
+///
+/// Length prefixes in corrupted or hostile files could otherwise request
+/// arbitrarily large allocations before any data is read.
+pub const BINARY_SIZE_LIMIT: u64 = 64 * 1024 * 1024;
+
+/// Serialization view of a [`ColorMap`] with every field always present
+///
+/// bincode isn't self-describing, so the JSON types' skipped fields can't
+/// be used directly. Adding a field here requires bumping
+/// [`BINARY_FORMAT_VERSION`].
+#[derive(Serialize, Deserialize)]
+struct BinaryColorMap {
+    name: String,
+    stops: Vec<BinaryColorStop>,
+    interpolation: InterpolationMode,
+    author: Option<String>,
+    description: Option<String>,
+    tags: Vec<String>,
+    license: Option<String>,
+    created_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BinaryColorStop {
+    position: f64,
+    color: Color,
+    name: Option<String>,
+    midpoint: Option<f64>,
+    easing: Easing,
+    alpha: Option<u8>,
+    metadata: BTreeMap<String, String>,
+}
+
+impl BinaryColorMap {
+    fn new(colormap: &ColorMap) -> Self {
+        let metadata = colormap.metadata.clone();
+        Self {
+            name: colormap.name.clone(),
+            stops: colormap
+                .stops
+                .iter()
+                .map(|stop| BinaryColorStop {
+                    position: stop.position,
+                    color: stop.color,
+                    name: stop.name.clone(),
+                    midpoint: stop.midpoint,
+                    easing: stop.easing,
+                    alpha: stop.alpha,
+                    metadata: stop.metadata.clone(),
+                })
+                .collect(),
+            interpolation: colormap.interpolation,
+            author: metadata.author,
+            description: metadata.description,
+            tags: metadata.tags,
+            license: metadata.license,
+            created_at: metadata.created_at,
+        }
+    }
+
+    fn into_colormap(self) -> Result<ColorMap> {
+        let stops = self
+            .stops
+            .into_iter()
+            .map(|stop| {
+                Ok(ColorStop {
+                    name: stop.name,
+                    midpoint: stop.midpoint,
+                    easing: stop.easing,
+                    alpha: stop.alpha,
+                    metadata: stop.metadata,
+                    ..ColorStop::try_new(stop.position, stop.color)?
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut colormap = ColorMap::with_stops(self.name, stops);
+        colormap.interpolation = self.interpolation;
+        colormap.metadata = ColorMapMetadata {
+            author: self.author,
+            description: self.description,
+            tags: self.tags,
+            license: self.license,
+            created_at: self.created_at,
+        };
+        checked_colormap(colormap)
+    }
+}
+
+/// Save a colormap as a binary file, replacing `path` atomically
+///
+/// # Examples
+/// ```no_run
+/// use scala_chromatica::{io, ColorMap};
+/// use std::path::Path;
+///
+/// io::save_colormap_binary(&ColorMap::fire_scheme(), Path::new("fire.scbin")).unwrap();
+/// let fire = io::load_colormap_binary(Path::new("fire.scbin")).unwrap();
+/// assert_eq!(fire.name, "Fire");
+/// ```
+pub fn save_colormap_binary(colormap: &ColorMap, path: &Path) -> Result<()> {
+    write_atomic(path, |file| {
+        let mut writer = io::BufWriter::new(file);
+        save_colormap_binary_to_writer(colormap, &mut writer)?;
+        writer.flush()?;
+        Ok(())
+    })
+}
+
+/// Load a colormap from a binary file written by [`save_colormap_binary`]
+pub fn load_colormap_binary(path: &Path) -> Result<ColorMap> {
+    let file = fs::File::open(path)?;
+    load_colormap_binary_from_reader(io::BufReader::new(file))
+}
+
+/// Write a colormap in the binary format to any writer
+pub fn save_colormap_binary_to_writer<W: Write>(colormap: &ColorMap, mut writer: W) -> Result<()> {
+    writer.write_all(&BINARY_MAGIC)?;
+    writer.write_all(&BINARY_FORMAT_VERSION.to_le_bytes())?;
+    binary_options()
+        .serialize_into(writer, &BinaryColorMap::new(colormap))
+        .map_err(|e| bincode_error(*e))
+}
+
+/// Read a colormap in the binary format from any reader
+///
+/// Fails with an [`io::ErrorKind::InvalidData`] I/O error for input that
+/// isn't a binary colormap, is corrupted or decodes to more than
+/// [`BINARY_SIZE_LIMIT`] bytes, and with
+/// [`ColorMapError::VersionMismatch`] for other format versions.
+pub fn load_colormap_binary_from_reader<R: Read>(mut reader: R) -> Result<ColorMap> {
+    let mut header = [0u8; 8];
+    reader.read_exact(&mut header).map_err(|e| match e.kind() {
+        io::ErrorKind::UnexpectedEof => invalid_binary("file is too short"),
+        _ => e.into(),
+    })?;
+    if header[..4] != BINARY_MAGIC {
+        return Err(invalid_binary("missing 'SCBN' header"));
+    }
+
+    let version = u32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+    if version != BINARY_FORMAT_VERSION {
+        return Err(ColorMapError::VersionMismatch {
+            found: version,
+            expected: BINARY_FORMAT_VERSION,
+        });
+    }
+
+    let colormap: BinaryColorMap = binary_options()
+        .with_limit(BINARY_SIZE_LIMIT)
+        .deserialize_from(reader)
+        .map_err(|e| bincode_error(*e))?;
+    colormap.into_colormap()
+}
+
+/// The bincode 1.x legacy encoding: fixed-width integers, trailing bytes allowed
+fn binary_options() -> impl Options {
+    bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .allow_trailing_bytes()
+}
+
+/// Build the error returned for malformed binary colormaps
+fn invalid_binary(message: &str) -> ColorMapError {
+    ColorMapError::IoError(io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid binary colormap: {}", message),
+    ))
+}
+
+fn bincode_error(error: bincode::ErrorKind) -> ColorMapError {
+    match error {
+        // A stream ending mid-value is a truncated file, not a read failure
+        bincode::ErrorKind::Io(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+            invalid_binary("file is truncated")
+        }
+        bincode::ErrorKind::Io(e) => ColorMapError::IoError(e),
+        other => invalid_binary(&other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::tests::temp_path;
+
+    fn annotated_map() -> ColorMap {
+        let mut map = ColorMap::fire_scheme();
+        map.stops[1] = map.stops[1]
+            .clone()
+            .with_midpoint(0.3)
+            .with_easing(Easing::EaseOut)
+            .with_alpha(200)
+            .with_metadata("source", "lava");
+        map.stops[2].name = Some("ember".to_string());
+        map.interpolation = InterpolationMode::Oklab;
+        map.metadata.author = Some("Tester".to_string());
+        map.metadata.tags = vec!["warm".to_string()];
+        map
+    }
+
+    #[test]
+    fn test_binary_roundtrip() {
+        let map = annotated_map();
+        let mut bytes = Vec::new();
+        save_colormap_binary_to_writer(&map, &mut bytes).unwrap();
+        assert_eq!(bytes[..4], BINARY_MAGIC);
+        assert_eq!(bytes[4..8], BINARY_FORMAT_VERSION.to_le_bytes());
+        assert!(bytes.len() < super::super::save_colormap_to_string(&map).unwrap().len());
+
+        let loaded = load_colormap_binary_from_reader(bytes.as_slice()).unwrap();
+        assert_eq!(loaded.name, map.name);
+        assert_eq!(loaded.stops, map.stops);
+        assert_eq!(loaded.interpolation, map.interpolation);
+        assert_eq!(loaded.metadata, map.metadata);
+
+        let path = temp_path("scala_chromatica_binary_test").with_extension("scbin");
+        save_colormap_binary(&map, &path).unwrap();
+        assert_eq!(fs::read(&path).unwrap(), bytes);
+        assert_eq!(load_colormap_binary(&path).unwrap().stops, map.stops);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_binary_rejects_bad_input() {
+        let mut bytes = Vec::new();
+        save_colormap_binary_to_writer(&annotated_map(), &mut bytes).unwrap();
+        let load = |bytes: &[u8]| load_colormap_binary_from_reader(bytes);
+        let is_invalid_data = |result: Result<ColorMap>| matches!(result, Err(ColorMapError::IoError(e)) if e.kind() == io::ErrorKind::InvalidData);
+
+        assert!(is_invalid_data(load(b"SCB")));
+        assert!(is_invalid_data(load(br#"{"name": "Fire"}"#)));
+
+        let mut newer = bytes.clone();
+        newer[4..8].copy_from_slice(&2u32.to_le_bytes());
+        assert!(matches!(
+            load(&newer),
+            Err(ColorMapError::VersionMismatch {
+                found: 2,
+                expected: BINARY_FORMAT_VERSION
+            })
+        ));
+
+        assert!(is_invalid_data(load(&bytes[..bytes.len() - 3])));
+        assert!(is_invalid_data(load(&bytes[..12])));
+
+        // A huge length prefix fails cleanly instead of allocating it
+        let mut huge = BINARY_MAGIC.to_vec();
+        huge.extend_from_slice(&BINARY_FORMAT_VERSION.to_le_bytes());
+        huge.extend_from_slice(&(1u64 << 40).to_le_bytes());
+        assert_eq!(huge.len(), 16);
+        assert!(is_invalid_data(load(&huge)));
+        huge.extend_from_slice(b"Fire");
+        assert!(is_invalid_data(load(&huge)));
+
+        // Positions are validated like JSON stops
+        let mut map = ColorMap::fire_scheme();
+        map.stops[0].position = f64::NAN;
+        let mut bytes = Vec::new();
+        save_colormap_binary_to_writer(&map, &mut bytes).unwrap();
+        assert!(matches!(
+            load(&bytes),
+            Err(ColorMapError::InvalidPosition(p)) if p.is_nan()
+        ));
+    }
+}
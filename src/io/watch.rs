@@ -0,0 +1,188 @@
+//! Change notifications for the custom colormaps directory (`watch` feature)
+
+use super::get_effective_colormaps_directory;
+use crate::error::{ColorMapError, Result};
+use notify::event::{EventKind, ModifyKind, RenameMode};
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A change to a custom colormap file, carrying the colormap name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WatchEvent {
+    /// A new `{name}.json` appeared
+    Created(String),
+    /// An existing `{name}.json` was written to or replaced
+    Modified(String),
+    /// `{name}.json` was removed or renamed away
+    Deleted(String),
+}
+
+/// Keeps a directory watch alive; watching stops when this is dropped
+pub struct WatchHandle {
+    _watcher: RecommendedWatcher,
+    directory: PathBuf,
+}
+
+impl WatchHandle {
+    /// The directory being watched
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+}
+
+impl std::fmt::Debug for WatchHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WatchHandle")
+            .field("directory", &self.directory)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Watch the custom colormaps directory for external changes
+///
+/// `callback` runs on the watcher's background thread for every `.json`
+/// file created, modified or deleted in
+/// [`get_effective_colormaps_directory`]; other files (including the
+/// `.json.tmp` files of [`save_colormap_atomic`](super::save_colormap_atomic))
+/// are ignored. A file renamed into place, as atomic saves do, is reported
+/// as [`WatchEvent::Modified`]. Editors and the OS may emit several events
+/// for a single save, so callbacks should be cheap and idempotent.
+///
+/// Built-in colormaps are embedded in the binary and can't be watched; a
+/// custom file that shadows a built-in name is still reported.
+///
+/// # Examples
+/// ```no_run
+/// use scala_chromatica::io::{self, WatchEvent};
+///
+/// let _handle = io::watch_colormap_directory(|event| {
+///     if let WatchEvent::Modified(name) = event {
+///         println!("reload {}", name);
+///     }
+/// })
+/// .unwrap();
+/// ```
+pub fn watch_colormap_directory(
+    callback: impl Fn(WatchEvent) + Send + 'static,
+) -> Result<WatchHandle> {
+    watch_directory(&get_effective_colormaps_directory()?, callback)
+}
+
+fn watch_directory(
+    directory: &Path,
+    callback: impl Fn(WatchEvent) + Send + 'static,
+) -> Result<WatchHandle> {
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let Ok(event) = event else {
+            return;
+        };
+        for (i, path) in event.paths.iter().enumerate() {
+            let Some(name) = colormap_name(path) else {
+                continue;
+            };
+            let change = match event.kind {
+                EventKind::Create(_) => WatchEvent::Created(name),
+                EventKind::Remove(_) => WatchEvent::Deleted(name),
+                EventKind::Modify(ModifyKind::Name(RenameMode::From)) => WatchEvent::Deleted(name),
+                EventKind::Modify(ModifyKind::Name(RenameMode::Both)) if i == 0 => {
+                    WatchEvent::Deleted(name)
+                }
+                EventKind::Modify(ModifyKind::Name(_)) if !path.exists() => {
+                    WatchEvent::Deleted(name)
+                }
+                EventKind::Modify(ModifyKind::Metadata(_)) => continue,
+                EventKind::Modify(_) => WatchEvent::Modified(name),
+                _ => continue,
+            };
+            callback(change);
+        }
+    })
+    .map_err(watch_error)?;
+
+    watcher
+        .watch(directory, RecursiveMode::NonRecursive)
+        .map_err(watch_error)?;
+
+    Ok(WatchHandle {
+        _watcher: watcher,
+        directory: directory.to_path_buf(),
+    })
+}
+
+/// The colormap name for a `{name}.json` path
+fn colormap_name(path: &Path) -> Option<String> {
+    if path.extension().and_then(|s| s.to_str()) != Some("json") {
+        return None;
+    }
+    path.file_stem()
+        .and_then(|s| s.to_str())
+        .map(str::to_string)
+}
+
+fn watch_error(error: notify::Error) -> ColorMapError {
+    ColorMapError::IoError(io::Error::other(error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::tests::temp_path;
+    use std::fs;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    /// Wait until `expected` arrives, skipping the extra events a write produces
+    fn expect_event(events: &mpsc::Receiver<WatchEvent>, expected: WatchEvent) {
+        loop {
+            match events.recv_timeout(Duration::from_secs(5)) {
+                Ok(event) if event == expected => return,
+                Ok(_) => continue,
+                Err(_) => panic!("timed out waiting for {:?}", expected),
+            }
+        }
+    }
+
+    #[test]
+    fn test_watch_directory() {
+        let dir = temp_path("scala_chromatica_watch_test");
+        fs::remove_dir_all(&dir).ok();
+        fs::create_dir_all(&dir).unwrap();
+
+        let (sender, events) = mpsc::channel();
+        let handle = watch_directory(&dir, move |event| {
+            sender.send(event).ok();
+        })
+        .unwrap();
+        assert_eq!(handle.directory(), dir);
+
+        let path = dir.join("Live.json");
+        fs::write(dir.join("notes.txt"), "ignored").unwrap();
+        fs::write(&path, "{}").unwrap();
+        expect_event(&events, WatchEvent::Created("Live".to_string()));
+
+        fs::write(&path, r#"{"name": "Live", "stops": []}"#).unwrap();
+        expect_event(&events, WatchEvent::Modified("Live".to_string()));
+
+        fs::remove_file(&path).unwrap();
+        expect_event(&events, WatchEvent::Deleted("Live".to_string()));
+
+        // Nothing arrives once the handle is gone
+        drop(handle);
+        while events.try_recv().is_ok() {}
+        fs::write(&path, "{}").unwrap();
+        assert!(events.recv_timeout(Duration::from_millis(300)).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_colormap_name() {
+        assert_eq!(
+            colormap_name(Path::new("/a/Fire.json")),
+            Some("Fire".to_string())
+        );
+        assert_eq!(colormap_name(Path::new("/a/Fire.json.tmp")), None);
+        assert_eq!(colormap_name(Path::new("/a/readme.md")), None);
+    }
+}
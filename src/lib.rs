@@ -6,12 +6,20 @@
 //! sophisticated color mapping.
 //!
 //! # Features
-//! - Smooth RGB interpolation between color stops
+//! - Smooth interpolation between color stops in RGB, HSV, Oklab or Oklch
 //! - HSV color space support
 //! - JSON serialization/deserialization
-//! - 15 built-in color schemes (Fire, Ocean, Rainbow, etc.)
+//! - 21 built-in color schemes (Fire, Ocean, Rainbow, Viridis, etc.)
 //! - Platform-specific config directory management
 //! - Custom colormap save/load
+//! - PNG gradient export (with the `png` feature)
+//! - Matplotlib colormap snippets via `ColorMap::to_matplotlib_python()`
+//! - `.cube` 1D and 3D LUT export for video grading (with the `cube` feature)
+//! - Seeded random colors and gradients (with the `rand` feature)
+//! - ANSI terminal previews (with the `terminal` feature)
+//! - Live reload of custom colormaps (with the `watch` feature)
+//! - Compact binary colormap files (with the `bincode` feature)
+//! - Parallel batch sampling for large renders (with the `rayon` feature)
 //!
 //! # Quick Start
 //! ```rust
@@ -30,15 +38,30 @@
 //! custom.add_stop(ColorStop::new(1.0, Color::new(0, 0, 255)));
 //!
 //! // Save for later use
-//! scala_chromatica::io::save_colormap(&custom).unwrap();
+//! scala_chromatica::io::save_colormap_atomic(&custom).unwrap();
 //! ```
 
 pub mod color;
 pub mod colormap;
 pub mod error;
+pub mod export;
 pub mod io;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "terminal")]
+pub mod terminal;
 
 // Re-export main types at crate root for convenience
-pub use color::Color;
-pub use colormap::{color_from_iterations, ColorMap, ColorStop};
+pub use color::{
+    BlendMode, Color, Color16, ColorBlindType, ColorF, ColorVisionDeficiency, GrayscaleMethod,
+    HuePath, Rgba,
+};
+pub use colormap::{
+    color_from_iterations, color_from_iterations_smooth, ColorMap, ColorMapA, ColorMapBuilder,
+    ColorMapMetadata, ColorStop, Easing, InterpolationMode, Junction,
+};
 pub use error::{ColorMapError, Result};
+#[cfg(feature = "rayon")]
+pub use parallel::color_from_iterations_batch;
@@ -0,0 +1,144 @@
+//! Multithreaded color sampling for large renders
+//!
+//! Enabled with the `rayon` cargo feature. Work is spread over rayon's
+//! global thread pool; every function returns exactly what the matching
+//! single-threaded call would, in input order.
+
+use crate::color::Color;
+use crate::colormap::{color_from_iterations, ColorMap};
+use rayon::prelude::*;
+
+impl ColorMap {
+    /// [`ColorMap::get_color`] for every position, computed in parallel
+    ///
+    /// # Examples
+    /// ```
+    /// use scala_chromatica::ColorMap;
+    ///
+    /// let fire = ColorMap::fire_scheme();
+    /// let positions: Vec<f64> = (0..1000).map(|i| i as f64 / 999.0).collect();
+    /// let colors = fire.get_colors_parallel(&positions);
+    /// assert_eq!(colors[500], fire.get_color(positions[500]));
+    /// ```
+    pub fn get_colors_parallel(&self, positions: &[f64]) -> Vec<Color> {
+        positions
+            .par_iter()
+            .map(|&position| self.get_color(position))
+            .collect()
+    }
+}
+
+/// [`color_from_iterations`] for a whole buffer of iteration counts,
+/// computed in parallel
+///
+/// Takes the same options as `color_from_iterations` and returns one color
+/// per entry of `iterations`, e.g. a row-major iteration buffer from a
+/// fractal renderer.
+///
+/// # Examples
+/// ```
+/// use scala_chromatica::{color_from_iterations_batch, ColorMap};
+///
+/// let fire = ColorMap::fire_scheme();
+/// let pixels = color_from_iterations_batch(&[0, 50, 100], 100, &fire, false, 0, true, [0, 0, 0], false);
+/// assert_eq!(pixels.len(), 3);
+/// assert_eq!(pixels[2], scala_chromatica::Color::BLACK);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn color_from_iterations_batch(
+    iterations: &[u32],
+    max_iterations: u32,
+    colormap: &ColorMap,
+    use_period: bool,
+    period: u32,
+    use_interior_color: bool,
+    interior_color: [u8; 3],
+    use_log_scale: bool,
+) -> Vec<Color> {
+    iterations
+        .par_iter()
+        .map(|&count| {
+            color_from_iterations(
+                count,
+                max_iterations,
+                colormap,
+                use_period,
+                period,
+                use_interior_color,
+                interior_color,
+                use_log_scale,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic pseudo-random iteration counts (xorshift)
+    fn iteration_counts(len: usize, max: u32) -> Vec<u32> {
+        let mut state = 0x2545_f491_u32;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state % (max + 1)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_batch_matches_sequential() {
+        let map = ColorMap::viridis_scheme();
+        let counts = iteration_counts(100_000, 500);
+        for (use_period, use_interior, use_log) in [
+            (false, false, false),
+            (true, true, false),
+            (false, true, true),
+        ] {
+            let parallel = color_from_iterations_batch(
+                &counts,
+                500,
+                &map,
+                use_period,
+                64,
+                use_interior,
+                [1, 2, 3],
+                use_log,
+            );
+            let sequential: Vec<Color> = counts
+                .iter()
+                .map(|&n| {
+                    color_from_iterations(
+                        n,
+                        500,
+                        &map,
+                        use_period,
+                        64,
+                        use_interior,
+                        [1, 2, 3],
+                        use_log,
+                    )
+                })
+                .collect();
+            assert_eq!(parallel, sequential);
+        }
+        assert!(
+            color_from_iterations_batch(&[], 10, &map, false, 0, false, [0; 3], false).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_get_colors_parallel_matches_sequential() {
+        let map = ColorMap::fire_scheme();
+        let positions: Vec<f64> = iteration_counts(50_000, 10_000)
+            .into_iter()
+            .map(|n| n as f64 / 10_000.0)
+            .chain([-1.0, 2.0, f64::NAN])
+            .collect();
+        let sequential: Vec<Color> = positions.iter().map(|&p| map.get_color(p)).collect();
+        assert_eq!(map.get_colors_parallel(&positions), sequential);
+    }
+}
@@ -0,0 +1,244 @@
+//! Random color and gradient generation
+//!
+//! Enabled with the `rand` cargo feature. All functions take the RNG as a
+//! parameter, so a seeded generator (e.g., `StdRng::seed_from_u64`) gives
+//! the same output on every run.
+
+use crate::color::Color;
+use crate::colormap::{ColorMap, ColorStop};
+use rand::Rng;
+use std::ops::RangeInclusive;
+
+impl Color {
+    /// Generate a color with uniformly random RGB channels
+    pub fn random(rng: &mut impl Rng) -> Self {
+        Self::new(rng.gen(), rng.gen(), rng.gen())
+    }
+
+    /// Generate a color with a uniformly random hue and saturation and value
+    /// drawn from the given ranges
+    ///
+    /// Ranges are clamped to 0.0 - 1.0. Useful for keeping placeholder
+    /// palettes away from muddy or washed-out colors.
+    ///
+    /// # Panics
+    /// If either range is empty (start greater than end).
+    ///
+    /// # Examples
+    /// ```
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use scala_chromatica::Color;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let color = Color::random_hsv(&mut rng, 0.6..=0.9, 0.8..=1.0);
+    /// let (_, s, v) = color.to_hsv();
+    /// assert!(s > 0.55 && v > 0.75);
+    /// ```
+    pub fn random_hsv(
+        rng: &mut impl Rng,
+        s_range: RangeInclusive<f64>,
+        v_range: RangeInclusive<f64>,
+    ) -> Self {
+        let h = rng.gen_range(0.0..360.0);
+        let s = rng.gen_range(clamp_unit(s_range));
+        let v = rng.gen_range(clamp_unit(v_range));
+        Self::from_hsv(h, s, v)
+    }
+
+    /// Generate a color with a uniformly random hue and saturation and
+    /// lightness drawn from the given ranges
+    ///
+    /// The HSL counterpart of [`Color::random_hsv`]; ranges are clamped to
+    /// 0.0 - 1.0.
+    ///
+    /// # Panics
+    /// If either range is empty (start greater than end).
+    pub fn random_hsl(
+        rng: &mut impl Rng,
+        s_range: RangeInclusive<f64>,
+        l_range: RangeInclusive<f64>,
+    ) -> Self {
+        let h = rng.gen_range(0.0..360.0);
+        let s = rng.gen_range(clamp_unit(s_range));
+        let l = rng.gen_range(clamp_unit(l_range));
+        Self::from_hsl(h, s, l)
+    }
+}
+
+impl ColorMap {
+    /// Generate a random gradient with `n_stops` stops (at least 2)
+    ///
+    /// The first and last stops sit at exactly 0.0 and 1.0. Interior stops
+    /// are jittered around even spacing, so positions are always strictly
+    /// increasing and the result passes [`ColorMap::validate`].
+    pub fn random(rng: &mut impl Rng, n_stops: usize) -> Self {
+        let n_stops = n_stops.max(2);
+        let last = n_stops - 1;
+
+        let stops = (0..n_stops)
+            .map(|i| {
+                let position = if i == 0 || i == last {
+                    i as f64 / last as f64
+                } else {
+                    (i as f64 + rng.gen_range(-0.25..0.25)) / last as f64
+                };
+                ColorStop::new(position, Color::random(rng))
+            })
+            .collect();
+
+        Self::with_stops("Random", stops)
+    }
+
+    /// Generate a gradient named `name` of `n_stops` (at least 2) uniformly
+    /// random colors at evenly spaced positions
+    pub fn random_evenly_spaced(
+        rng: &mut impl Rng,
+        name: impl Into<String>,
+        n_stops: usize,
+    ) -> Self {
+        let colors = (0..n_stops.max(2)).map(|_| Color::random(rng)).collect();
+        Self::equidistant(name, colors)
+    }
+
+    /// Generate an evenly spaced random gradient that tends to look good
+    ///
+    /// Starts at a random hue and walks 30 - 90 degrees around the color
+    /// wheel per stop, keeping saturation within 0.45 - 0.85 and lightness
+    /// within 0.35 - 0.7 so no stop is muddy, neon or washed out.
+    ///
+    /// # Examples
+    /// ```
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use scala_chromatica::ColorMap;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(3);
+    /// let map = ColorMap::random_aesthetic(&mut rng, "Sunset", 5);
+    /// assert_eq!(map.name, "Sunset");
+    /// assert_eq!(map.stops.len(), 5);
+    /// assert!(map.validate().is_ok());
+    /// ```
+    pub fn random_aesthetic(rng: &mut impl Rng, name: impl Into<String>, n_stops: usize) -> Self {
+        let mut hue = rng.gen_range(0.0..360.0);
+        let direction = if rng.gen() { 1.0 } else { -1.0 };
+
+        let colors = (0..n_stops.max(2))
+            .map(|_| {
+                let color = Color::from_hsl(
+                    hue,
+                    rng.gen_range(AESTHETIC_SATURATION),
+                    rng.gen_range(AESTHETIC_LIGHTNESS),
+                );
+                hue += direction * rng.gen_range(30.0..=90.0);
+                color
+            })
+            .collect();
+
+        Self::equidistant(name, colors)
+    }
+}
+
+/// Saturation range used by [`ColorMap::random_aesthetic`]
+const AESTHETIC_SATURATION: RangeInclusive<f64> = 0.45..=0.85;
+
+/// Lightness range used by [`ColorMap::random_aesthetic`]
+const AESTHETIC_LIGHTNESS: RangeInclusive<f64> = 0.35..=0.7;
+
+/// Clamp both ends of a range to 0.0 - 1.0
+fn clamp_unit(range: RangeInclusive<f64>) -> RangeInclusive<f64> {
+    range.start().clamp(0.0, 1.0)..=range.end().clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_seeded_determinism() {
+        let mut a = StdRng::seed_from_u64(42);
+        let mut b = StdRng::seed_from_u64(42);
+
+        assert_eq!(Color::random(&mut a), Color::random(&mut b));
+        assert_eq!(
+            Color::random_hsv(&mut a, 0.5..=1.0, 0.5..=1.0),
+            Color::random_hsv(&mut b, 0.5..=1.0, 0.5..=1.0)
+        );
+        assert_eq!(
+            ColorMap::random(&mut a, 6).stops,
+            ColorMap::random(&mut b, 6).stops
+        );
+
+        let mut c = StdRng::seed_from_u64(43);
+        let maps: Vec<_> = (0..4).map(|_| ColorMap::random(&mut c, 6).stops).collect();
+        assert!(maps.windows(2).any(|w| w[0] != w[1]));
+    }
+
+    #[test]
+    fn test_random_hsv_ranges() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..200 {
+            let (_, s, v) = Color::random_hsv(&mut rng, 0.4..=0.6, 0.7..=0.9).to_hsv();
+            // Channel truncation can move the values slightly
+            assert!((0.35..=0.65).contains(&s), "saturation {}", s);
+            assert!((0.65..=0.95).contains(&v), "value {}", v);
+        }
+
+        // Out-of-range bounds are clamped rather than producing invalid colors
+        let color = Color::random_hsv(&mut rng, 2.0..=3.0, -1.0..=0.0);
+        assert_eq!(color, Color::black());
+    }
+
+    #[test]
+    fn test_random_colormap_is_valid() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for n in [0, 1, 2, 3, 10, 64] {
+            let map = ColorMap::random(&mut rng, n);
+            assert_eq!(map.stops.len(), n.max(2));
+            assert_eq!(map.stops.first().unwrap().position, 0.0);
+            assert_eq!(map.stops.last().unwrap().position, 1.0);
+            assert!(map.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_random_hsl_ranges() {
+        let mut rng = StdRng::seed_from_u64(5);
+        for _ in 0..200 {
+            let (_, s, l) = Color::random_hsl(&mut rng, 0.4..=0.6, 0.3..=0.5).to_hsl();
+            assert!((0.3..=0.7).contains(&s), "saturation {}", s);
+            assert!((0.28..=0.52).contains(&l), "lightness {}", l);
+        }
+    }
+
+    #[test]
+    fn test_random_evenly_spaced_and_aesthetic() {
+        let mut rng = StdRng::seed_from_u64(11);
+        for n in [0, 2, 7] {
+            let even = ColorMap::random_evenly_spaced(&mut rng, "Even", n);
+            let aesthetic = ColorMap::random_aesthetic(&mut rng, String::from("Aesthetic"), n);
+            assert_eq!(even.name, "Even");
+            assert_eq!(aesthetic.name, "Aesthetic");
+            for map in [&even, &aesthetic] {
+                assert_eq!(map.stops.len(), n.max(2));
+                assert!(map.validate().is_ok());
+                let last = map.stops.len() - 1;
+                for (i, stop) in map.stops.iter().enumerate() {
+                    assert!((stop.position - i as f64 / last as f64).abs() < 1e-12);
+                }
+            }
+            for stop in &aesthetic.stops {
+                let (_, s, l) = stop.color.to_hsl();
+                assert!((0.4..=0.9).contains(&s), "saturation {}", s);
+                assert!((0.33..=0.72).contains(&l), "lightness {}", l);
+            }
+        }
+
+        let mut a = StdRng::seed_from_u64(9);
+        let mut b = StdRng::seed_from_u64(9);
+        assert_eq!(
+            ColorMap::random_aesthetic(&mut a, "A", 4).stops,
+            ColorMap::random_aesthetic(&mut b, "A", 4).stops
+        );
+    }
+}
@@ -0,0 +1,92 @@
+//! Terminal previews of colormaps using ANSI truecolor escapes
+//!
+//! Enabled with the `terminal` cargo feature. Truecolor output only renders
+//! correctly in terminals that support 24-bit color. The escapes for single
+//! colors, such as [`Color::ansi_bg`](crate::Color::ansi_bg), are always
+//! available.
+
+pub use crate::color::ANSI_RESET;
+use crate::colormap::ColorMap;
+use std::fmt::Write;
+
+/// Column width the colormap name is padded to by [`ColorMap::to_ansi_gradient_line`]
+pub const ANSI_LABEL_WIDTH: usize = 20;
+
+impl ColorMap {
+    /// Render the gradient as `width` background-colored spaces
+    ///
+    /// Each cell is `\x1b[48;2;R;G;Bm \x1b[0m`, sampled evenly from 0.0 to 1.0.
+    ///
+    /// # Examples
+    /// ```
+    /// let fire = scala_chromatica::io::load_builtin_colormap("Fire").unwrap();
+    /// println!("{}", fire.to_ansi_string(40));
+    /// ```
+    pub fn to_ansi_string(&self, width: usize) -> String {
+        let mut out = String::new();
+        for i in 0..width {
+            let t = if width > 1 {
+                i as f64 / (width - 1) as f64
+            } else {
+                0.0
+            };
+            let color = self.get_color(t);
+            // Writing to a String can't fail
+            let _ = write!(out, "{} {}", color.ansi_bg(), ANSI_RESET);
+        }
+        out
+    }
+
+    /// Render the gradient as a line, optionally preceded by the colormap name
+    ///
+    /// The name is right-aligned in [`ANSI_LABEL_WIDTH`] columns and
+    /// followed by a space; longer names are printed in full.
+    pub fn to_ansi_gradient_line(&self, width: usize, label: bool) -> String {
+        let gradient = self.to_ansi_string(width);
+        if label {
+            format!("{:>w$} {}", self.name, gradient, w = ANSI_LABEL_WIDTH)
+        } else {
+            gradient
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::Color;
+    use crate::colormap::ColorStop;
+
+    fn solid(color: Color) -> ColorMap {
+        ColorMap::with_stops(
+            "Solid",
+            vec![ColorStop::new(0.0, color), ColorStop::new(1.0, color)],
+        )
+    }
+
+    #[test]
+    fn test_ansi_string_length() {
+        let white = "\x1b[48;2;255;255;255m \x1b[0m";
+        let map = solid(Color::white());
+        assert_eq!(map.to_ansi_string(10).len(), 10 * white.len());
+        assert_eq!(map.to_ansi_string(10), white.repeat(10));
+        assert_eq!(map.to_ansi_string(0), "");
+
+        let fire = ColorMap::fire_scheme();
+        let ansi = fire.to_ansi_string(32);
+        assert_eq!(ansi.matches("\x1b[0m").count(), 32);
+        assert!(ansi.starts_with("\x1b[48;2;0;0;0m \x1b[0m"));
+        assert!(ansi.ends_with(white));
+    }
+
+    #[test]
+    fn test_ansi_gradient_line() {
+        let map = solid(Color::new(1, 2, 3));
+        let cell = "\x1b[48;2;1;2;3m \x1b[0m";
+
+        assert_eq!(map.to_ansi_gradient_line(3, false), cell.repeat(3));
+        let line = map.to_ansi_gradient_line(3, true);
+        assert_eq!(line, format!("{}Solid {}", " ".repeat(15), cell.repeat(3)));
+        assert_eq!(line.find('\x1b'), Some(ANSI_LABEL_WIDTH + 1));
+    }
+}